@@ -0,0 +1,185 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+};
+
+use super::{Status, Streams};
+
+/// The aliases defined in the shell, by name.
+#[derive(Default, Clone)]
+pub struct Aliases {
+    definitions: BTreeMap<String, String>,
+}
+
+impl Aliases {
+    /// Returns the names of the aliases, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.definitions.keys().map(String::as_str)
+    }
+
+    /// Removes the alias `name`, returning whether it was defined.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.definitions.remove(name).is_some()
+    }
+
+    /// Removes every alias.
+    pub fn clear(&mut self) {
+        self.definitions.clear();
+    }
+
+    /// Replaces the first word of `line` by its definition when it names an alias, repeatedly, so that an
+    /// alias may be defined in terms of another one. Each alias is expanded once at most, which stops the
+    /// expansion of aliases referring to themselves, directly or not.
+    ///
+    /// Like in other shells, a first word holding quotes, backslashes or variables is never expanded.
+    pub fn expand(&self, line: &str) -> String {
+        let mut line = line.to_string();
+        let mut expanded = HashSet::new();
+
+        loop {
+            let end = line
+                .find(|c: char| c.is_whitespace() || matches!(c, '|' | '<' | '>'))
+                .unwrap_or(line.len());
+            let word = &line[..end];
+
+            if !is_alias_name(word) || expanded.contains(word) {
+                return line;
+            }
+
+            let Some(definition) = self.definitions.get(word) else {
+                return line;
+            };
+
+            expanded.insert(word.to_string());
+            line = format!("{}{}", definition, &line[end..]);
+        }
+    }
+}
+
+/// Execute the `alias` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `alias` command.
+///
+/// Each argument of the form `NAME=VALUE` defines the alias `NAME`, which the shell replaces by `VALUE`
+/// when it is the first word of a line. An argument made of a name only prints the definition of that
+/// alias. With no arguments, every alias is printed, sorted by name.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `alias` command.
+/// * `aliases` - The aliases defined in the shell.
+/// * `streams` - The standard streams of the command.
+pub fn execute(
+    args: Vec<String>,
+    aliases: &mut Aliases,
+    streams: &mut Streams,
+) -> io::Result<Status> {
+    if args.is_empty() {
+        for (name, definition) in aliases.definitions.iter() {
+            print_alias(name, definition, streams)?;
+        }
+
+        return Ok(Status::SUCCESS);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for arg in args {
+        match arg.split_once('=') {
+            Some((name, _)) if !is_alias_name(name) => {
+                eprintln!("alias : invalid alias name: '{}'", name);
+                status = Status::FAILURE;
+            }
+            Some((name, definition)) => {
+                aliases
+                    .definitions
+                    .insert(name.to_string(), definition.to_string());
+            }
+            None => match aliases.definitions.get(&arg) {
+                Some(definition) => print_alias(&arg, definition, streams)?,
+                None => {
+                    eprintln!("alias : not found: {}", arg);
+                    status = Status::FAILURE;
+                }
+            },
+        }
+    }
+
+    Ok(status)
+}
+
+/// Prints an alias definition in a form that can be read back by the shell, the definition being quoted
+/// with single quotes.
+fn print_alias(name: &str, definition: &str, streams: &mut Streams) -> io::Result<()> {
+    writeln!(
+        streams.stdout,
+        "alias {}='{}'",
+        name,
+        definition.replace('\'', "'\\''")
+    )
+}
+
+/// Returns whether `name` can name an alias: it must not be empty, nor hold whitespace, quotes,
+/// backslashes, `$`, `/`, `=` or the characters of the shell operators.
+fn is_alias_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|c| {
+            c.is_whitespace() || matches!(c, '\'' | '"' | '\\' | '$' | '/' | '=' | '|' | '<' | '>')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn define(aliases: &mut Aliases, definitions: &[&str]) -> Status {
+        run("", |streams| execute(args(definitions), aliases, streams)).0
+    }
+
+    #[test]
+    fn alias_replaces_the_first_word() {
+        let mut aliases = Aliases::default();
+        define(&mut aliases, &["ll=ls -l"]);
+
+        assert_eq!(aliases.expand("ll /tmp"), "ls -l /tmp");
+        assert_eq!(aliases.expand("ll|wc"), "ls -l|wc");
+        assert_eq!(aliases.expand("echo ll"), "echo ll");
+        assert_eq!(aliases.expand("'ll'"), "'ll'");
+    }
+
+    #[test]
+    fn aliases_expand_through_other_aliases() {
+        let mut aliases = Aliases::default();
+        define(&mut aliases, &["l=ll", "ll=ls -l"]);
+
+        assert_eq!(aliases.expand("l a"), "ls -l a");
+    }
+
+    #[test]
+    fn recursive_aliases_expand_once() {
+        let mut aliases = Aliases::default();
+        define(&mut aliases, &["ls=ls -F", "a=b", "b=a x"]);
+
+        assert_eq!(aliases.expand("ls"), "ls -F");
+        assert_eq!(aliases.expand("a"), "a x");
+    }
+
+    #[test]
+    fn no_arguments_prints_the_definitions_sorted_and_quoted() {
+        let mut aliases = Aliases::default();
+        define(&mut aliases, &["say=echo 'hi'", "ll=ls -l"]);
+
+        let (_, output) = run("", |streams| execute(vec![], &mut aliases, streams));
+
+        assert_eq!(output, "alias ll='ls -l'\nalias say='echo '\\''hi'\\'''\n");
+    }
+
+    #[test]
+    fn invalid_name_fails() {
+        let mut aliases = Aliases::default();
+
+        assert_eq!(define(&mut aliases, &["a/b=ls"]), Status::FAILURE);
+        assert_eq!(aliases.names().count(), 0);
+    }
+}
@@ -0,0 +1,139 @@
+use std::io;
+
+use super::{Status, Streams};
+
+/// Execute the `basename` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `basename` command.
+///
+/// It prints the last component of the path given as first argument, ignoring trailing slashes, and with
+/// the suffix given as second argument removed. With `-a`, every argument is a path, and with `-s SUFFIX`,
+/// which implies `-a`, the suffix is removed from each of them. The suffix is never removed when it is
+/// the whole component.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `basename` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut multiple = false;
+    let mut suffix = None;
+    let mut operands = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-a" => multiple = true,
+            "-s" => match args.next() {
+                Some(value) => {
+                    multiple = true;
+                    suffix = Some(value);
+                }
+                None => {
+                    eprintln!("basename : option requires an argument - 's'");
+
+                    return Ok(Status::FAILURE);
+                }
+            },
+            "--" => {
+                operands.extend(args.by_ref());
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("basename : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => operands.push(arg),
+        }
+    }
+
+    if operands.is_empty() {
+        eprintln!("basename : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    if !multiple {
+        match operands.len() {
+            1 => {}
+            2 => suffix = operands.pop(),
+            _ => {
+                eprintln!("basename : extra operand '{}'", operands[2]);
+
+                return Ok(Status::FAILURE);
+            }
+        }
+    }
+
+    for path in operands {
+        writeln!(streams.stdout, "{}", basename(&path, suffix.as_deref()))?;
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Returns the last component of `path`, ignoring trailing slashes, with `suffix` removed unless it is the
+/// whole component. A path made of slashes only gives `/`.
+fn basename<'a>(path: &'a str, suffix: Option<&str>) -> &'a str {
+    if path.is_empty() {
+        return path;
+    }
+
+    let trimmed = path.trim_end_matches('/');
+
+    if trimmed.is_empty() {
+        return "/";
+    }
+
+    let name = trimmed.rsplit('/').next().unwrap_or(trimmed);
+
+    match suffix {
+        Some(suffix) if name != suffix => name.strip_suffix(suffix).unwrap_or(name),
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn basename_of(arg: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arg), streams))
+    }
+
+    #[test]
+    fn prints_the_last_component() {
+        assert_eq!(basename_of(&["/a/b/c.txt"]).1, "c.txt\n");
+        assert_eq!(basename_of(&["c.txt"]).1, "c.txt\n");
+    }
+
+    #[test]
+    fn trailing_slashes_are_ignored() {
+        assert_eq!(basename_of(&["/a/b/"]).1, "b\n");
+        assert_eq!(basename_of(&["//"]).1, "/\n");
+    }
+
+    #[test]
+    fn suffix_is_removed_unless_it_is_the_whole_name() {
+        assert_eq!(basename_of(&["/a/b/c.txt", ".txt"]).1, "c\n");
+        assert_eq!(basename_of(&["/a/b/c.txt", ".rs"]).1, "c.txt\n");
+        assert_eq!(basename_of(&["/a/.txt", ".txt"]).1, ".txt\n");
+    }
+
+    #[test]
+    fn a_takes_several_names() {
+        assert_eq!(basename_of(&["-a", "/a/b", "c/d/"]).1, "b\nd\n");
+    }
+
+    #[test]
+    fn s_removes_the_suffix_of_several_names() {
+        assert_eq!(basename_of(&["-s", ".rs", "a/b.rs", "c.rs"]).1, "b\nc\n");
+    }
+
+    #[test]
+    fn extra_operand_fails() {
+        assert_eq!(basename_of(&["a", "b", "c"]).0, Status::FAILURE);
+        assert_eq!(basename_of(&[]).0, Status::FAILURE);
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+};
+
+use super::{Status, Streams};
+
+/// The options of `cat` changing the content it prints.
+#[derive(Default)]
+struct Options {
+    /// Number every output line, with `-n`.
+    number: bool,
+    /// Number the non-blank output lines only, with `-b`, which takes precedence over `-n`.
+    number_nonblank: bool,
+    /// Print a `$` at the end of each line, with `-E`.
+    show_ends: bool,
+    /// Print tabs as `^I`, with `-T`.
+    show_tabs: bool,
+    /// Print the control characters and the bytes above 127 in caret and `M-` notation, with `-v`.
+    show_nonprinting: bool,
+}
+
+impl Options {
+    /// Returns whether the content is printed as is, and can be copied without being looked at.
+    fn is_plain(&self) -> bool {
+        !(self.number
+            || self.number_nonblank
+            || self.show_ends
+            || self.show_tabs
+            || self.show_nonprinting)
+    }
+}
+
+/// Where the output stands, carried from one file to the next so that the numbering is continuous.
+struct Position {
+    /// The number of the last numbered line.
+    line: u64,
+    /// Whether the next byte printed starts a line, which is false when a file doesn't end with a newline.
+    at_line_start: bool,
+}
+
+/// Execute the `cat` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `cat` command.
+///
+/// It writes the content of each file to the standard output, in order. The argument `-` stands for the
+/// standard input, which is also read when no file is given. With `-n`, each line is preceded by its
+/// number, right-aligned, and a tab; with `-b`, only the non-blank lines are numbered. The numbering goes
+/// on from one file to the next.
+///
+/// With `-E`, each line ends with a `$`; with `-T`, tabs are printed as `^I`; and with `-v`, the other
+/// control characters are printed in caret notation, like `^M`, and the bytes above 127 with a `M-`
+/// prefix. `-A` is a shorthand for `-vET`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `cat` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut options = Options::default();
+    let mut paths = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            paths.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'n' => options.number = true,
+                'b' => options.number_nonblank = true,
+                'E' => options.show_ends = true,
+                'T' => options.show_tabs = true,
+                'v' => options.show_nonprinting = true,
+                'A' => {
+                    options.show_ends = true;
+                    options.show_tabs = true;
+                    options.show_nonprinting = true;
+                }
+                _ => {
+                    eprintln!("cat : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let mut position = Position {
+        line: 0,
+        at_line_start: true,
+    };
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let result = if path == "-" {
+            cat(streams.stdin, streams.stdout, &options, &mut position)
+        } else {
+            File::open(&path).and_then(|file| {
+                cat(
+                    &mut BufReader::new(file),
+                    streams.stdout,
+                    &options,
+                    &mut position,
+                )
+            })
+        };
+
+        match result {
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Err(e),
+            Err(e) => {
+                handle_error(e, path);
+                status = Status::FAILURE;
+            }
+            Ok(()) => {}
+        }
+    }
+
+    streams.stdout.flush()?;
+
+    Ok(status)
+}
+
+/// Writes the content of `reader` to `writer`, transformed according to `options`.
+fn cat(
+    reader: &mut dyn BufRead,
+    writer: &mut dyn Write,
+    options: &Options,
+    position: &mut Position,
+) -> io::Result<()> {
+    // `io::copy` streams through a fixed-size buffer, so large files are never loaded whole.
+    if options.is_plain() {
+        return io::copy(reader, writer).map(|_| ());
+    }
+
+    let mut line = vec![];
+
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        let numbered = if options.number_nonblank {
+            line != b"\n"
+        } else {
+            options.number
+        };
+
+        if position.at_line_start && numbered {
+            position.line += 1;
+            write!(writer, "{:>6}\t", position.line)?;
+        }
+
+        let (content, newline) = match line.strip_suffix(b"\n") {
+            Some(content) => (content, true),
+            None => (&line[..], false),
+        };
+
+        write_content(content, options, writer)?;
+
+        if newline {
+            writer.write_all(if options.show_ends { b"$\n" } else { b"\n" })?;
+        }
+
+        position.at_line_start = newline;
+        line.clear();
+    }
+
+    Ok(())
+}
+
+/// Writes the content of a line, without its newline, making the characters selected by `options` visible.
+///
+/// The content is handled as bytes, so that input which isn't valid UTF-8 is printed safely.
+fn write_content(content: &[u8], options: &Options, writer: &mut dyn Write) -> io::Result<()> {
+    if !options.show_tabs && !options.show_nonprinting {
+        return writer.write_all(content);
+    }
+
+    let mut visible = Vec::with_capacity(content.len());
+
+    for &byte in content {
+        match byte {
+            b'\t' if options.show_tabs => visible.extend_from_slice(b"^I"),
+            b'\t' => visible.push(byte),
+            _ if options.show_nonprinting => push_visible(byte, &mut visible),
+            _ => visible.push(byte),
+        }
+    }
+
+    writer.write_all(&visible)
+}
+
+/// Appends `byte` to `visible` in caret notation when it is a control character, prefixed by `M-` when
+/// it is above 127, like `cat -v`.
+fn push_visible(byte: u8, visible: &mut Vec<u8>) {
+    let byte = if byte >= 128 {
+        visible.extend_from_slice(b"M-");
+        byte - 128
+    } else {
+        byte
+    };
+
+    match byte {
+        0..=31 => visible.extend_from_slice(&[b'^', byte + 64]),
+        127 => visible.extend_from_slice(b"^?"),
+        _ => visible.push(byte),
+    }
+}
+
+fn handle_error(error: io::Error, path: String) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("cat : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("cat : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("cat : is a directory: {}", path),
+        _ => eprintln!("cat : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn cat(arg: &[&str], input: &str) -> String {
+        run(input, |streams| execute(args(arg), streams)).1
+    }
+
+    #[test]
+    fn copies_the_input_as_is() {
+        assert_eq!(cat(&[], "a\n\tb"), "a\n\tb");
+    }
+
+    #[test]
+    fn n_numbers_every_line() {
+        assert_eq!(cat(&["-n"], "a\n\nb\n"), "     1\ta\n     2\t\n     3\tb\n");
+    }
+
+    #[test]
+    fn b_numbers_the_non_blank_lines_only() {
+        assert_eq!(cat(&["-b"], "a\n\nb\n"), "     1\ta\n\n     2\tb\n");
+        assert_eq!(cat(&["-nb"], "\nc\n"), "\n     1\tc\n");
+    }
+
+    #[test]
+    fn numbers_keep_aligned_past_999_lines() {
+        let input = "x\n".repeat(1000);
+
+        let output = cat(&["-n"], &input);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[998], "   999\tx");
+        assert_eq!(lines[999], "  1000\tx");
+    }
+
+    #[test]
+    fn numbering_goes_on_across_files() {
+        let dir = TempDir::new();
+        let first = dir.write("first", "a\nb");
+        let second = dir.write("second", "c\nd\n");
+
+        assert_eq!(
+            cat(&["-n", &first, &second], ""),
+            "     1\ta\n     2\tbc\n     3\td\n"
+        );
+    }
+
+    #[test]
+    fn missing_file_fails_after_printing_the_others() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "content\n");
+
+        let (status, output) = run("", |streams| {
+            execute(args(&[&dir.join("missing"), &file]), streams)
+        });
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, "content\n"));
+    }
+
+    #[test]
+    fn t_shows_tabs() {
+        assert_eq!(cat(&["-T"], "a\tb\x01\n"), "a^Ib\x01\n");
+    }
+
+    #[test]
+    fn e_shows_line_ends() {
+        assert_eq!(cat(&["-E"], "a\n\nb"), "a$\n$\nb");
+    }
+
+    #[test]
+    fn v_shows_control_characters_in_caret_notation() {
+        assert_eq!(cat(&["-v"], "a\tb\x01\x1b\x7f\r\n"), "a\tb^A^[^?^M\n");
+    }
+
+    #[test]
+    fn v_shows_high_bytes_with_a_meta_prefix() {
+        let dir = TempDir::new();
+        let path = dir.join("bytes");
+        std::fs::write(&path, [b'a', 0xe9, 0x81, 0xff, b'\n']).unwrap();
+
+        assert_eq!(cat(&["-v", &path], ""), "aM-iM-^AM-^?\n");
+    }
+
+    #[test]
+    fn a_shows_tabs_ends_and_control_characters() {
+        assert_eq!(cat(&["-A"], "a\tb\x01\n"), "a^Ib^A$\n");
+        assert_eq!(cat(&["-A"], "x\n"), cat(&["-vET"], "x\n"));
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    env, fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use super::{pwd::logical_current_dir, Status, Streams};
+
+/// State kept by the `cd` command between invocations.
+#[derive(Default, Clone)]
+pub struct DirectoryState {
+    previous: Option<PathBuf>,
+}
+
+/// Execute the `cd` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `cd` command.
+///
+/// It changes the current working directory of the process to the first argument, or to `$HOME` when no
+/// argument is given. The argument `-` changes back to the previous directory and prints it.
+///
+/// Like the `-L` mode of other shells, the directory is followed logically: a relative argument is joined
+/// to `$PWD` and its `..` components remove the previous component, rather than going to the parent of
+/// the target of a symlink. `$PWD` is then set to that logical path.
+///
+/// In a subshell, the directory is only checked, since the current directory is shared by the whole
+/// shell.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `cd` command.
+/// * `state` - The directory state shared between invocations, used to resolve `cd -`.
+/// * `subshell` - Whether `cd` runs in a stage of a pipeline but the last.
+/// * `streams` - The standard streams of the command.
+pub fn execute(
+    args: Vec<String>,
+    state: &mut DirectoryState,
+    subshell: bool,
+    streams: &mut Streams,
+) -> io::Result<Status> {
+    let target = match args.first().map(String::as_str) {
+        Some("-") => match &state.previous {
+            Some(previous) => {
+                writeln!(streams.stdout, "{}", previous.display())?;
+                previous.clone()
+            }
+            None => {
+                eprintln!("cd : no previous directory");
+
+                return Ok(Status::FAILURE);
+            }
+        },
+        Some(path) => PathBuf::from(path),
+        None => match env::var_os("HOME") {
+            Some(home) => PathBuf::from(home),
+            None => {
+                eprintln!("cd : HOME not set");
+
+                return Ok(Status::FAILURE);
+            }
+        },
+    };
+
+    let current_dir = logical_current_dir().ok();
+    let target = match &current_dir {
+        Some(current_dir) => logical_path(current_dir, &target),
+        None => target,
+    };
+
+    if subshell {
+        return match check_directory(&target) {
+            Ok(()) => Ok(Status::SUCCESS),
+            Err(e) => {
+                handle_error(e, target);
+
+                Ok(Status::FAILURE)
+            }
+        };
+    }
+
+    match env::set_current_dir(&target) {
+        Ok(()) => {
+            state.previous = current_dir;
+
+            // Without a current directory to start from, a relative target cannot be made absolute.
+            if target.is_absolute() {
+                env::set_var("PWD", &target);
+            } else if let Ok(new_dir) = env::current_dir() {
+                env::set_var("PWD", new_dir);
+            }
+        }
+        Err(e) => {
+            handle_error(e, target);
+
+            return Ok(Status::FAILURE);
+        }
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Returns the path reached by going to `target` from `current_dir`, resolving the `.` and `..`
+/// components lexically, without following symlinks.
+fn logical_path(current_dir: &Path, target: &Path) -> PathBuf {
+    let mut path = current_dir.to_path_buf();
+
+    for component in target.components() {
+        match component {
+            Component::RootDir | Component::Prefix(_) => {
+                path = PathBuf::from(component.as_os_str())
+            }
+            Component::CurDir => {}
+            Component::ParentDir => {
+                path.pop();
+            }
+            Component::Normal(name) => path.push(name),
+        }
+    }
+
+    path
+}
+
+/// Fails like changing to `path` would when it isn't a directory that can be searched.
+fn check_directory(path: &Path) -> io::Result<()> {
+    if !fs::metadata(path)?.is_dir() {
+        return Err(io::Error::from(io::ErrorKind::NotADirectory));
+    }
+
+    fs::read_dir(path).map(|_| ())
+}
+
+fn handle_error(error: io::Error, path: PathBuf) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("cd : no such file or directory: {}", path.display()),
+        io::ErrorKind::PermissionDenied => {
+            eprintln!("cd : permission denied: {}", path.display())
+        }
+        _ => eprintln!("cd : not a directory: {}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, os::unix::fs::symlink};
+
+    use super::*;
+    use crate::testing::{args, lock_process, run, CurrentDirGuard, TempDir};
+
+    fn cd(arg: &[&str], state: &mut DirectoryState) -> (Status, String) {
+        run("", |streams| execute(args(arg), state, false, streams))
+    }
+
+    #[test]
+    fn changes_to_the_given_directory() {
+        let _lock = lock_process();
+        let _guard = CurrentDirGuard::new();
+        let dir = TempDir::new();
+        let mut state = DirectoryState::default();
+
+        assert_eq!(cd(&[&dir.join(".")], &mut state).0, Status::SUCCESS);
+        assert_eq!(
+            env::current_dir().unwrap(),
+            fs::canonicalize(dir.path()).unwrap()
+        );
+        assert_eq!(env::var_os("PWD").unwrap(), dir.path());
+    }
+
+    #[test]
+    fn follows_symlinks_logically() {
+        let _lock = lock_process();
+        let _guard = CurrentDirGuard::new();
+        let dir = TempDir::new();
+        let mut state = DirectoryState::default();
+
+        fs::create_dir(dir.join("real")).unwrap();
+        symlink(dir.join("real"), dir.join("link")).unwrap();
+
+        cd(&[&dir.join("")], &mut state);
+        cd(&["link"], &mut state);
+
+        assert_eq!(env::var_os("PWD").unwrap(), dir.path().join("link"));
+        assert_eq!(logical_current_dir().unwrap(), dir.path().join("link"));
+        assert_eq!(
+            env::current_dir().unwrap(),
+            fs::canonicalize(dir.join("real")).unwrap()
+        );
+
+        cd(&[".."], &mut state);
+
+        assert_eq!(env::var_os("PWD").unwrap(), dir.path());
+    }
+
+    #[test]
+    fn dash_returns_to_the_previous_logical_directory() {
+        let _lock = lock_process();
+        let _guard = CurrentDirGuard::new();
+        let dir = TempDir::new();
+        let mut state = DirectoryState::default();
+
+        fs::create_dir(dir.join("real")).unwrap();
+        symlink(dir.join("real"), dir.join("link")).unwrap();
+
+        cd(&[&dir.join("link")], &mut state);
+        cd(&[&dir.join("")], &mut state);
+
+        let (status, output) = cd(&["-"], &mut state);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, format!("{}\n", dir.join("link")));
+        assert_eq!(env::var_os("PWD").unwrap(), dir.path().join("link"));
+    }
+
+    #[test]
+    fn dash_without_previous_directory_fails() {
+        assert_eq!(
+            cd(&["-"], &mut DirectoryState::default()).0,
+            Status::FAILURE
+        );
+    }
+
+    #[test]
+    fn missing_directory_fails_without_moving() {
+        let _lock = lock_process();
+        let _guard = CurrentDirGuard::new();
+        let dir = TempDir::new();
+        let before = env::current_dir().unwrap();
+
+        let (status, _) = cd(&[&dir.join("missing")], &mut DirectoryState::default());
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(env::current_dir().unwrap(), before);
+    }
+
+    #[test]
+    fn logical_path_resolves_dots_lexically() {
+        let current = Path::new("/a/b");
+
+        assert_eq!(
+            logical_path(current, Path::new("../c/./d")),
+            Path::new("/a/c/d")
+        );
+        assert_eq!(logical_path(current, Path::new("/x/../y")), Path::new("/y"));
+        assert_eq!(logical_path(current, Path::new("../../..")), Path::new("/"));
+    }
+}
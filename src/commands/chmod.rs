@@ -0,0 +1,116 @@
+use std::{fs, io, os::unix::fs::PermissionsExt};
+
+use crate::unix::permissions::Mode;
+
+use super::Status;
+
+/// Execute the `chmod` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `chmod` command.
+///
+/// It changes the mode of each file to the mode given as first argument, either in octal form (`644`) or
+/// in symbolic form (`u+x`), symbolic changes being relative to the current mode of each file.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `chmod` command.
+pub fn execute(args: Vec<String>) -> io::Result<Status> {
+    let Some((mode, paths)) = args.split_first() else {
+        eprintln!("chmod : missing operand");
+
+        return Ok(Status::FAILURE);
+    };
+
+    if paths.is_empty() {
+        eprintln!("chmod : missing operand after '{}'", mode);
+
+        return Ok(Status::FAILURE);
+    }
+
+    // The mode is validated once, rather than once per file.
+    if let Err(e) = Mode::parse(mode) {
+        eprintln!("chmod : {}", e);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        if let Err(e) = change_mode(mode, path) {
+            handle_error(e, path);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+fn change_mode(mode: &str, path: &str) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    let new_mode = Mode::parse_relative(mode, permissions.mode())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+
+    permissions.set_mode(new_mode);
+
+    fs::set_permissions(path, permissions)
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("chmod : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("chmod : permission denied: {}", path),
+        _ => eprintln!("chmod : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, TempDir};
+
+    fn mode(path: &str) -> u32 {
+        fs::metadata(path).unwrap().permissions().mode() & 0o7777
+    }
+
+    #[test]
+    fn sets_an_octal_mode() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+
+        assert_eq!(execute(args(&["640", &file])).unwrap(), Status::SUCCESS);
+        assert_eq!(mode(&file), 0o640);
+    }
+
+    #[test]
+    fn applies_a_symbolic_mode_to_the_current_one() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o644)).unwrap();
+
+        assert_eq!(execute(args(&["u+x,o-r", &file])).unwrap(), Status::SUCCESS);
+        assert_eq!(mode(&file), 0o740);
+    }
+
+    #[test]
+    fn continues_after_a_missing_file() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+
+        let status = execute(args(&["600", &dir.join("missing"), &file])).unwrap();
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(mode(&file), 0o600);
+    }
+
+    #[test]
+    fn rejects_an_invalid_mode_without_changing_files() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o644)).unwrap();
+
+        assert_eq!(execute(args(&["u+q", &file])).unwrap(), Status::FAILURE);
+        assert_eq!(execute(args(&["644"])).unwrap(), Status::FAILURE);
+        assert_eq!(mode(&file), 0o644);
+    }
+}
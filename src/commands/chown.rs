@@ -0,0 +1,281 @@
+use std::{
+    fs, io,
+    os::unix::fs::{chown, lchown},
+    path::PathBuf,
+};
+
+use crate::unix::users::{lookup_gid, lookup_uid};
+
+use super::Status;
+
+/// Execute the `chown` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `chown` command.
+///
+/// It changes the owner and group of each file to the ones given as first argument, in the form
+/// `OWNER[:GROUP]` or `:GROUP`, where the owner and group are names or numeric ids. With `-R`, the
+/// content of directories is changed too, symlinks inside them being changed themselves rather than
+/// followed. Changing the owner of a file usually requires privileges.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `chown` command.
+pub fn execute(args: Vec<String>) -> io::Result<Status> {
+    let mut recursive = false;
+    let mut operands = vec![];
+
+    for arg in args {
+        match arg.as_str() {
+            "-R" => recursive = true,
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("chown : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => operands.push(arg),
+        }
+    }
+
+    let Some((owner, paths)) = operands.split_first() else {
+        eprintln!("chown : missing operand");
+
+        return Ok(Status::FAILURE);
+    };
+
+    if paths.is_empty() {
+        eprintln!("chown : missing operand after '{}'", owner);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let (uid, gid) = match parse_owner(owner) {
+        Ok(ids) => ids,
+        Err(message) => {
+            eprintln!("chown : {}", message);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let changed = if recursive {
+            change_tree(path, uid, gid)
+        } else {
+            match chown(path, uid, gid) {
+                Ok(()) => true,
+                Err(e) => {
+                    handle_error(e, path);
+                    false
+                }
+            }
+        };
+
+        if !changed {
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Parses an owner given as `OWNER[:GROUP]` or `:GROUP` into a uid and a gid, either of which may be left
+/// unchanged. Returns an error message for an unknown name or an empty specification.
+fn parse_owner(owner: &str) -> Result<(Option<u32>, Option<u32>), String> {
+    let (user, group) = match owner.split_once(':') {
+        Some((user, group)) => (user, group),
+        None => (owner, ""),
+    };
+
+    if user.is_empty() && group.is_empty() {
+        return Err(format!("invalid owner: '{}'", owner));
+    }
+
+    let uid = match user {
+        "" => None,
+        _ => Some(
+            user.parse()
+                .ok()
+                .or_else(|| lookup_uid(user))
+                .ok_or_else(|| format!("invalid user: '{}'", user))?,
+        ),
+    };
+
+    let gid = match group {
+        "" => None,
+        _ => Some(
+            group
+                .parse()
+                .ok()
+                .or_else(|| lookup_gid(group))
+                .ok_or_else(|| format!("invalid group: '{}'", group))?,
+        ),
+    };
+
+    Ok((uid, gid))
+}
+
+/// Changes the owner and group of the tree at `start`, walking it with an explicit stack. Symlinks are
+/// changed themselves rather than followed. Returns whether every file could be changed.
+fn change_tree(start: &str, uid: Option<u32>, gid: Option<u32>) -> bool {
+    let mut stack = vec![PathBuf::from(start)];
+    let mut changed = true;
+
+    while let Some(path) = stack.pop() {
+        let result = fs::symlink_metadata(&path).and_then(|metadata| {
+            lchown(&path, uid, gid)?;
+
+            Ok(metadata.is_dir())
+        });
+
+        match result {
+            Ok(true) => match fs::read_dir(&path) {
+                Ok(entries) => {
+                    for entry in entries {
+                        match entry {
+                            Ok(entry) => stack.push(entry.path()),
+                            Err(e) => {
+                                handle_error(e, &path.to_string_lossy());
+                                changed = false;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    handle_error(e, &path.to_string_lossy());
+                    changed = false;
+                }
+            },
+            Ok(false) => {}
+            Err(e) => {
+                handle_error(e, &path.to_string_lossy());
+                changed = false;
+            }
+        }
+    }
+
+    changed
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("chown : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => {
+            eprintln!(
+                "chown : changing ownership of '{}': operation not permitted",
+                path
+            )
+        }
+        _ => eprintln!("chown : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::{symlink, MetadataExt};
+
+    use super::*;
+    use crate::{
+        testing::{args, TempDir},
+        unix::users::{effective_gid, effective_uid},
+    };
+
+    #[test]
+    fn parses_names_and_ids() {
+        assert_eq!(parse_owner("0:0"), Ok((Some(0), Some(0))));
+        assert_eq!(parse_owner("root:root"), Ok((Some(0), Some(0))));
+        assert_eq!(parse_owner("root"), Ok((Some(0), None)));
+        assert_eq!(parse_owner(":0"), Ok((None, Some(0))));
+        assert_eq!(parse_owner("12345:"), Ok((Some(12345), None)));
+    }
+
+    #[test]
+    fn rejects_unknown_names_and_empty_owner() {
+        assert_eq!(
+            parse_owner("no-such-user"),
+            Err(String::from("invalid user: 'no-such-user'"))
+        );
+        assert_eq!(
+            parse_owner("0:no-such-group"),
+            Err(String::from("invalid group: 'no-such-group'"))
+        );
+        assert_eq!(parse_owner(":"), Err(String::from("invalid owner: ':'")));
+    }
+
+    #[test]
+    fn changing_to_the_current_owner_succeeds() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+        let owner = format!("{}:{}", effective_uid(), effective_gid());
+
+        assert_eq!(execute(args(&[&owner, &file])).unwrap(), Status::SUCCESS);
+        assert_eq!(fs::metadata(&file).unwrap().uid(), effective_uid());
+    }
+
+    #[test]
+    fn changing_to_another_owner_needs_privileges() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+        let status = execute(args(&["12345:12345", &file])).unwrap();
+
+        if effective_uid() == 0 {
+            let metadata = fs::metadata(&file).unwrap();
+            assert_eq!(status, Status::SUCCESS);
+            assert_eq!((metadata.uid(), metadata.gid()), (12345, 12345));
+        } else {
+            assert_eq!(status, Status::FAILURE);
+            assert_eq!(fs::metadata(&file).unwrap().uid(), effective_uid());
+        }
+    }
+
+    #[test]
+    fn r_changes_the_tree_without_following_symlinks() {
+        let dir = TempDir::new();
+        let outside = dir.write("outside", "");
+        let tree = dir.join("tree");
+        fs::create_dir_all(format!("{}/sub", tree)).unwrap();
+        fs::write(format!("{}/sub/file", tree), "").unwrap();
+        symlink(&outside, format!("{}/link", tree)).unwrap();
+        let owner = format!("{}", effective_uid());
+
+        assert_eq!(
+            execute(args(&["-R", &owner, &tree])).unwrap(),
+            Status::SUCCESS
+        );
+
+        if effective_uid() == 0 {
+            assert_eq!(
+                execute(args(&["-R", "12345", &tree])).unwrap(),
+                Status::SUCCESS
+            );
+            assert_eq!(
+                fs::metadata(format!("{}/sub/file", tree)).unwrap().uid(),
+                12345
+            );
+            assert_eq!(
+                fs::symlink_metadata(format!("{}/link", tree))
+                    .unwrap()
+                    .uid(),
+                12345
+            );
+            assert_eq!(fs::metadata(&outside).unwrap().uid(), 0);
+        }
+    }
+
+    #[test]
+    fn missing_file_or_operand_fails() {
+        let dir = TempDir::new();
+
+        assert_eq!(
+            execute(args(&["0", &dir.join("missing")])).unwrap(),
+            Status::FAILURE
+        );
+        assert_eq!(execute(args(&["0"])).unwrap(), Status::FAILURE);
+        assert_eq!(execute(args(&[])).unwrap(), Status::FAILURE);
+        assert_eq!(
+            execute(args(&["-x", "0", "file"])).unwrap(),
+            Status::FAILURE
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::io;
+
+use super::{Status, Streams};
+
+/// Erases the whole screen, then moves the cursor to its top left corner.
+const CLEAR_SCREEN: &[u8] = b"\x1b[2J\x1b[H";
+
+/// Execute the `clear` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `clear` command.
+///
+/// It clears the terminal and moves the cursor to its top left corner. When the standard output isn't the
+/// terminal, as in `clear > file` or `clear | cat`, nothing is written, so that the escape sequence doesn't
+/// end up in files or in the input of other commands.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `clear` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    if let Some(extra) = args.first() {
+        eprintln!("clear : extra operand '{}'", extra);
+
+        return Ok(Status::FAILURE);
+    }
+
+    if streams.terminal {
+        streams.stdout.write_all(CLEAR_SCREEN)?;
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::testing::{args, run};
+
+    #[test]
+    fn writes_the_escape_sequence_to_a_terminal() {
+        let mut stdin = Cursor::new(vec![]);
+        let mut stdout = vec![];
+        let mut streams = Streams {
+            stdin: &mut stdin,
+            stdout: &mut stdout,
+            terminal: true,
+            shell_stdin: false,
+        };
+
+        let status = execute(args(&[]), &mut streams).unwrap();
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(stdout, b"\x1b[2J\x1b[H");
+    }
+
+    #[test]
+    fn writes_nothing_when_not_a_terminal() {
+        let (status, output) = run("", |streams| execute(args(&[]), streams));
+
+        assert_eq!((status, output.as_str()), (Status::SUCCESS, ""));
+    }
+
+    #[test]
+    fn operand_fails() {
+        let (status, output) = run("", |streams| execute(args(&["-x"]), streams));
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+    }
+}
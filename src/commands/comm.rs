@@ -0,0 +1,247 @@
+use std::{
+    cmp::Ordering,
+    fs::File,
+    io::{self, BufRead, BufReader},
+};
+
+use super::{Status, Streams};
+
+/// Execute the `comm` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `comm` command.
+///
+/// It compares two sorted files line by line, and prints three columns: the lines only in the first file,
+/// the lines only in the second one, and the lines in both. Each column is indented by a tab more than the
+/// previous one. `-1`, `-2` and `-3` leave out the first, second and third columns. The files are
+/// expected to be sorted byte by byte, as by `sort`, otherwise the lines in both may not be found. One of
+/// the files may be `-`, which stands for the standard input.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `comm` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut shown = [true; 3];
+    let mut paths = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            paths.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                '1' => shown[0] = false,
+                '2' => shown[1] = false,
+                '3' => shown[2] = false,
+                _ => {
+                    eprintln!("comm : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    let (first, second) = match paths.as_slice() {
+        [first, second] => (first, second),
+        [_, _, extra, ..] => {
+            eprintln!("comm : extra operand '{}'", extra);
+
+            return Ok(Status::FAILURE);
+        }
+        _ => {
+            eprintln!("comm : missing operand");
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    if first == "-" && second == "-" {
+        eprintln!("comm : only one file may be the standard input");
+
+        return Ok(Status::FAILURE);
+    }
+
+    // The standard input is handed to the file that stands for it, which is at most one of them.
+    let mut stdin = Some(&mut *streams.stdin);
+    let mut first_reader = match open(first, &mut stdin) {
+        Ok(reader) => reader,
+        Err(e) => {
+            handle_error(e, first);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+    let mut second_reader = match open(second, &mut stdin) {
+        Ok(reader) => reader,
+        Err(e) => {
+            handle_error(e, second);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    // The tabs preceding each column, one for each column shown before it.
+    let indents: Vec<String> = (0..3)
+        .map(|column| "\t".repeat(shown[..column].iter().filter(|shown| **shown).count()))
+        .collect();
+
+    let mut print = |column: usize, line: &[u8]| -> io::Result<()> {
+        if shown[column] {
+            streams.stdout.write_all(indents[column].as_bytes())?;
+            streams.stdout.write_all(line)?;
+            streams.stdout.write_all(b"\n")?;
+        }
+
+        Ok(())
+    };
+
+    let mut status = Status::SUCCESS;
+    let mut left = next_line(&mut first_reader, first, &mut status);
+    let mut right = next_line(&mut second_reader, second, &mut status);
+
+    loop {
+        let ordering = match (&left, &right) {
+            (None, None) => break,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(left), Some(right)) => left.cmp(right),
+        };
+
+        match ordering {
+            Ordering::Less => {
+                print(0, left.as_deref().unwrap_or_default())?;
+                left = next_line(&mut first_reader, first, &mut status);
+            }
+            Ordering::Greater => {
+                print(1, right.as_deref().unwrap_or_default())?;
+                right = next_line(&mut second_reader, second, &mut status);
+            }
+            Ordering::Equal => {
+                print(2, left.as_deref().unwrap_or_default())?;
+                left = next_line(&mut first_reader, first, &mut status);
+                right = next_line(&mut second_reader, second, &mut status);
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Opens the file at `path`, or takes the standard input for `-`.
+fn open<'a, R: BufRead + ?Sized>(
+    path: &str,
+    stdin: &mut Option<&'a mut R>,
+) -> io::Result<Box<dyn BufRead + 'a>> {
+    match stdin.take() {
+        Some(stdin) if path == "-" => Ok(Box::new(stdin)),
+        taken => {
+            *stdin = taken;
+
+            Ok(Box::new(BufReader::new(File::open(path)?)))
+        }
+    }
+}
+
+/// Reads the next line of `reader`, without its newline, or `None` at the end of the input. A read error
+/// is reported, sets `status` to a failure and ends the input.
+fn next_line(reader: &mut dyn BufRead, path: &str, status: &mut Status) -> Option<Vec<u8>> {
+    let mut line = vec![];
+
+    match reader.read_until(b'\n', &mut line) {
+        Ok(0) => None,
+        Ok(_) => {
+            if line.ends_with(b"\n") {
+                line.pop();
+            }
+
+            Some(line)
+        }
+        Err(e) => {
+            handle_error(e, path);
+            *status = Status::FAILURE;
+
+            None
+        }
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("comm : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("comm : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("comm : is a directory: {}", path),
+        _ => eprintln!("comm : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    /// Runs `comm` on two files holding `a b c e` and `b d e f`, one per line.
+    fn comm(options: &[&str]) -> (Status, String) {
+        let dir = TempDir::new();
+        let first = dir.write("first", "a\nb\nc\ne\n");
+        let second = dir.write("second", "b\nd\ne\nf\n");
+        let mut arguments = options.to_vec();
+        arguments.extend([first.as_str(), second.as_str()]);
+
+        run("", |streams| execute(args(&arguments), streams))
+    }
+
+    #[test]
+    fn prints_three_columns() {
+        assert_eq!(
+            comm(&[]),
+            (
+                Status::SUCCESS,
+                String::from("a\n\t\tb\nc\n\td\n\t\te\n\tf\n")
+            )
+        );
+    }
+
+    #[test]
+    fn suppresses_columns() {
+        assert_eq!(comm(&["-1"]).1, "\tb\nd\n\te\nf\n");
+        assert_eq!(comm(&["-2"]).1, "a\n\tb\nc\n\te\n");
+        assert_eq!(comm(&["-3"]).1, "a\nc\n\td\n\tf\n");
+        assert_eq!(comm(&["-12"]).1, "b\ne\n");
+        assert_eq!(comm(&["-1", "-3"]).1, "d\nf\n");
+        assert_eq!(comm(&["-123"]).1, "");
+    }
+
+    #[test]
+    fn reads_the_standard_input_for_dash() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "a\nc");
+
+        let (status, output) = run("b\nc\n", |streams| execute(args(&["-", &file]), streams));
+
+        assert_eq!(
+            (status, output.as_str()),
+            (Status::SUCCESS, "\ta\nb\n\t\tc\n")
+        );
+    }
+
+    #[test]
+    fn invalid_usage_fails() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "a\n");
+
+        for arguments in [
+            &[file.as_str()][..],
+            &["-", "-"],
+            &[&file, &file, &file],
+            &["-4", &file, &file],
+            &[&file, &dir.join("missing")],
+        ] {
+            let (status, output) = run("", |streams| execute(args(arguments), streams));
+
+            assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+        }
+    }
+}
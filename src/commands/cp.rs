@@ -0,0 +1,370 @@
+use std::{
+    fs::{self, File, FileTimes, Metadata},
+    io::{self, BufRead},
+    os::unix::fs::{symlink, MetadataExt},
+    path::Path,
+};
+
+use crate::prompt::confirm;
+
+use super::{split_options, Status, Streams};
+
+/// The options of the `cp` command.
+struct CopyOptions {
+    recursive: bool,
+    preserve: bool,
+    interactive: bool,
+}
+
+/// Execute the `cp` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `cp` command.
+///
+/// It copies a source file to a destination, or several sources into a destination directory, keeping
+/// their base names. With `-r` or `-R`, directories are copied along with their content, symlinks inside
+/// them being copied as symlinks. With `-p`, the mode and timestamps of the sources are preserved. With
+/// `-i`, a confirmation is read from the standard input before overwriting a file.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `cp` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let (paths, letters) = split_options(args);
+
+    if let Some(wrong_option) = letters.chars().find(|c| !"rRpi".contains(*c)) {
+        eprintln!("cp : invalid option - '{}'", wrong_option);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let options = CopyOptions {
+        recursive: letters.contains('r') || letters.contains('R'),
+        preserve: letters.contains('p'),
+        interactive: letters.contains('i'),
+    };
+
+    let Some((target, sources)) = paths
+        .split_last()
+        .filter(|(_, sources)| !sources.is_empty())
+    else {
+        eprintln!("cp : missing file operand");
+
+        return Ok(Status::FAILURE);
+    };
+
+    let target = Path::new(target);
+    let into_dir = target.is_dir();
+
+    if sources.len() > 1 && !into_dir {
+        eprintln!("cp : target '{}' is not a directory", target.display());
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for source in sources {
+        let source = Path::new(source);
+
+        let destination = match source.file_name() {
+            Some(name) if into_dir => target.join(name),
+            _ => target.to_path_buf(),
+        };
+
+        // Symlinks given as arguments are followed, unlike the ones met while copying a directory.
+        let copied = match fs::metadata(source) {
+            Ok(metadata) => copy(source, &destination, &metadata, &options, streams.stdin)?,
+            Err(e) => {
+                handle_error(e, source);
+
+                false
+            }
+        };
+
+        if !copied {
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Copies `source` to `destination`, reporting the errors on the standard error rather than returning
+/// them, so that a failure on one file doesn't stop the copy of a directory. Only the errors raised while
+/// asking for a confirmation, whose answers are read from `input`, are returned; otherwise, returns
+/// whether the copy went without errors.
+fn copy(
+    source: &Path,
+    destination: &Path,
+    metadata: &Metadata,
+    options: &CopyOptions,
+    input: &mut dyn BufRead,
+) -> io::Result<bool> {
+    if metadata.is_dir() {
+        if !options.recursive {
+            eprintln!("cp : omitting directory: {}", source.display());
+
+            return Ok(false);
+        }
+
+        return copy_dir(source, destination, metadata, options, input);
+    }
+
+    if let Ok(existing) = fs::metadata(destination) {
+        if existing.dev() == metadata.dev() && existing.ino() == metadata.ino() {
+            eprintln!(
+                "cp : '{}' and '{}' are the same file",
+                source.display(),
+                destination.display()
+            );
+
+            return Ok(false);
+        }
+
+        let question = format!("cp : overwrite '{}'?", destination.display());
+
+        if options.interactive && !confirm(&question, input)? {
+            return Ok(true);
+        }
+    }
+
+    let result = if metadata.is_symlink() {
+        fs::read_link(source).and_then(|target| {
+            remove_existing(destination)?;
+            symlink(target, destination)
+        })
+    } else {
+        fs::copy(source, destination).and_then(|_| preserve(destination, metadata, options))
+    };
+
+    if let Err(e) = result {
+        handle_error(e, destination);
+
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+fn copy_dir(
+    source: &Path,
+    destination: &Path,
+    metadata: &Metadata,
+    options: &CopyOptions,
+    input: &mut dyn BufRead,
+) -> io::Result<bool> {
+    if is_inside(destination, source) {
+        eprintln!(
+            "cp : cannot copy a directory, '{}', into itself, '{}'",
+            source.display(),
+            destination.display()
+        );
+
+        return Ok(false);
+    }
+
+    if let Err(e) = fs::create_dir(destination) {
+        if e.kind() != io::ErrorKind::AlreadyExists || !destination.is_dir() {
+            handle_error(e, destination);
+
+            return Ok(false);
+        }
+    }
+
+    let read_dir = match fs::read_dir(source) {
+        Ok(read_dir) => read_dir,
+        Err(e) => {
+            handle_error(e, source);
+
+            return Ok(false);
+        }
+    };
+
+    let mut copied = true;
+
+    for entry in read_dir {
+        match entry.and_then(|e| Ok((e.path(), e.file_name(), e.metadata()?))) {
+            Ok((path, name, entry_metadata)) => {
+                copied &= copy(
+                    &path,
+                    &destination.join(name),
+                    &entry_metadata,
+                    options,
+                    input,
+                )?
+            }
+            Err(e) => {
+                handle_error(e, source);
+                copied = false;
+            }
+        }
+    }
+
+    // The timestamps of the directory are preserved last, as copying its content modifies them.
+    if let Err(e) = preserve(destination, metadata, options) {
+        handle_error(e, destination);
+        copied = false;
+    }
+
+    Ok(copied)
+}
+
+/// Sets the mode and timestamps of `destination` to the ones of the source when `-p` is given.
+fn preserve(destination: &Path, metadata: &Metadata, options: &CopyOptions) -> io::Result<()> {
+    if !options.preserve {
+        return Ok(());
+    }
+
+    fs::set_permissions(destination, metadata.permissions())?;
+
+    let times = FileTimes::new()
+        .set_accessed(metadata.accessed()?)
+        .set_modified(metadata.modified()?);
+
+    File::open(destination)?.set_times(times)
+}
+
+/// Removes the file at `path` if there is one, so that a symlink can be created in its place.
+fn remove_existing(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Returns whether `path` is `dir` or lies inside it, once both are resolved. The path itself doesn't
+/// need to exist, only its parent.
+fn is_inside(path: &Path, dir: &Path) -> bool {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    match (
+        fs::canonicalize(parent),
+        path.file_name(),
+        fs::canonicalize(dir),
+    ) {
+        (Ok(parent), Some(name), Ok(dir)) => parent.join(name).starts_with(dir),
+        _ => false,
+    }
+}
+
+fn handle_error(error: io::Error, path: &Path) {
+    match error.kind() {
+        io::ErrorKind::NotFound => {
+            eprintln!("cp : no such file or directory: {}", path.display())
+        }
+        io::ErrorKind::PermissionDenied => {
+            eprintln!("cp : permission denied: {}", path.display())
+        }
+        _ => eprintln!("cp : {}: {}", path.display(), error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        os::unix::fs::PermissionsExt,
+        time::{Duration, SystemTime},
+    };
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn cp(arg: &[&str], input: &str) -> Status {
+        run(input, |streams| execute(args(arg), streams)).0
+    }
+
+    #[test]
+    fn copies_a_file() {
+        let dir = TempDir::new();
+        let source = dir.write("source", "content");
+
+        assert_eq!(cp(&[&source, &dir.join("copy")], ""), Status::SUCCESS);
+        assert_eq!(fs::read_to_string(dir.join("copy")).unwrap(), "content");
+    }
+
+    #[test]
+    fn copies_files_into_a_directory() {
+        let dir = TempDir::new();
+        let a = dir.write("a", "1");
+        let b = dir.write("b", "2");
+        fs::create_dir(dir.join("into")).unwrap();
+
+        assert_eq!(cp(&[&a, &b, &dir.join("into")], ""), Status::SUCCESS);
+        assert_eq!(fs::read_to_string(dir.join("into/a")).unwrap(), "1");
+        assert_eq!(fs::read_to_string(dir.join("into/b")).unwrap(), "2");
+    }
+
+    #[test]
+    fn directories_need_r() {
+        let dir = TempDir::new();
+        fs::create_dir_all(dir.join("tree/sub")).unwrap();
+        fs::write(dir.join("tree/sub/file"), "deep").unwrap();
+        symlink("sub/file", dir.join("tree/link")).unwrap();
+
+        assert_eq!(
+            cp(&[&dir.join("tree"), &dir.join("copy")], ""),
+            Status::FAILURE
+        );
+        assert!(!Path::new(&dir.join("copy")).exists());
+
+        assert_eq!(
+            cp(&["-r", &dir.join("tree"), &dir.join("copy")], ""),
+            Status::SUCCESS
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("copy/sub/file")).unwrap(),
+            "deep"
+        );
+        assert_eq!(
+            fs::read_link(dir.join("copy/link")).unwrap(),
+            Path::new("sub/file")
+        );
+    }
+
+    #[test]
+    fn p_preserves_mode_and_times() {
+        let dir = TempDir::new();
+        let source = dir.write("source", "");
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        fs::set_permissions(&source, fs::Permissions::from_mode(0o741)).unwrap();
+        File::options()
+            .write(true)
+            .open(&source)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+
+        assert_eq!(cp(&["-p", &source, &dir.join("copy")], ""), Status::SUCCESS);
+
+        let copied = fs::metadata(dir.join("copy")).unwrap();
+
+        assert_eq!(copied.permissions().mode() & 0o777, 0o741);
+        assert_eq!(copied.modified().unwrap(), time);
+    }
+
+    #[test]
+    fn i_overwrites_only_when_confirmed() {
+        let dir = TempDir::new();
+        let source = dir.write("source", "new");
+        let destination = dir.write("destination", "old");
+
+        assert_eq!(cp(&["-i", &source, &destination], "n\n"), Status::SUCCESS);
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "old");
+
+        assert_eq!(cp(&["-i", &source, &destination], "y\n"), Status::SUCCESS);
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "new");
+    }
+
+    #[test]
+    fn refuses_to_copy_a_file_onto_itself() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "content");
+
+        assert_eq!(cp(&[&file, &file], ""), Status::FAILURE);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "content");
+    }
+}
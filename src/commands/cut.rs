@@ -0,0 +1,331 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+};
+
+use super::{Status, Streams};
+
+/// What `cut` selects in each line, with the ranges of positions selected, counted from 1.
+enum Selection {
+    Bytes(Vec<(usize, usize)>),
+    Chars(Vec<(usize, usize)>),
+    Fields(Vec<(usize, usize)>),
+}
+
+/// Execute the `cut` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `cut` command.
+///
+/// It prints the selected parts of each line of the files given as arguments: bytes with `-b LIST`,
+/// characters with `-c LIST`, or fields with `-f LIST`, separated by tabs or by the single byte given with
+/// `-d DELIM`. `LIST` is made of positions counted from 1 and separated by commas, each of them being a
+/// single position like `3` or a range like `2-4`, `3-` or `-2`. Lines holding no delimiter are printed
+/// whole in field mode, unless `-s` is given, which leaves them out. The argument `-` stands for the
+/// standard input, which is also read when no file is given.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `cut` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut selection = None;
+    let mut delimiter = b'\t';
+    let mut only_delimited = false;
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let option = match arg.as_str() {
+            "-s" => {
+                only_delimited = true;
+                continue;
+            }
+            _ if ["-b", "-c", "-d", "-f"]
+                .iter()
+                .any(|option| arg.starts_with(option)) =>
+            {
+                arg[1..2].to_string()
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("cut : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => {
+                paths.push(arg);
+                continue;
+            }
+        };
+
+        let value = match &arg[2..] {
+            "" => args.next(),
+            value => Some(value.to_string()),
+        };
+
+        let Some(value) = value else {
+            eprintln!("cut : option requires an argument - '{}'", option);
+
+            return Ok(Status::FAILURE);
+        };
+
+        if option == "d" {
+            match value.as_bytes() {
+                [byte] => delimiter = *byte,
+                _ => {
+                    eprintln!("cut : the delimiter must be a single byte");
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+
+            continue;
+        }
+
+        let Some(ranges) = parse_list(&value) else {
+            eprintln!("cut : invalid list '{}'", value);
+
+            return Ok(Status::FAILURE);
+        };
+
+        if selection.is_some() {
+            eprintln!("cut : only one list may be specified");
+
+            return Ok(Status::FAILURE);
+        }
+
+        selection = Some(match option.as_str() {
+            "b" => Selection::Bytes(ranges),
+            "c" => Selection::Chars(ranges),
+            _ => Selection::Fields(ranges),
+        });
+    }
+
+    let Some(selection) = selection else {
+        eprintln!("cut : you must specify a list of bytes, characters, or fields");
+
+        return Ok(Status::FAILURE);
+    };
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let reader: io::Result<Box<dyn BufRead>> = if path == "-" {
+            Ok(Box::new(&mut *streams.stdin))
+        } else {
+            File::open(&path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+        };
+
+        let mut line = vec![];
+        let result = reader.and_then(|mut reader| loop {
+            line.clear();
+
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                return Ok(());
+            }
+
+            if line.ends_with(b"\n") {
+                line.pop();
+            }
+
+            cut(&line, &selection, delimiter, only_delimited, streams.stdout)?;
+        });
+
+        if let Err(e) = result {
+            if e.kind() == io::ErrorKind::BrokenPipe {
+                return Err(e);
+            }
+
+            handle_error(e, &path);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Parses a list of positions like `1,3,5-7,9-`, into ranges of positions, both ends included.
+fn parse_list(list: &str) -> Option<Vec<(usize, usize)>> {
+    list.split(',')
+        .map(|item| {
+            let (start, end) = match item.split_once('-') {
+                Some(("", "")) => return None,
+                Some((start, end)) => (
+                    if start.is_empty() {
+                        1
+                    } else {
+                        start.parse().ok()?
+                    },
+                    if end.is_empty() {
+                        usize::MAX
+                    } else {
+                        end.parse().ok()?
+                    },
+                ),
+                None => {
+                    let position = item.parse().ok()?;
+
+                    (position, position)
+                }
+            };
+
+            (start > 0 && start <= end).then_some((start, end))
+        })
+        .collect()
+}
+
+fn is_selected(ranges: &[(usize, usize)], position: usize) -> bool {
+    ranges
+        .iter()
+        .any(|(start, end)| (*start..=*end).contains(&position))
+}
+
+/// Prints the selected parts of `line`, given without its newline.
+fn cut(
+    line: &[u8],
+    selection: &Selection,
+    delimiter: u8,
+    only_delimited: bool,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    match selection {
+        Selection::Bytes(ranges) => {
+            let selected: Vec<u8> = line
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| is_selected(ranges, i + 1))
+                .map(|(_, byte)| *byte)
+                .collect();
+
+            writer.write_all(&selected)?;
+        }
+        Selection::Chars(ranges) => {
+            let selected: String = String::from_utf8_lossy(line)
+                .chars()
+                .enumerate()
+                .filter(|(i, _)| is_selected(ranges, i + 1))
+                .map(|(_, c)| c)
+                .collect();
+
+            writer.write_all(selected.as_bytes())?;
+        }
+        Selection::Fields(_) if !line.contains(&delimiter) => {
+            if only_delimited {
+                return Ok(());
+            }
+
+            writer.write_all(line)?;
+        }
+        Selection::Fields(ranges) => {
+            let selected: Vec<&[u8]> = line
+                .split(|byte| *byte == delimiter)
+                .enumerate()
+                .filter(|(i, _)| is_selected(ranges, i + 1))
+                .map(|(_, field)| field)
+                .collect();
+
+            writer.write_all(&selected.join(&delimiter))?;
+        }
+    }
+
+    writer.write_all(b"\n")
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("cut : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("cut : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("cut : is a directory: {}", path),
+        _ => eprintln!("cut : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn cut(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn f_selects_tab_separated_fields() {
+        assert_eq!(
+            cut(&["-f", "2"], "a\tb\tc\n1\t2\t3\n"),
+            (Status::SUCCESS, String::from("b\n2\n"))
+        );
+        assert_eq!(cut(&["-f1,3"], "a\tb\tc\n").1, "a\tc\n");
+    }
+
+    #[test]
+    fn d_sets_the_delimiter() {
+        let input = "root:x:0:0:root:/root:/bin/sh\n";
+
+        assert_eq!(
+            cut(&["-d", ":", "-f", "1,6-"], input).1,
+            "root:/root:/bin/sh\n"
+        );
+        assert_eq!(cut(&["-d:", "-f-2"], input).1, "root:x\n");
+        assert_eq!(cut(&["-d,", "-f", "5"], "a,b\n").1, "\n");
+    }
+
+    #[test]
+    fn lines_without_delimiter_are_printed_unless_s() {
+        let input = "a,b\nnone\n";
+
+        assert_eq!(cut(&["-d,", "-f2"], input).1, "b\nnone\n");
+        assert_eq!(cut(&["-s", "-d,", "-f2"], input).1, "b\n");
+    }
+
+    #[test]
+    fn c_selects_characters() {
+        assert_eq!(cut(&["-c", "1,3"], "abcdef\n").1, "ac\n");
+        assert_eq!(cut(&["-c2-4"], "abcdef\n").1, "bcd\n");
+        assert_eq!(cut(&["-c", "4-"], "abcdef\nab\n").1, "def\n\n");
+        assert_eq!(cut(&["-c", "1-2"], "héllo\n").1, "hé\n");
+    }
+
+    #[test]
+    fn b_selects_bytes() {
+        assert_eq!(cut(&["-b", "1-2"], "héllo\n").1, "h\u{fffd}\n");
+        assert_eq!(cut(&["-b", "1,4"], "abcd").1, "ad\n");
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(parse_list("1,3"), Some(vec![(1, 1), (3, 3)]));
+        assert_eq!(
+            parse_list("2-4,-2,3-"),
+            Some(vec![(2, 4), (1, 2), (3, usize::MAX)])
+        );
+        for list in ["0", "-", "3-2", "a", "1,,2", ""] {
+            assert_eq!(parse_list(list), None, "{}", list);
+        }
+    }
+
+    #[test]
+    fn reads_files_in_order() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "a b\n");
+
+        assert_eq!(cut(&["-d", " ", "-f2", &file, "-"], "c d").1, "b\nd\n");
+    }
+
+    #[test]
+    fn invalid_usage_fails() {
+        for arguments in [
+            &[][..],
+            &["-f"],
+            &["-f", "0"],
+            &["-f1", "-c1"],
+            &["-d", "ab", "-f1"],
+            &["-x"],
+        ] {
+            assert_eq!(cut(arguments, "a\tb\n"), (Status::FAILURE, String::new()));
+        }
+    }
+}
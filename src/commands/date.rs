@@ -0,0 +1,181 @@
+use std::{io, time::SystemTime};
+
+use crate::unix::time::{local_zone, unix_seconds, DateTime, MONTHS, WEEKDAYS};
+
+use super::{Status, Streams};
+
+/// The format of the date printed without `+FORMAT`, like `Thu Oct 15 09:41:07 CEST 2026`.
+const DEFAULT_FORMAT: &str = "%a %b %e %H:%M:%S %Z %Y";
+
+/// Execute the `date` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `date` command.
+///
+/// It prints the current date and time in the local time zone, or in UTC with `-u`. With `+FORMAT`, the
+/// date is printed following `FORMAT`, in which `%Y` stands for the year, `%m` for the month, `%d` for the
+/// day, `%H`, `%M` and `%S` for the hour, minute and second, `%a` and `%b` for the abbreviated names of
+/// the day and month, `%e` for the day padded with a space, `%F` for `%Y-%m-%d`, `%T` for `%H:%M:%S`, `%Z`
+/// for the time zone, `%s` for the seconds since the Unix epoch, and `%%` for a `%`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `date` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut utc = false;
+    let mut format = None;
+
+    for arg in args {
+        if let Some(value) = arg.strip_prefix('+') {
+            if format.is_some() {
+                eprintln!("date : extra operand '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+
+            format = Some(value.to_string());
+            continue;
+        }
+
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            eprintln!("date : invalid date format '{}': must start with '+'", arg);
+
+            return Ok(Status::FAILURE);
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'u' => utc = true,
+                _ => {
+                    eprintln!("date : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    let now = SystemTime::now();
+    let (date, zone) = if utc {
+        (DateTime::utc(now), Some(String::from("UTC")))
+    } else {
+        (DateTime::local(now), local_zone(now))
+    };
+
+    let Some(date) = date else {
+        eprintln!("date : cannot represent the current time");
+
+        return Ok(Status::FAILURE);
+    };
+
+    let format = format.as_deref().unwrap_or(DEFAULT_FORMAT);
+    let zone = zone.unwrap_or_default();
+
+    writeln!(
+        streams.stdout,
+        "{}",
+        format_date(format, &date, unix_seconds(now), &zone)
+    )?;
+
+    Ok(Status::SUCCESS)
+}
+
+/// Formats `date`, lying `seconds` after the Unix epoch in the time zone named `zone`, following a
+/// `+FORMAT` format. Unknown specifiers are kept as they are.
+fn format_date(format: &str, date: &DateTime, seconds: i64, zone: &str) -> String {
+    let mut output = String::new();
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+
+        let Some(specifier) = chars.next() else {
+            output.push('%');
+            break;
+        };
+
+        let value = match specifier {
+            'Y' => date.year.to_string(),
+            'm' => format!("{:02}", date.month),
+            'd' => format!("{:02}", date.day),
+            'e' => format!("{:>2}", date.day),
+            'H' => format!("{:02}", date.hour),
+            'M' => format!("{:02}", date.minute),
+            'S' => format!("{:02}", date.second),
+            'a' => WEEKDAYS[date.weekday() as usize].to_string(),
+            'b' => MONTHS[date.month as usize - 1].to_string(),
+            'F' => format!("{}-{:02}-{:02}", date.year, date.month, date.day),
+            'T' => format!("{:02}:{:02}:{:02}", date.hour, date.minute, date.second),
+            'Z' => zone.to_string(),
+            's' => seconds.to_string(),
+            '%' => String::from("%"),
+            _ => format!("%{}", specifier),
+        };
+
+        output.push_str(&value);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{args, run},
+        unix::time::from_unix_seconds,
+    };
+
+    /// The billionth second since the Unix epoch, Sunday 9 September 2001 at 01:46:40 in UTC.
+    const SECONDS: i64 = 1_000_000_000;
+
+    fn format_utc(format: &str) -> String {
+        let date = DateTime::utc(from_unix_seconds(SECONDS).unwrap()).unwrap();
+
+        format_date(format, &date, SECONDS, "UTC")
+    }
+
+    #[test]
+    fn formats_numeric_specifiers() {
+        assert_eq!(format_utc("%Y-%m-%d %H:%M:%S"), "2001-09-09 01:46:40");
+        assert_eq!(format_utc("%F %T"), "2001-09-09 01:46:40");
+        assert_eq!(format_utc("[%e]"), "[ 9]");
+        assert_eq!(format_utc("%s"), "1000000000");
+    }
+
+    #[test]
+    fn formats_the_default_format() {
+        assert_eq!(format_utc(DEFAULT_FORMAT), "Sun Sep  9 01:46:40 UTC 2001");
+    }
+
+    #[test]
+    fn keeps_unknown_specifiers_and_escapes_percent() {
+        assert_eq!(format_utc("100%% %q %"), "100% %q %");
+    }
+
+    #[test]
+    fn u_prints_the_current_time_in_utc() {
+        let before = unix_seconds(SystemTime::now());
+        let (status, output) = run("", |streams| execute(args(&["-u", "+%s %Z"]), streams));
+        let after = unix_seconds(SystemTime::now());
+
+        let (seconds, zone) = output.trim_end().split_once(' ').unwrap();
+        let seconds: i64 = seconds.parse().unwrap();
+
+        assert_eq!(status, Status::SUCCESS);
+        assert!((before..=after).contains(&seconds));
+        assert_eq!(zone, "UTC");
+    }
+
+    #[test]
+    fn invalid_arguments_fail() {
+        for arguments in [&["%Y"][..], &["-x"], &["+%Y", "+%m"]] {
+            let (status, output) = run("", |streams| execute(args(arguments), streams));
+
+            assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    collections::HashSet,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+use crate::{format::human_size, unix::filesystem};
+
+use super::{Status, Streams};
+
+/// The table of the mounted filesystems maintained by the kernel.
+const MOUNTS_FILE: &str = "/proc/mounts";
+
+/// A mounted filesystem, as listed in `/proc/mounts`.
+struct Mount {
+    source: String,
+    target: String,
+}
+
+/// Execute the `df` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `df` command.
+///
+/// It prints the size, used space, available space and use percentage of each mounted filesystem, in
+/// kibibytes, followed by its mount point. The mounted filesystems are read from `/proc/mounts`, so this
+/// command is Linux-specific. Pseudo filesystems, which have a size of zero like `proc`, are only printed
+/// with `-a`, and so are the repeated mounts of a mount point. With `-h`, sizes are printed in a human readable format, like `ls -h`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `df` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut all = false;
+    let mut human_readable = false;
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            eprintln!("df : extra operand '{}'", arg);
+
+            return Ok(Status::FAILURE);
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'a' => all = true,
+                'h' => human_readable = true,
+                _ => {
+                    eprintln!("df : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    let mounts = match fs::read_to_string(MOUNTS_FILE) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("df : cannot read {}: {}", MOUNTS_FILE, e);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    let format_size = |size: u64| {
+        if human_readable {
+            human_size(size)
+        } else {
+            size.div_ceil(1024).to_string()
+        }
+    };
+
+    let header = if human_readable {
+        ["Filesystem", "Size", "Used", "Avail", "Use%", "Mounted on"]
+    } else {
+        [
+            "Filesystem",
+            "1K-blocks",
+            "Used",
+            "Available",
+            "Use%",
+            "Mounted on",
+        ]
+    };
+
+    let mut rows = vec![header.map(String::from)];
+    let mut listed = HashSet::new();
+    let mut status = Status::SUCCESS;
+
+    for mount in mounts.lines().filter_map(parse_mount) {
+        // A mount point mounted several times is listed once, unless every filesystem is asked for.
+        if !listed.insert(mount.target.clone()) && !all {
+            continue;
+        }
+
+        let usage = match filesystem::usage(Path::new(&mount.target)) {
+            Ok(usage) => usage,
+            Err(e) => {
+                // Like GNU df, filesystems that cannot be queried are only reported when asked for all.
+                if all {
+                    eprintln!("df : {}: {}", mount.target, e);
+                    status = Status::FAILURE;
+                }
+
+                continue;
+            }
+        };
+
+        if usage.total == 0 && !all {
+            continue;
+        }
+
+        let used = usage.total - usage.free;
+        // The percentage is relative to the space usable by unprivileged users, and rounded up.
+        let usable = used + usage.available;
+        let percentage = match usable {
+            0 => String::from("-"),
+            _ => format!("{}%", (used * 100).div_ceil(usable)),
+        };
+
+        rows.push([
+            mount.source,
+            format_size(usage.total),
+            format_size(used),
+            format_size(usage.available),
+            percentage,
+            mount.target,
+        ]);
+    }
+
+    print_rows(&rows, streams.stdout)?;
+
+    Ok(status)
+}
+
+/// Parses a line of `/proc/mounts`, made of the source, the mount point, the filesystem type, the mount
+/// options and two numbers, separated by spaces. Returns `None` if the line is malformed.
+fn parse_mount(line: &str) -> Option<Mount> {
+    let mut fields = line.split_whitespace();
+
+    Some(Mount {
+        source: unescape(fields.next()?),
+        target: unescape(fields.next()?),
+    })
+}
+
+/// Decodes the octal escapes used in `/proc/mounts` for the spaces, tabs, newlines and backslashes of the
+/// paths, like `\040` for a space.
+fn unescape(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escape = bytes.get(i + 1..i + 4).filter(|digits| {
+            bytes[i] == b'\\' && digits.iter().all(|digit| (b'0'..=b'7').contains(digit))
+        });
+
+        match escape {
+            Some(digits) => {
+                decoded.push(
+                    digits
+                        .iter()
+                        .fold(0u8, |value, digit| value.wrapping_mul(8) + (digit - b'0')),
+                );
+                i += 4;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Prints the rows in aligned columns: the first and last ones aligned to the left, the others, holding
+/// numbers, to the right.
+fn print_rows(rows: &[[String; 6]], writer: &mut dyn Write) -> io::Result<()> {
+    let mut widths = [0; 6];
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in rows {
+        writeln!(
+            writer,
+            "{:<w0$} {:>w1$} {:>w2$} {:>w3$} {:>w4$} {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            row[5],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    #[test]
+    fn parses_a_line_of_the_mount_table() {
+        let mount = parse_mount("/dev/sda1 / ext4 rw,relatime 0 0").unwrap();
+
+        assert_eq!(
+            (mount.source.as_str(), mount.target.as_str()),
+            ("/dev/sda1", "/")
+        );
+    }
+
+    #[test]
+    fn decodes_the_escaped_characters_of_paths() {
+        let mount = parse_mount(r"//server/my\040share /mnt/a\134b\011c cifs rw 0 0").unwrap();
+
+        assert_eq!(mount.source, "//server/my share");
+        assert_eq!(mount.target, "/mnt/a\\b\tc");
+        assert_eq!(unescape(r"a\04"), r"a\04");
+    }
+
+    #[test]
+    fn malformed_line_is_skipped() {
+        assert!(parse_mount("").is_none());
+        assert!(parse_mount("none").is_none());
+    }
+
+    #[test]
+    fn rows_are_aligned() {
+        let rows = [
+            ["Filesystem", "Size", "Used", "Avail", "Use%", "Mounted on"],
+            ["/dev/sda1", "100G", "40G", "60G", "40%", "/"],
+        ]
+        .map(|row| row.map(String::from));
+        let mut output = vec![];
+
+        print_rows(&rows, &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Filesystem Size Used Avail Use% Mounted on\n\
+             /dev/sda1  100G  40G   60G  40% /\n"
+        );
+    }
+
+    #[test]
+    fn lists_the_mounted_filesystems_under_a_header() {
+        let (status, output) = run("", |streams| execute(args(&[]), streams));
+
+        assert_eq!(status, Status::SUCCESS);
+        assert!(output.starts_with("Filesystem"), "{}", output);
+        assert!(output.lines().next().unwrap().ends_with(" Mounted on"));
+    }
+
+    #[test]
+    fn operand_fails() {
+        let (status, output) = run("", |streams| execute(args(&["/"]), streams));
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+    }
+}
@@ -0,0 +1,348 @@
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    ops::Range,
+};
+
+use super::{Status, Streams};
+
+/// The number of unchanged lines printed around the changes when none is given.
+const DEFAULT_CONTEXT: usize = 3;
+
+/// The status of `diff` when an error occurred, whether the files differ or not.
+const TROUBLE: Status = Status {
+    code: 2,
+    exit: false,
+};
+
+/// A step of the edit script turning the old lines into the new ones, with the index of the line, in the
+/// old lines for the kept and removed ones, and in the new lines for the added ones.
+#[derive(Clone, Copy)]
+enum Edit {
+    Keep(usize),
+    Remove(usize),
+    Add(usize),
+}
+
+/// Execute the `diff` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `diff` command.
+///
+/// It compares two files line by line and prints their differences in the unified format: the removed
+/// lines preceded by `-` and the added ones by `+`, grouped in hunks with 3 unchanged lines of context
+/// around them, or `N` lines with `-U N`. Identical files print nothing. The differences are found from
+/// the longest common subsequence of the lines, whose computation takes a time and memory proportional to
+/// the product of the numbers of lines once the common beginning and end are left out. One of the files
+/// may be `-`, which stands for the standard input.
+///
+/// Like other implementations, the status is 0 when the files are identical, 1 when they differ, and 2
+/// when an error occurred.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `diff` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut context = DEFAULT_CONTEXT;
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // The unified format is the only one, so `-u` is accepted for compatibility.
+            "-u" => {}
+            "-U" => match args.next().map(|value| value.parse()) {
+                Some(Ok(lines)) => context = lines,
+                Some(Err(_)) | None => {
+                    eprintln!("diff : option requires a number - 'U'");
+
+                    return Ok(TROUBLE);
+                }
+            },
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("diff : invalid option - '{}'", arg);
+
+                return Ok(TROUBLE);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    let [old_path, new_path] = match <[String; 2]>::try_from(paths) {
+        Ok(paths) => paths,
+        Err(paths) if paths.len() < 2 => {
+            eprintln!("diff : missing operand");
+
+            return Ok(TROUBLE);
+        }
+        Err(paths) => {
+            eprintln!("diff : extra operand '{}'", paths[2]);
+
+            return Ok(TROUBLE);
+        }
+    };
+
+    let mut contents = [vec![], vec![]];
+
+    for (content, path) in contents.iter_mut().zip([&old_path, &new_path]) {
+        let read = if path == "-" {
+            streams.stdin.read_to_end(content)
+        } else {
+            File::open(path).and_then(|mut file| file.read_to_end(content))
+        };
+
+        if let Err(e) = read {
+            handle_error(e, path);
+
+            return Ok(TROUBLE);
+        }
+    }
+
+    // Lines keep their newline, so that a last line without one differs from the same line with one.
+    let [old, new] = contents
+        .each_ref()
+        .map(|content| content.split_inclusive(|b| *b == b'\n').collect::<Vec<_>>());
+
+    let edits = edit_script(&old, &new);
+
+    if edits.iter().all(|edit| matches!(edit, Edit::Keep(_))) {
+        return Ok(Status::SUCCESS);
+    }
+
+    writeln!(streams.stdout, "--- {}", old_path)?;
+    writeln!(streams.stdout, "+++ {}", new_path)?;
+
+    for hunk in hunks(&edits, context) {
+        print_hunk(&edits, hunk, &old, &new, streams.stdout)?;
+    }
+
+    Ok(Status::FAILURE)
+}
+
+/// Computes the shortest edit script turning `old` into `new`, from their longest common subsequence.
+fn edit_script(old: &[&[u8]], new: &[&[u8]]) -> Vec<Edit> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+    let (rows, columns) = (old_middle.len(), new_middle.len());
+
+    // `lengths[i][j]` is the length of the longest common subsequence of the middle lines from `i` and `j`.
+    let mut lengths = vec![vec![0u32; columns + 1]; rows + 1];
+
+    for i in (0..rows).rev() {
+        for j in (0..columns).rev() {
+            lengths[i][j] = if old_middle[i] == new_middle[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits: Vec<Edit> = (0..prefix).map(Edit::Keep).collect();
+    let (mut i, mut j) = (0, 0);
+
+    while i < rows || j < columns {
+        if i < rows && j < columns && old_middle[i] == new_middle[j] {
+            edits.push(Edit::Keep(prefix + i));
+            i += 1;
+            j += 1;
+        } else if j == columns || (i < rows && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            edits.push(Edit::Remove(prefix + i));
+            i += 1;
+        } else {
+            edits.push(Edit::Add(prefix + j));
+            j += 1;
+        }
+    }
+
+    edits.extend((0..suffix).map(|k| Edit::Keep(prefix + rows + k)));
+
+    edits
+}
+
+/// Groups the edits into hunks holding the changes with `context` unchanged lines around them, returned
+/// as ranges of edits. Changes separated by at most twice the context share a hunk.
+fn hunks(edits: &[Edit], context: usize) -> Vec<Range<usize>> {
+    let mut hunks: Vec<Range<usize>> = vec![];
+
+    for (i, edit) in edits.iter().enumerate() {
+        if matches!(edit, Edit::Keep(_)) {
+            continue;
+        }
+
+        let hunk = i.saturating_sub(context)..(i + context + 1).min(edits.len());
+
+        match hunks.last_mut() {
+            Some(last) if hunk.start <= last.end => last.end = hunk.end,
+            _ => hunks.push(hunk),
+        }
+    }
+
+    hunks
+}
+
+/// Prints the hunk made of `edits[hunk]`: its header with the ranges of lines it covers, then its lines.
+fn print_hunk(
+    edits: &[Edit],
+    hunk: Range<usize>,
+    old: &[&[u8]],
+    new: &[&[u8]],
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    let is_old = |edit: &&Edit| !matches!(edit, Edit::Add(_));
+    let is_new = |edit: &&Edit| !matches!(edit, Edit::Remove(_));
+
+    let old_before = edits[..hunk.start].iter().filter(is_old).count();
+    let new_before = edits[..hunk.start].iter().filter(is_new).count();
+    let old_count = edits[hunk.clone()].iter().filter(is_old).count();
+    let new_count = edits[hunk.clone()].iter().filter(is_new).count();
+
+    writeln!(
+        writer,
+        "@@ -{} +{} @@",
+        range(old_before, old_count),
+        range(new_before, new_count)
+    )?;
+
+    for edit in &edits[hunk] {
+        let (marker, line) = match *edit {
+            Edit::Keep(i) => (b' ', old[i]),
+            Edit::Remove(i) => (b'-', old[i]),
+            Edit::Add(j) => (b'+', new[j]),
+        };
+
+        writer.write_all(&[marker])?;
+        writer.write_all(line)?;
+
+        if !line.ends_with(b"\n") {
+            writer.write_all(b"\n\\ No newline at end of file\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Formats the range of `count` lines following the first `before` ones for a hunk header. The count is
+/// left out when it is 1, and an empty range is located by the line before it.
+fn range(before: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", before),
+        1 => (before + 1).to_string(),
+        _ => format!("{},{}", before + 1, count),
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("diff : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("diff : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("diff : is a directory: {}", path),
+        _ => eprintln!("diff : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    /// Compares `old`, read from the standard input, with a file holding `new`, returning the status and
+    /// the hunks printed after the two header lines.
+    fn diff(options: &[&str], old: &str, new: &str) -> (Status, String) {
+        let dir = TempDir::new();
+        let path = dir.write("new", new);
+        let mut arguments = options.to_vec();
+        arguments.extend(["-", path.as_str()]);
+
+        let (status, output) = run(old, |streams| execute(args(&arguments), streams));
+
+        match output.strip_prefix(&format!("--- -\n+++ {}\n", path)) {
+            Some(hunks) => (status, hunks.to_string()),
+            None => (status, output),
+        }
+    }
+
+    #[test]
+    fn identical_files_print_nothing() {
+        assert_eq!(
+            diff(&[], "a\nb\n", "a\nb\n"),
+            (Status::SUCCESS, String::new())
+        );
+        assert_eq!(diff(&[], "", ""), (Status::SUCCESS, String::new()));
+    }
+
+    #[test]
+    fn added_line_is_preceded_by_a_plus() {
+        assert_eq!(
+            diff(&[], "a\nb\nc\n", "a\nb\nnew\nc\n"),
+            (
+                Status::FAILURE,
+                String::from("@@ -1,3 +1,4 @@\n a\n b\n+new\n c\n")
+            )
+        );
+    }
+
+    #[test]
+    fn removed_line_is_preceded_by_a_minus() {
+        assert_eq!(
+            diff(&[], "a\nb\nc\n", "a\nc\n"),
+            (
+                Status::FAILURE,
+                String::from("@@ -1,3 +1,2 @@\n a\n-b\n c\n")
+            )
+        );
+    }
+
+    #[test]
+    fn changed_line_is_removed_then_added() {
+        assert_eq!(diff(&[], "a\n", "b\n").1, "@@ -1 +1 @@\n-a\n+b\n");
+        assert_eq!(diff(&[], "", "a\n").1, "@@ -0,0 +1 @@\n+a\n");
+    }
+
+    #[test]
+    fn context_limits_the_unchanged_lines_of_each_hunk() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let new = "one\n2\n3\n4\n5\n6\n7\n8\n9\nten\n";
+
+        assert_eq!(
+            diff(&["-U", "1"], old, new).1,
+            "@@ -1,2 +1,2 @@\n-1\n+one\n 2\n@@ -9,2 +9,2 @@\n 9\n-10\n+ten\n"
+        );
+        assert_eq!(diff(&["-u"], old, new).1.matches("@@ -").count(), 2);
+        assert_eq!(diff(&["-U", "4"], old, new).1.matches("@@ -").count(), 1);
+    }
+
+    #[test]
+    fn missing_newline_at_the_end_is_a_difference() {
+        assert_eq!(
+            diff(&["-U", "0"], "a\n", "a").1,
+            "@@ -1 +1 @@\n-a\n+a\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn errors_have_their_own_status() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "a\n");
+
+        for arguments in [
+            &[file.as_str()][..],
+            &[&file, &file, &file],
+            &["-U", "x", &file, &file],
+            &["-x", &file, &file],
+            &[&file, &dir.join("missing")],
+        ] {
+            let (status, output) = run("", |streams| execute(args(arguments), streams));
+
+            assert_eq!((status, output.as_str()), (TROUBLE, ""));
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::io;
+
+use super::{Status, Streams};
+
+/// Execute the `dirname` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `dirname` command.
+///
+/// It prints each path given as argument without its last component, on its own line. Trailing slashes
+/// are ignored, a path without a slash gives `.`, and a path made of slashes only gives `/`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `dirname` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    if args.is_empty() {
+        eprintln!("dirname : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    for path in args {
+        writeln!(streams.stdout, "{}", dirname(&path))?;
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Returns `path` without its last component and the slashes around it.
+fn dirname(path: &str) -> &str {
+    let trimmed = path.trim_end_matches('/');
+
+    if trimmed.is_empty() && !path.is_empty() {
+        return "/";
+    }
+
+    match trimmed.rfind('/') {
+        Some(i) => match trimmed[..i].trim_end_matches('/') {
+            "" => "/",
+            parent => parent,
+        },
+        None => ".",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    #[test]
+    fn prints_the_directory_of_each_path() {
+        let (status, output) = run("", |streams| {
+            execute(args(&["/a/b/c", "c", "/", "a/b"]), streams)
+        });
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "/a/b\n.\n/\na\n");
+    }
+
+    #[test]
+    fn trailing_and_repeated_slashes_are_ignored() {
+        assert_eq!(dirname("/a/b/"), "/a");
+        assert_eq!(dirname("/a//b"), "/a");
+        assert_eq!(dirname("/a"), "/");
+        assert_eq!(dirname("//a//"), "/");
+        assert_eq!(dirname("a/"), ".");
+        assert_eq!(dirname(""), ".");
+    }
+
+    #[test]
+    fn missing_operand_fails() {
+        assert_eq!(
+            run("", |streams| execute(vec![], streams)).0,
+            Status::FAILURE
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use std::{
+    collections::HashSet,
+    fs,
+    io::{self, Write},
+    os::linux::fs::MetadataExt,
+    path::Path,
+};
+
+use crate::format::human_size;
+
+use super::{Status, Streams};
+
+/// The size of the blocks counted by `st_blocks`, whatever the block size of the filesystem.
+const BLOCK_SIZE: u64 = 512;
+
+/// How `du` prints the sizes it computes.
+struct Options {
+    human_readable: bool,
+    /// The depth below which directories are counted without being printed, 0 for `-s`.
+    max_depth: Option<usize>,
+}
+
+/// Execute the `du` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `du` command.
+///
+/// It prints the disk usage of each directory below the paths given as arguments, `.` by default, and of
+/// the paths themselves, in kibibytes. Sizes are computed from the blocks allocated to the files rather
+/// than from their apparent sizes, and files with several hard links are counted once. With `-h`, sizes
+/// are printed in a human readable format, like `ls -h`. With `-d N`, only directories at most `N` levels
+/// below the paths are printed, and `-s` only prints the paths themselves, like `-d 0`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `du` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut options = Options {
+        human_readable: false,
+        max_depth: None,
+    };
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "-d" {
+            match args.next().map(|value| value.parse()) {
+                Some(Ok(depth)) => options.max_depth = Some(depth),
+                Some(Err(_)) | None => {
+                    eprintln!("du : option requires a number - 'd'");
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+
+            continue;
+        }
+
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            paths.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'h' => options.human_readable = true,
+                's' => options.max_depth = Some(0),
+                _ => {
+                    eprintln!("du : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("."));
+    }
+
+    // The inodes of the files with several hard links already counted, shared by every path.
+    let mut seen = HashSet::new();
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let (_, complete) = disk_usage(Path::new(&path), 0, &options, &mut seen, streams.stdout)?;
+
+        if !complete {
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Computes the disk usage of the tree at `path` in bytes, printing the directories within the maximum
+/// depth after their content. Returns the usage along with whether the whole tree could be read.
+fn disk_usage(
+    path: &Path,
+    depth: usize,
+    options: &Options,
+    seen: &mut HashSet<(u64, u64)>,
+    writer: &mut dyn Write,
+) -> io::Result<(u64, bool)> {
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            handle_error(e, path);
+
+            return Ok((0, false));
+        }
+    };
+
+    if metadata.st_nlink() > 1
+        && !metadata.is_dir()
+        && !seen.insert((metadata.st_dev(), metadata.st_ino()))
+    {
+        return Ok((0, true));
+    }
+
+    let mut usage = metadata.st_blocks() * BLOCK_SIZE;
+    let mut complete = true;
+
+    if metadata.is_dir() {
+        match fs::read_dir(path) {
+            Ok(entries) => {
+                for entry in entries {
+                    match entry {
+                        Ok(entry) => {
+                            let (child_usage, child_complete) =
+                                disk_usage(&entry.path(), depth + 1, options, seen, writer)?;
+
+                            usage += child_usage;
+                            complete &= child_complete;
+                        }
+                        Err(e) => {
+                            handle_error(e, path);
+                            complete = false;
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                handle_error(e, path);
+                complete = false;
+            }
+        }
+    }
+
+    // Files are only printed when given as arguments, and directories when within the maximum depth.
+    let printed =
+        (metadata.is_dir() || depth == 0) && options.max_depth.is_none_or(|max| depth <= max);
+
+    if printed {
+        let size = if options.human_readable {
+            human_size(usage)
+        } else {
+            usage.div_ceil(1024).to_string()
+        };
+
+        writeln!(writer, "{}\t{}", size, path.display())?;
+    }
+
+    Ok((usage, complete))
+}
+
+fn handle_error(error: io::Error, path: &Path) {
+    match error.kind() {
+        io::ErrorKind::NotFound => {
+            eprintln!("du : no such file or directory: {}", path.display())
+        }
+        io::ErrorKind::PermissionDenied => {
+            eprintln!("du : permission denied: {}", path.display())
+        }
+        _ => eprintln!("du : {}: {}", path.display(), error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    /// Creates a small tree: `a` of 10 KB, `sub/b` of 20 KB and `sub/deep/c` of 30 KB.
+    fn tree() -> TempDir {
+        let dir = TempDir::new();
+
+        fs::create_dir_all(dir.join("sub/deep")).unwrap();
+        dir.write("a", &"a".repeat(10_000));
+        dir.write("sub/b", &"b".repeat(20_000));
+        dir.write("sub/deep/c", &"c".repeat(30_000));
+
+        dir
+    }
+
+    /// Returns the disk usage in kilobytes of the paths, counted from their blocks.
+    fn kilobytes(paths: &[String]) -> u64 {
+        let blocks: u64 = paths
+            .iter()
+            .map(|path| fs::symlink_metadata(path).unwrap().st_blocks())
+            .sum();
+
+        (blocks * BLOCK_SIZE).div_ceil(1024)
+    }
+
+    fn du(arg: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arg), streams))
+    }
+
+    #[test]
+    fn prints_each_directory_after_its_content() {
+        let dir = tree();
+        let root = dir.join("");
+        let root = root.trim_end_matches('/');
+
+        let (status, output) = du(&[root]);
+
+        let deep = [dir.join("sub/deep"), dir.join("sub/deep/c")];
+        let sub = [dir.join("sub"), dir.join("sub/b")];
+        let all = [&deep[..], &sub[..], &[root.to_string(), dir.join("a")]].concat();
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(
+            output,
+            format!(
+                "{}\t{}\n{}\t{}\n{}\t{}\n",
+                kilobytes(&deep),
+                deep[0],
+                kilobytes(&[&deep[..], &sub[..]].concat()),
+                sub[0],
+                kilobytes(&all),
+                root
+            )
+        );
+    }
+
+    #[test]
+    fn s_prints_the_total_only() {
+        let dir = tree();
+        let root = dir.join("sub");
+
+        let (_, summary) = du(&["-s", &root]);
+        let (_, depth_zero) = du(&["-d", "0", &root]);
+
+        let all = [
+            dir.join("sub"),
+            dir.join("sub/b"),
+            dir.join("sub/deep"),
+            dir.join("sub/deep/c"),
+        ];
+
+        assert_eq!(summary, format!("{}\t{}\n", kilobytes(&all), root));
+        assert_eq!(depth_zero, summary);
+    }
+
+    #[test]
+    fn d_limits_the_depth_printed() {
+        let dir = tree();
+        let root = dir.join("");
+        let root = root.trim_end_matches('/');
+
+        let (_, output) = du(&["-d", "1", root]);
+        let paths: Vec<&str> = output
+            .lines()
+            .map(|line| line.split('\t').nth(1).unwrap())
+            .collect();
+
+        assert_eq!(paths, [dir.join("sub").as_str(), root]);
+    }
+
+    #[test]
+    fn files_given_as_arguments_are_printed() {
+        let dir = tree();
+        let file = dir.join("a");
+
+        let (_, output) = du(&[&file]);
+
+        assert_eq!(
+            output,
+            format!("{}\t{}\n", kilobytes(&[dir.join("a")]), file)
+        );
+    }
+
+    #[test]
+    fn hard_links_are_counted_once() {
+        let dir = TempDir::new();
+        fs::create_dir(dir.join("root")).unwrap();
+        let file = dir.write("root/file", &"x".repeat(50_000));
+        fs::hard_link(&file, dir.join("root/link")).unwrap();
+
+        let (_, output) = du(&["-s", &dir.join("root")]);
+
+        assert_eq!(
+            output,
+            format!(
+                "{}\t{}\n",
+                kilobytes(&[dir.join("root"), file]),
+                dir.join("root")
+            )
+        );
+    }
+
+    #[test]
+    fn h_prints_human_readable_sizes() {
+        let dir = tree();
+        let file = dir.join("a");
+        let bytes = fs::metadata(&file).unwrap().st_blocks() * BLOCK_SIZE;
+
+        let (_, output) = du(&["-h", &file]);
+
+        assert_eq!(output, format!("{}\t{}\n", human_size(bytes), file));
+    }
+
+    #[test]
+    fn missing_path_fails() {
+        let dir = TempDir::new();
+
+        assert_eq!(
+            du(&[&dir.join("missing")]),
+            (Status::FAILURE, String::new())
+        );
+    }
+}
@@ -1,18 +1,203 @@
 use std::io;
 
+use super::{print_help, Status, Streams};
+
+const HELP: &str = "\
+Usage: echo [-ne] [STRING]...
+Print the strings, separated by spaces, followed by a newline.
+
+  -n  do not print the trailing newline
+  -e  interpret backslash escapes: \\n, \\t, \\r, \\\\, \\0NNN and \\c, which stops the output
+";
+
 /// Execute the `echo` command with the provided arguments.
 ///
 /// This function takes a vector of strings `args` representing the arguments passed to the `echo` command.
 ///
-/// It performs the logic for the `echo` linux command.
+/// It performs the logic for the `echo` linux command. The very first argument may hold options: `-n`
+/// suppresses the trailing newline and `-e` enables the interpretation of backslash escapes. Both can be
+/// combined, as in `-ne`.
 ///
 /// # Arguments
 ///
 /// * `args` - A vector of strings representing the arguments for the `echo` command.
-pub fn execute(args: Vec<String>) -> io::Result<bool> {
-    let result = String::from(args.join(" ").trim());
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    if print_help(&args, HELP, streams)? {
+        return Ok(Status::SUCCESS);
+    }
+
+    let options = args.first().and_then(|arg| parse_options(arg));
+    let (no_newline, escapes) = options.unwrap_or((false, false));
+    let args = if options.is_some() {
+        &args[1..]
+    } else {
+        &args[..]
+    };
+
+    let text = args.join(" ");
+
+    // The output is built as bytes, since escapes such as `\0377` may produce bytes that aren't valid
+    // UTF-8, and written at once.
+    let (mut output, stopped) = if escapes {
+        interpret_escapes(&text)
+    } else {
+        (text.as_bytes().to_vec(), false)
+    };
+
+    if !no_newline && !stopped {
+        output.push(b'\n');
+    }
+
+    streams.stdout.write_all(&output)?;
+    streams.stdout.flush()?;
+
+    Ok(Status::SUCCESS)
+}
+
+/// Parses an argument made of the `echo` option letters, returning whether the trailing newline must be
+/// suppressed and whether escapes must be interpreted. Returns `None` if the argument holds anything else.
+fn parse_options(arg: &str) -> Option<(bool, bool)> {
+    let letters = arg
+        .strip_prefix('-')
+        .filter(|letters| !letters.is_empty())?;
+
+    if !letters.chars().all(|c| c == 'n' || c == 'e') {
+        return None;
+    }
+
+    Some((letters.contains('n'), letters.contains('e')))
+}
+
+/// Interprets the backslash escapes of `input`.
+///
+/// Supported escapes are `\n`, `\t`, `\r`, `\\`, `\0NNN` (up to three octal digits) and `\c`, which stops
+/// the output. Any other escape, as well as a lone trailing backslash, is kept literally.
+///
+/// Returns the interpreted bytes along with whether a `\c` escape was met.
+pub fn interpret_escapes(input: &str) -> (Vec<u8>, bool) {
+    let mut output = vec![];
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            push_char(&mut output, c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => output.push(b'\n'),
+            Some('t') => output.push(b'\t'),
+            Some('r') => output.push(b'\r'),
+            Some('\\') => output.push(b'\\'),
+            Some('0') => {
+                let mut value = 0;
+
+                for _ in 0..3 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+
+                // Like other implementations, the value is truncated to a single byte.
+                output.push(value as u8);
+            }
+            Some('c') => return (output, true),
+            Some(other) => {
+                output.push(b'\\');
+                push_char(&mut output, other);
+            }
+            None => output.push(b'\\'),
+        }
+    }
+
+    (output, false)
+}
+
+fn push_char(output: &mut Vec<u8>, c: char) {
+    output.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn echo(arg: &[&str]) -> String {
+        run("", |streams| execute(args(arg), streams)).1
+    }
+
+    #[test]
+    fn prints_arguments_followed_by_newline() {
+        assert_eq!(echo(&["a", "b"]), "a b\n");
+    }
+
+    #[test]
+    fn n_suppresses_the_newline() {
+        assert_eq!(echo(&["-n", "hi"]), "hi");
+    }
+
+    #[test]
+    fn n_is_only_an_option_as_first_argument() {
+        assert_eq!(echo(&["-n", "-n", "hi"]), "-n hi");
+        assert_eq!(echo(&["hi", "-n"]), "hi -n\n");
+    }
+
+    #[test]
+    fn escapes_are_literal_without_e() {
+        assert_eq!(echo(&[r"a\tb"]), "a\\tb\n");
+    }
+
+    #[test]
+    fn e_interprets_each_escape() {
+        assert_eq!(echo(&["-e", r"a\nb"]), "a\nb\n");
+        assert_eq!(echo(&["-e", r"a\tb"]), "a\tb\n");
+        assert_eq!(echo(&["-e", r"a\rb"]), "a\rb\n");
+        assert_eq!(echo(&["-e", r"a\\b"]), "a\\b\n");
+        assert_eq!(echo(&["-e", r"a\0101b"]), "aAb\n");
+    }
+
+    #[test]
+    fn e_stops_at_c() {
+        assert_eq!(echo(&["-e", r"a\cb"]), "a");
+    }
+
+    #[test]
+    fn e_keeps_unknown_and_trailing_backslashes() {
+        assert_eq!(echo(&["-e", r"\q a\"]), "\\q a\\\n");
+    }
+
+    /// Runs `echo`, returning the exact bytes it wrote.
+    fn echo_bytes(arg: &[&str]) -> Vec<u8> {
+        let mut stdin = Cursor::new(vec![]);
+        let mut stdout = vec![];
+        let mut streams = Streams {
+            stdin: &mut stdin,
+            stdout: &mut stdout,
+            terminal: false,
+            shell_stdin: false,
+        };
+
+        execute(args(arg), &mut streams).unwrap();
+
+        stdout
+    }
 
-    println!("{0}", result);
+    #[test]
+    fn writes_the_bytes_of_the_text_and_the_newline() {
+        assert_eq!(echo_bytes(&["héllo", "wörld"]), "héllo wörld\n".as_bytes());
+        assert_eq!(echo_bytes(&[]), b"\n");
+    }
 
-    Ok(true)
+    #[test]
+    fn e_writes_bytes_that_are_not_utf8() {
+        assert_eq!(echo_bytes(&["-e", r"\0377\0200"]), [0xff, 0x80, b'\n']);
+        assert_eq!(echo_bytes(&["-ne", r"a\0"]), [b'a', 0]);
+    }
 }
@@ -0,0 +1,205 @@
+use std::{env, ffi::OsString, io};
+
+use super::{export::sorted_vars, EnvRunner, Status, Streams};
+use crate::parser::is_variable_name;
+
+/// The variables set by `env` for the command it runs, overriding the environment of the shell.
+///
+/// They are kept in the state of the shell rather than set in its environment, which is shared by every
+/// command of a pipeline. External programs are spawned with them, and the commands of the shell look
+/// variables up through [`Environment::var_os`].
+#[derive(Debug, Default, Clone)]
+pub struct Environment {
+    overrides: Vec<(String, String)>,
+}
+
+impl Environment {
+    /// Sets the variable `name` to `value` in this environment only.
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.overrides.retain(|(overridden, _)| overridden != name);
+        self.overrides.push((name.to_string(), value.to_string()));
+    }
+
+    /// Returns the value of the variable `name`, its overriding value coming first.
+    pub fn var_os(&self, name: &str) -> Option<OsString> {
+        match self
+            .overrides
+            .iter()
+            .find(|(overridden, _)| overridden == name)
+        {
+            Some((_, value)) => Some(OsString::from(value)),
+            None => env::var_os(name),
+        }
+    }
+
+    /// Returns the overriding variables, which programs are spawned with.
+    pub fn overrides(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.overrides
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Returns the names and values of every variable, sorted by name.
+    pub fn vars(&self) -> Vec<(String, String)> {
+        let mut vars: Vec<(String, String)> = sorted_vars()
+            .into_iter()
+            .filter(|(name, _)| {
+                !self
+                    .overrides
+                    .iter()
+                    .any(|(overridden, _)| overridden == name)
+            })
+            .chain(self.overrides.iter().cloned())
+            .collect();
+
+        vars.sort();
+
+        vars
+    }
+}
+
+/// Execute the `env` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `env` command.
+///
+/// The leading arguments of the form `NAME=VALUE` set environment variables for the duration of the
+/// command. The remaining arguments name a command and its arguments, which is then run with the modified
+/// environment; with no command, the environment is printed as `NAME=VALUE` lines, sorted by name.
+///
+/// The command is dispatched through the commands of the shell, by `run`, rather than spawned as a new
+/// process. It is given a copy of `environment` holding the variables, so that the environment of the
+/// shell itself, shared by the other commands of a pipeline, is left untouched.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `env` command.
+/// * `environment` - The environment `env` runs in.
+/// * `streams` - The standard streams of the command.
+/// * `run` - Runs a command of the shell with its arguments in the given environment.
+pub fn execute(
+    args: Vec<String>,
+    environment: &Environment,
+    streams: &mut Streams,
+    run: &mut EnvRunner,
+) -> io::Result<Status> {
+    let mut args = args.into_iter().peekable();
+    let mut environment = environment.clone();
+
+    while let Some((name, value)) = args.peek().and_then(|arg| arg.split_once('=')) {
+        if !is_variable_name(name) {
+            break;
+        }
+
+        environment.set(name, value);
+        args.next();
+    }
+
+    match args.next() {
+        Some(command_name) => run(environment, command_name, args.collect(), streams),
+        None => print_vars(&environment, streams),
+    }
+}
+
+fn print_vars(environment: &Environment, streams: &mut Streams) -> io::Result<Status> {
+    for (name, value) in environment.vars() {
+        writeln!(streams.stdout, "{}={}", name, value)?;
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, lock_process, run};
+
+    fn env(arguments: &[&str], run_command: &mut EnvRunner) -> (Status, String) {
+        run("", |streams| {
+            execute(
+                args(arguments),
+                &Environment::default(),
+                streams,
+                run_command,
+            )
+        })
+    }
+
+    #[test]
+    fn no_arguments_prints_the_sorted_variables() {
+        let _lock = lock_process();
+        env::set_var("CORE_UTILS_TEST_B", "2");
+        env::set_var("CORE_UTILS_TEST_A", "1 \"one\"");
+
+        let (status, output) = env(&[], &mut |_, _, _, _| unreachable!());
+
+        env::remove_var("CORE_UTILS_TEST_A");
+        env::remove_var("CORE_UTILS_TEST_B");
+
+        let a = output.find("\nCORE_UTILS_TEST_A=1 \"one\"\n").unwrap();
+        let b = output.find("\nCORE_UTILS_TEST_B=2\n").unwrap();
+
+        assert_eq!(status, Status::SUCCESS);
+        assert!(a < b);
+    }
+
+    #[test]
+    fn assignments_alone_are_listed_without_being_set() {
+        let _lock = lock_process();
+        env::remove_var("CORE_UTILS_TEST_TEMPORARY");
+
+        let (_, output) = env(
+            &["CORE_UTILS_TEST_TEMPORARY=yes"],
+            &mut |_, _, _, _| unreachable!(),
+        );
+
+        assert!(output.contains("\nCORE_UTILS_TEST_TEMPORARY=yes\n"));
+        assert!(env::var_os("CORE_UTILS_TEST_TEMPORARY").is_none());
+    }
+
+    #[test]
+    fn overridden_variable_is_listed_once() {
+        let _lock = lock_process();
+        env::set_var("CORE_UTILS_TEST_LISTED", "before");
+
+        let (_, output) = env(
+            &["CORE_UTILS_TEST_LISTED=a", "CORE_UTILS_TEST_LISTED=b"],
+            &mut |_, _, _, _| unreachable!(),
+        );
+
+        env::remove_var("CORE_UTILS_TEST_LISTED");
+
+        assert_eq!(output.matches("CORE_UTILS_TEST_LISTED=").count(), 1);
+        assert!(output.contains("\nCORE_UTILS_TEST_LISTED=b\n"));
+    }
+
+    #[test]
+    fn command_runs_with_the_modified_environment() {
+        let _lock = lock_process();
+        env::remove_var("CORE_UTILS_TEST_OVERRIDDEN");
+
+        let mut seen = None;
+        let (status, _) = env(
+            &["CORE_UTILS_TEST_OVERRIDDEN=during", "cmd", "arg"],
+            &mut |environment, name, arguments, _| {
+                seen = Some((
+                    name,
+                    arguments,
+                    environment.var_os("CORE_UTILS_TEST_OVERRIDDEN"),
+                ));
+
+                Ok(Status::FAILURE)
+            },
+        );
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(
+            seen,
+            Some((
+                String::from("cmd"),
+                args(&["arg"]),
+                Some(OsString::from("during"))
+            ))
+        );
+        assert!(env::var_os("CORE_UTILS_TEST_OVERRIDDEN").is_none());
+    }
+}
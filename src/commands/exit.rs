@@ -1,11 +1,71 @@
 use std::io;
 
+use super::{print_help, Status, Streams};
+
+const HELP: &str = "\
+Usage: exit [STATUS]
+Exit the shell with STATUS, or with the status of the last command when it is omitted.
+";
+
 /// Terminate the application.
 ///
 /// This function is responsible for gracefully terminating the application. It prints a "Goodbye!" message
-/// and returns `Ok(false)`.
-pub fn execute() -> io::Result<bool> {
-    println!("Goodbye!");
+/// and asks the shell to exit with the status given as argument, or with the status of the last command
+/// when there is none. A status that is not a number is reported, and the shell exits with status 2.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `exit` command.
+/// * `last_status` - The exit status of the last command run by the shell.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, last_status: i32, streams: &mut Streams) -> io::Result<Status> {
+    if print_help(&args, HELP, streams)? {
+        return Ok(Status::SUCCESS);
+    }
+
+    writeln!(streams.stdout, "Goodbye!")?;
+
+    let code = match args.first() {
+        Some(arg) => arg.parse::<i32>().unwrap_or_else(|_| {
+            eprintln!("exit : numeric argument required: {}", arg);
+
+            2
+        }),
+        None => last_status,
+    };
+
+    Ok(Status::exit(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn exit(arg: &[&str], last_status: i32) -> Status {
+        run("", |streams| execute(args(arg), last_status, streams)).0
+    }
+
+    #[test]
+    fn exits_with_the_given_status() {
+        assert_eq!(exit(&["0"], 1), Status::exit(0));
+        assert_eq!(exit(&["42"], 0), Status::exit(42));
+    }
+
+    #[test]
+    fn bare_exit_keeps_the_last_status() {
+        assert_eq!(exit(&[], 3), Status::exit(3));
+    }
+
+    #[test]
+    fn says_goodbye_on_its_output() {
+        let (_, output) = run("", |streams| execute(args(&[]), 0, streams));
+
+        assert_eq!(output, "Goodbye!\n");
+    }
 
-    Ok(false)
+    #[test]
+    fn non_numeric_status_exits_with_2() {
+        assert_eq!(exit(&["x"], 0), Status::exit(2));
+    }
 }
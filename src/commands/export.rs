@@ -0,0 +1,150 @@
+use std::{env, io};
+
+use super::{Status, Streams};
+use crate::parser::is_variable_name;
+
+/// Execute the `export` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `export` command.
+///
+/// Each argument of the form `NAME=VALUE` sets the environment variable `NAME` to `VALUE`, which is then
+/// visible to the variable expansion of the shell. An argument made of a name only leaves the variable as
+/// it is, since every variable of the shell already lives in the environment. With no arguments, every
+/// environment variable is printed, sorted by name.
+///
+/// In a subshell, the names are only checked, since the environment is shared by the whole shell.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `export` command.
+/// * `subshell` - Whether `export` runs in a stage of a pipeline but the last.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, subshell: bool, streams: &mut Streams) -> io::Result<Status> {
+    if args.is_empty() {
+        for (name, value) in sorted_vars() {
+            writeln!(streams.stdout, "export {}=\"{}\"", name, quote(&value))?;
+        }
+
+        return Ok(Status::SUCCESS);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for arg in args {
+        let (name, value) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (arg.as_str(), None),
+        };
+
+        if !is_variable_name(name) {
+            eprintln!("export : not a valid identifier: '{}'", arg);
+            status = Status::FAILURE;
+            continue;
+        }
+
+        if let Some(value) = value.filter(|_| !subshell) {
+            env::set_var(name, value);
+        }
+    }
+
+    Ok(status)
+}
+
+/// Returns the names and values of every environment variable, sorted by name.
+pub fn sorted_vars() -> Vec<(String, String)> {
+    let mut vars: Vec<(String, String)> = env::vars_os()
+        .map(|(name, value)| {
+            (
+                name.to_string_lossy().into_owned(),
+                value.to_string_lossy().into_owned(),
+            )
+        })
+        .collect();
+
+    vars.sort();
+
+    vars
+}
+
+/// Escapes the characters of `value` that keep their special meaning inside double quotes, so that the
+/// printed assignments can be read back by the shell.
+fn quote(value: &str) -> String {
+    let mut quoted = String::new();
+
+    for c in value.chars() {
+        if matches!(c, '"' | '$' | '\\') {
+            quoted.push('\\');
+        }
+
+        quoted.push(c);
+    }
+
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, lock_process, run};
+
+    #[test]
+    fn assignment_sets_the_variable() {
+        let _lock = lock_process();
+
+        let (status, _) = run("", |streams| {
+            execute(args(&["CORE_UTILS_TEST_EXPORT=a b"]), false, streams)
+        });
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(env::var("CORE_UTILS_TEST_EXPORT").unwrap(), "a b");
+
+        env::remove_var("CORE_UTILS_TEST_EXPORT");
+    }
+
+    #[test]
+    fn name_alone_leaves_the_variable_as_it_is() {
+        let _lock = lock_process();
+        env::set_var("CORE_UTILS_TEST_KEPT", "kept");
+
+        run("", |streams| {
+            execute(args(&["CORE_UTILS_TEST_KEPT"]), false, streams)
+        });
+
+        assert_eq!(env::var("CORE_UTILS_TEST_KEPT").unwrap(), "kept");
+
+        env::remove_var("CORE_UTILS_TEST_KEPT");
+    }
+
+    #[test]
+    fn invalid_name_fails_without_stopping_the_others() {
+        let _lock = lock_process();
+
+        let (status, _) = run("", |streams| {
+            execute(args(&["1A=x", "CORE_UTILS_TEST_VALID=y"]), false, streams)
+        });
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(env::var("CORE_UTILS_TEST_VALID").unwrap(), "y");
+
+        env::remove_var("CORE_UTILS_TEST_VALID");
+    }
+
+    #[test]
+    fn no_arguments_prints_the_sorted_variables_quoted() {
+        let _lock = lock_process();
+        env::set_var("CORE_UTILS_TEST_B", "2");
+        env::set_var("CORE_UTILS_TEST_A", "say \"$1\"");
+
+        let (_, output) = run("", |streams| execute(args(&[]), false, streams));
+
+        env::remove_var("CORE_UTILS_TEST_A");
+        env::remove_var("CORE_UTILS_TEST_B");
+
+        let a = output
+            .find("export CORE_UTILS_TEST_A=\"say \\\"\\$1\\\"\"\n")
+            .unwrap();
+        let b = output.find("export CORE_UTILS_TEST_B=\"2\"\n").unwrap();
+
+        assert!(a < b);
+    }
+}
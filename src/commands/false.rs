@@ -0,0 +1,28 @@
+use std::io;
+
+use super::Status;
+
+/// Execute the `false` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `false` command.
+///
+/// It does nothing and fails. Like other implementations, it ignores its arguments.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `false` command.
+pub fn execute(_args: Vec<String>) -> io::Result<Status> {
+    Ok(Status::FAILURE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::args;
+
+    #[test]
+    fn ignores_its_arguments() {
+        assert_eq!(execute(args(&[])).unwrap(), Status::FAILURE);
+        assert_eq!(execute(args(&["--help", "x"])).unwrap(), Status::FAILURE);
+    }
+}
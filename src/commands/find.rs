@@ -0,0 +1,291 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use crate::glob;
+
+use super::{Status, Streams};
+
+/// The tests applied by `find` to each path it walks through.
+#[derive(Default)]
+struct Filters {
+    name: Option<String>,
+    file_type: Option<char>,
+    max_depth: Option<usize>,
+}
+
+impl Filters {
+    /// Returns whether the path at `path` passes every test.
+    fn accept(&self, path: &str, metadata: &fs::Metadata) -> bool {
+        let name_matches = match &self.name {
+            Some(pattern) => glob::matches(pattern, base_name(path)),
+            None => true,
+        };
+
+        let type_matches = match self.file_type {
+            Some('f') => metadata.is_file(),
+            Some('d') => metadata.is_dir(),
+            Some('l') => metadata.is_symlink(),
+            _ => true,
+        };
+
+        name_matches && type_matches
+    }
+}
+
+/// Execute the `find` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `find` command.
+///
+/// It walks the directory trees starting at each path given before the expressions, `.` by default, and
+/// prints the paths passing every test: `-name PATTERN` matches the base name against a glob pattern,
+/// `-type f|d|l` matches regular files, directories or symlinks, and `-maxdepth N` stops descending `N`
+/// levels below the starting paths. Symlinks are never followed.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `find` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut args = args.into_iter().peekable();
+    let mut start_paths = vec![];
+
+    while let Some(path) = args.next_if(|arg| !arg.starts_with('-')) {
+        start_paths.push(path);
+    }
+
+    if start_paths.is_empty() {
+        start_paths.push(String::from("."));
+    }
+
+    let mut filters = Filters::default();
+
+    while let Some(expression) = args.next() {
+        let Some(value) = args.next() else {
+            eprintln!("find : missing argument to '{}'", expression);
+
+            return Ok(Status::FAILURE);
+        };
+
+        match expression.as_str() {
+            "-name" => filters.name = Some(value),
+            "-type" if matches!(value.as_str(), "f" | "d" | "l") => {
+                filters.file_type = value.chars().next()
+            }
+            "-type" => {
+                eprintln!("find : unknown argument to -type: {}", value);
+
+                return Ok(Status::FAILURE);
+            }
+            "-maxdepth" => match value.parse() {
+                Ok(depth) => filters.max_depth = Some(depth),
+                Err(_) => {
+                    eprintln!("find : invalid argument to -maxdepth: {}", value);
+
+                    return Ok(Status::FAILURE);
+                }
+            },
+            _ => {
+                eprintln!("find : unknown predicate '{}'", expression);
+
+                return Ok(Status::FAILURE);
+            }
+        }
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in start_paths {
+        if !walk(path, &filters, &mut streams.stdout)? {
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Walks the tree starting at `start` depth first, printing the paths passing the filters. An explicit
+/// stack is used rather than recursion, so that deep trees cannot overflow the call stack. Returns whether
+/// the whole tree could be walked.
+fn walk(start: String, filters: &Filters, stdout: &mut impl Write) -> io::Result<bool> {
+    let mut stack = vec![(start, 0)];
+    let mut walked = true;
+
+    while let Some((path, depth)) = stack.pop() {
+        let metadata = match fs::symlink_metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                handle_error(e, &path);
+                walked = false;
+                continue;
+            }
+        };
+
+        if filters.accept(&path, &metadata) {
+            writeln!(stdout, "{}", path)?;
+        }
+
+        if !metadata.is_dir() || filters.max_depth.is_some_and(|max| depth >= max) {
+            continue;
+        }
+
+        let entries = match fs::read_dir(&path) {
+            Ok(read_dir) => read_dir,
+            Err(e) => {
+                handle_error(e, &path);
+                walked = false;
+                continue;
+            }
+        };
+
+        let mut children = vec![];
+
+        for entry in entries {
+            match entry {
+                Ok(entry) => {
+                    let child: PathBuf = PathBuf::from(&path).join(entry.file_name());
+
+                    children.push((child.to_string_lossy().into_owned(), depth + 1));
+                }
+                Err(e) => {
+                    handle_error(e, &path);
+                    walked = false;
+                }
+            }
+        }
+
+        // Children are pushed in reverse, so that they are popped in the order they were read.
+        stack.extend(children.into_iter().rev());
+    }
+
+    Ok(walked)
+}
+
+/// Returns the last component of `path`, keeping `.` and `..` rather than resolving them.
+fn base_name(path: &str) -> &str {
+    let trimmed = path.trim_end_matches('/');
+
+    match trimmed.rsplit('/').next() {
+        Some(name) if !name.is_empty() => name,
+        _ => "/",
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("find : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("find : permission denied: {}", path),
+        _ => eprintln!("find : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::symlink;
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    /// Creates a small tree: `a.txt`, `b.rs`, `sub/c.txt`, `sub/deep/d.txt` and `link` to `a.txt`.
+    fn tree() -> TempDir {
+        let dir = TempDir::new();
+
+        fs::create_dir_all(dir.join("sub/deep")).unwrap();
+        dir.write("a.txt", "");
+        dir.write("b.rs", "");
+        dir.write("sub/c.txt", "");
+        dir.write("sub/deep/d.txt", "");
+        symlink("a.txt", dir.join("link")).unwrap();
+
+        dir
+    }
+
+    /// Runs `find` from the tree with the expressions, returning the paths found relative to the tree,
+    /// sorted since the order of the entries of a directory is not specified.
+    fn find(dir: &TempDir, expressions: &[&str]) -> (Status, Vec<String>) {
+        let root = dir.join("");
+        let mut arg = vec![root.trim_end_matches('/')];
+        arg.extend(expressions);
+
+        let (status, output) = run("", |streams| execute(args(&arg), streams));
+        let mut paths: Vec<String> = output
+            .lines()
+            .map(|line| line[root.len() - 1..].to_string())
+            .collect();
+        paths.sort();
+
+        (status, paths)
+    }
+
+    #[test]
+    fn prints_the_whole_tree() {
+        let dir = tree();
+
+        let (status, paths) = find(&dir, &[]);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(
+            paths,
+            [
+                "",
+                "/a.txt",
+                "/b.rs",
+                "/link",
+                "/sub",
+                "/sub/c.txt",
+                "/sub/deep",
+                "/sub/deep/d.txt"
+            ]
+        );
+    }
+
+    #[test]
+    fn name_matches_a_glob_pattern() {
+        let dir = tree();
+
+        assert_eq!(
+            find(&dir, &["-name", "*.txt"]).1,
+            ["/a.txt", "/sub/c.txt", "/sub/deep/d.txt"]
+        );
+    }
+
+    #[test]
+    fn type_matches_the_kind_of_file() {
+        let dir = tree();
+
+        assert_eq!(find(&dir, &["-type", "d"]).1, ["", "/sub", "/sub/deep"]);
+        assert_eq!(find(&dir, &["-type", "l"]).1, ["/link"]);
+        assert_eq!(find(&dir, &["-type", "f", "-name", "*.rs"]).1, ["/b.rs"]);
+    }
+
+    #[test]
+    fn maxdepth_stops_descending() {
+        let dir = tree();
+
+        assert_eq!(
+            find(&dir, &["-maxdepth", "1", "-name", "*.txt"]).1,
+            ["/a.txt"]
+        );
+    }
+
+    #[test]
+    fn missing_start_path_fails() {
+        let dir = TempDir::new();
+
+        let (status, output) = run("", |streams| {
+            execute(args(&[&dir.join("missing")]), streams)
+        });
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn invalid_type_fails() {
+        let (status, _) = run("", |streams| execute(args(&[".", "-type", "x"]), streams));
+
+        assert_eq!(status, Status::FAILURE);
+    }
+}
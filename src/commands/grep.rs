@@ -0,0 +1,254 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+};
+
+use super::{Status, Streams};
+
+/// The options of the `grep` command.
+struct GrepOptions {
+    ignore_case: bool,
+    line_numbers: bool,
+    invert: bool,
+    count: bool,
+}
+
+/// The status of `grep` when an error occurred, whether lines were selected or not.
+const TROUBLE: Status = Status {
+    code: 2,
+    exit: false,
+};
+
+/// Execute the `grep` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `grep` command.
+///
+/// It prints the lines of each file containing the pattern given as first argument, matched as a fixed
+/// string. With `-i`, the case is ignored; with `-n`, lines are prefixed by their number; with `-v`, the
+/// lines not containing the pattern are printed instead; and with `-c`, only the number of selected
+/// lines is printed. When several files are given, lines are prefixed by their file name. The argument
+/// `-` stands for the standard input, which is also read when no file is given.
+///
+/// Like other implementations, the status is 0 when a line was selected, 1 when none was, and 2 when an
+/// error occurred.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `grep` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut letters = String::new();
+    let mut operands = vec![];
+    let mut args = args.into_iter();
+
+    for arg in args.by_ref() {
+        if arg == "--" {
+            break;
+        }
+
+        match arg.strip_prefix('-') {
+            Some(option) if !option.is_empty() => letters.push_str(option),
+            _ => operands.push(arg),
+        }
+    }
+
+    operands.extend(args);
+
+    if let Some(wrong_option) = letters.chars().find(|c| !"invc".contains(*c)) {
+        eprintln!("grep : invalid option - '{}'", wrong_option);
+
+        return Ok(TROUBLE);
+    }
+
+    let options = GrepOptions {
+        ignore_case: letters.contains('i'),
+        line_numbers: letters.contains('n'),
+        invert: letters.contains('v'),
+        count: letters.contains('c'),
+    };
+
+    let mut operands = operands.into_iter();
+
+    let Some(mut pattern) = operands.next() else {
+        eprintln!("grep : missing pattern");
+
+        return Ok(TROUBLE);
+    };
+
+    if options.ignore_case {
+        pattern = pattern.to_lowercase();
+    }
+
+    let mut paths: Vec<String> = operands.collect();
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let show_names = paths.len() > 1;
+    let mut stdout = &mut *streams.stdout;
+    let mut selected = 0;
+    let mut failed = false;
+
+    for path in paths.iter() {
+        let reader: io::Result<Box<dyn BufRead>> = if path == "-" {
+            Ok(Box::new(&mut *streams.stdin))
+        } else {
+            File::open(path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+        };
+
+        let prefix = match (show_names, path.as_str()) {
+            (false, _) => String::new(),
+            (true, "-") => String::from("(standard input):"),
+            (true, path) => format!("{}:", path),
+        };
+
+        let result =
+            reader.and_then(|reader| grep(reader, &mut stdout, &pattern, &prefix, &options));
+
+        match result {
+            Ok(count) => selected += count,
+            Err(e) => {
+                handle_error(e, path);
+                failed = true;
+            }
+        }
+    }
+
+    stdout.flush()?;
+
+    Ok(match (failed, selected) {
+        (true, _) => TROUBLE,
+        (false, 0) => Status::FAILURE,
+        (false, _) => Status::SUCCESS,
+    })
+}
+
+/// Prints the lines of `reader` selected by the pattern, or their number with `-c`, returning that number.
+/// Lines are matched as text, but printed as the raw bytes read.
+fn grep(
+    mut reader: impl BufRead,
+    writer: &mut impl Write,
+    pattern: &str,
+    prefix: &str,
+    options: &GrepOptions,
+) -> io::Result<usize> {
+    let mut line = vec![];
+    let mut number = 0;
+    let mut selected = 0;
+
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        number += 1;
+
+        let text = String::from_utf8_lossy(&line);
+        let matches = if options.ignore_case {
+            text.to_lowercase().contains(pattern)
+        } else {
+            text.contains(pattern)
+        };
+
+        if matches != options.invert {
+            selected += 1;
+
+            if !options.count {
+                write!(writer, "{}", prefix)?;
+
+                if options.line_numbers {
+                    write!(writer, "{}:", number)?;
+                }
+
+                writer.write_all(&line)?;
+
+                if !line.ends_with(b"\n") {
+                    writeln!(writer)?;
+                }
+            }
+        }
+
+        line.clear();
+    }
+
+    if options.count {
+        writeln!(writer, "{}{}", prefix, selected)?;
+    }
+
+    Ok(selected)
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("grep : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("grep : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("grep : is a directory: {}", path),
+        _ => eprintln!("grep : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    const TEXT: &str = "apple pie\nBanana split\napple tart\ncherry\n";
+
+    fn grep(arg: &[&str]) -> (Status, String) {
+        run(TEXT, |streams| execute(args(arg), streams))
+    }
+
+    #[test]
+    fn prints_the_matching_lines() {
+        assert_eq!(
+            grep(&["apple"]),
+            (Status::SUCCESS, String::from("apple pie\napple tart\n"))
+        );
+    }
+
+    #[test]
+    fn fails_when_no_line_matches() {
+        assert_eq!(grep(&["grape"]), (Status::FAILURE, String::new()));
+    }
+
+    #[test]
+    fn i_ignores_the_case() {
+        assert_eq!(grep(&["-i", "banana"]).1, "Banana split\n");
+        assert_eq!(grep(&["banana"]).0, Status::FAILURE);
+    }
+
+    #[test]
+    fn n_prefixes_line_numbers() {
+        assert_eq!(grep(&["-n", "apple"]).1, "1:apple pie\n3:apple tart\n");
+    }
+
+    #[test]
+    fn v_inverts_the_match() {
+        assert_eq!(grep(&["-v", "apple"]).1, "Banana split\ncherry\n");
+    }
+
+    #[test]
+    fn c_counts_the_selected_lines() {
+        assert_eq!(grep(&["-c", "apple"]).1, "2\n");
+        assert_eq!(grep(&["-vc", "apple"]).1, "2\n");
+        assert_eq!(
+            grep(&["-c", "grape"]),
+            (Status::FAILURE, String::from("0\n"))
+        );
+    }
+
+    #[test]
+    fn several_files_prefix_lines_with_their_name() {
+        let dir = TempDir::new();
+        let first = dir.write("first", "one\ntwo\n");
+        let second = dir.write("second", "three\n");
+
+        let (_, output) = grep(&["t", &first, &second]);
+
+        assert_eq!(output, format!("{}:two\n{}:three\n", first, second));
+    }
+
+    #[test]
+    fn missing_file_is_trouble() {
+        let dir = TempDir::new();
+
+        assert_eq!(grep(&["apple", &dir.join("missing")]).0, TROUBLE);
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Write},
+};
+
+use super::{Status, Streams};
+
+/// The number of lines printed when no count is given.
+const DEFAULT_COUNT: u64 = 10;
+
+/// What `head` counts: lines with `-n`, or bytes with `-c`.
+enum Count {
+    Lines(u64),
+    Bytes(u64),
+}
+
+/// Execute the `head` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `head` command.
+///
+/// It prints the first 10 lines of each file, or the first `N` lines with `-n N`, or the first `N` bytes
+/// with `-c N`. When several files are given, each one is preceded by a `==> name <==` header. The argument
+/// `-` stands for the standard input, which is also read when no file is given.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `head` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut count = Count::Lines(DEFAULT_COUNT);
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (option, value) = match arg.as_str() {
+            "-n" | "-c" => (arg.clone(), args.next()),
+            _ if arg.starts_with("-n") || arg.starts_with("-c") => {
+                (arg[..2].to_string(), Some(arg[2..].to_string()))
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("head : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => {
+                paths.push(arg);
+                continue;
+            }
+        };
+
+        let Some(value) = value else {
+            eprintln!("head : option requires an argument - '{}'", &option[1..]);
+
+            return Ok(Status::FAILURE);
+        };
+
+        let Ok(number) = value.parse() else {
+            eprintln!("head : invalid number: '{}'", value);
+
+            return Ok(Status::FAILURE);
+        };
+
+        count = if option == "-n" {
+            Count::Lines(number)
+        } else {
+            Count::Bytes(number)
+        };
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let show_headers = paths.len() > 1;
+    let mut stdout = &mut *streams.stdout;
+
+    let mut status = Status::SUCCESS;
+
+    for (i, path) in paths.iter().enumerate() {
+        let reader: io::Result<Box<dyn BufRead>> = if path == "-" {
+            Ok(Box::new(&mut *streams.stdin))
+        } else {
+            File::open(path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+        };
+
+        let mut reader = match reader {
+            Ok(reader) => reader,
+            Err(e) => {
+                handle_error(e, path);
+                status = Status::FAILURE;
+                continue;
+            }
+        };
+
+        if show_headers {
+            let name = if path == "-" { "standard input" } else { path };
+
+            if i > 0 {
+                writeln!(stdout)?;
+            }
+
+            writeln!(stdout, "==> {} <==", name)?;
+        }
+
+        if let Err(e) = head(&mut reader, &mut stdout, &count) {
+            handle_error(e, path);
+            status = Status::FAILURE;
+        }
+    }
+
+    stdout.flush()?;
+
+    Ok(status)
+}
+
+/// Copies the beginning of `reader` to `writer`, line by line or through a bounded reader, so that no
+/// more than the requested part of the input is ever read.
+fn head(reader: &mut dyn BufRead, writer: &mut impl Write, count: &Count) -> io::Result<()> {
+    match *count {
+        Count::Lines(lines) => {
+            let mut line = vec![];
+
+            for _ in 0..lines {
+                line.clear();
+
+                if reader.read_until(b'\n', &mut line)? == 0 {
+                    break;
+                }
+
+                writer.write_all(&line)?;
+            }
+        }
+        Count::Bytes(bytes) => {
+            io::copy(&mut reader.take(bytes), writer)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("head : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("head : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("head : is a directory: {}", path),
+        _ => eprintln!("head : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    const TWELVE_LINES: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+
+    #[test]
+    fn prints_ten_lines_by_default() {
+        let (status, output) = run(TWELVE_LINES, |streams| execute(args(&[]), streams));
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n");
+    }
+
+    #[test]
+    fn n_sets_the_number_of_lines() {
+        let (_, separate) = run(TWELVE_LINES, |streams| execute(args(&["-n", "3"]), streams));
+        let (_, attached) = run(TWELVE_LINES, |streams| execute(args(&["-n2"]), streams));
+
+        assert_eq!(separate, "1\n2\n3\n");
+        assert_eq!(attached, "1\n2\n");
+    }
+
+    #[test]
+    fn c_counts_bytes() {
+        let (_, output) = run(TWELVE_LINES, |streams| execute(args(&["-c", "5"]), streams));
+
+        assert_eq!(output, "1\n2\n3");
+    }
+
+    #[test]
+    fn headers_precede_each_of_several_files() {
+        let dir = TempDir::new();
+        let first = dir.write("first", "a\nb\n");
+        let second = dir.write("second", "c\n");
+
+        let (status, output) = run("", |streams| {
+            execute(args(&["-n", "1", &first, &second]), streams)
+        });
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(
+            output,
+            format!("==> {} <==\na\n\n==> {} <==\nc\n", first, second)
+        );
+    }
+
+    #[test]
+    fn missing_file_fails() {
+        let dir = TempDir::new();
+
+        let (status, _) = run("", |streams| {
+            execute(args(&[&dir.join("missing")]), streams)
+        });
+
+        assert_eq!(status, Status::FAILURE);
+    }
+
+    #[test]
+    fn invalid_number_fails() {
+        let (status, output) = run("a\n", |streams| execute(args(&["-n", "x"]), streams));
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(output, "");
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::{self, Write};
+
+use super::{CommandRegistry, Status, Streams};
+
+/// Execute the `help` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `help` command.
+///
+/// With no arguments, it lists every command of the shell, sorted by name, along with a one-line
+/// description. Otherwise, the usage and description of each command given as argument is printed.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `help` command.
+/// * `registry` - The commands known to the shell.
+/// * `streams` - The standard streams of the command.
+pub fn execute(
+    args: Vec<String>,
+    registry: &CommandRegistry,
+    streams: &mut Streams,
+) -> io::Result<Status> {
+    if args.is_empty() {
+        list_commands(registry, streams.stdout)?;
+
+        return Ok(Status::SUCCESS);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for name in args {
+        match registry.builtin(&name) {
+            Some(builtin) => {
+                writeln!(streams.stdout, "Usage: {}", builtin.usage)?;
+                writeln!(streams.stdout, "{}", builtin.summary)?;
+            }
+            None => {
+                eprintln!("help : no help topics match: {}", name);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Prints the name and description of every command of `registry`, sorted by name, the descriptions
+/// being aligned in a column.
+pub fn list_commands(registry: &CommandRegistry, writer: &mut dyn Write) -> io::Result<()> {
+    let mut names: Vec<&str> = registry.names().collect();
+
+    names.sort_unstable();
+
+    let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+
+    for name in names {
+        if let Some(builtin) = registry.builtin(name) {
+            writeln!(writer, "  {:<width$}  {}", name, builtin.summary)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn help(arg: &[&str]) -> (Status, String) {
+        let registry = CommandRegistry::default();
+
+        run("", |streams| execute(args(arg), &registry, streams))
+    }
+
+    #[test]
+    fn lists_every_command() {
+        let (status, output) = help(&[]);
+
+        assert_eq!(status, Status::SUCCESS);
+        for name in ["echo", "exit", "ls"] {
+            assert!(
+                output
+                    .lines()
+                    .any(|line| line.trim_start().starts_with(name)),
+                "{} is not listed in {:?}",
+                name,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn prints_the_usage_of_a_command() {
+        let (status, output) = help(&["exit"]);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "Usage: exit [STATUS]\nExit the shell.\n");
+    }
+
+    #[test]
+    fn unknown_command_fails() {
+        let (status, output) = help(&["nosuchcommand"]);
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(output, "");
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    collections::VecDeque,
+    env,
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use super::{Status, Streams};
+
+/// The number of lines kept in the history, the oldest ones being dropped first.
+const CAPACITY: usize = 1000;
+
+/// The name of the file in the home directory where the history is kept between sessions.
+const FILE_NAME: &str = ".core_utils_history";
+
+/// The lines entered in the shell, most recent last.
+#[derive(Default, Clone)]
+pub struct History {
+    lines: VecDeque<String>,
+    /// The number of the oldest line kept, counting from 1 since the history was last cleared.
+    first_number: usize,
+    /// The number of lines entered since the history was loaded, the last ones of `lines`.
+    new_lines: usize,
+}
+
+impl History {
+    /// Loads the history saved at `path`, one line per entry, keeping its last lines only when it holds
+    /// more than the history can. A missing file yields an empty history.
+    pub fn load(path: &Path) -> io::Result<History> {
+        let lines = read_lines(path)?;
+        let kept = &lines[lines.len().saturating_sub(CAPACITY)..];
+
+        Ok(History {
+            lines: kept.iter().cloned().collect(),
+            first_number: 0,
+            new_lines: 0,
+        })
+    }
+
+    /// Appends the lines entered since the history was loaded to the file at `path`, one line per entry,
+    /// so that the lines saved meanwhile by other sessions are kept. The file is then cut down to its last
+    /// lines when it holds more than the history can.
+    pub fn append(&self, path: &Path) -> io::Result<()> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let skipped = self.lines.len() - self.new_lines;
+
+        write_lines(file, self.lines.iter().skip(skipped))?;
+
+        let saved = read_lines(path)?;
+
+        if saved.len() > CAPACITY {
+            write_lines(File::create(path)?, &saved[saved.len() - CAPACITY..])?;
+        }
+
+        Ok(())
+    }
+
+    /// Appends a line to the history, dropping the oldest line when the history is full.
+    pub fn push(&mut self, line: String) {
+        if self.lines.len() == CAPACITY {
+            self.lines.pop_front();
+            self.first_number += 1;
+        }
+
+        self.lines.push_back(line);
+        self.new_lines = (self.new_lines + 1).min(self.lines.len());
+    }
+
+    fn clear(&mut self) {
+        self.lines.clear();
+        self.first_number = 0;
+        self.new_lines = 0;
+    }
+}
+
+/// Reads the non-empty lines of the history file at `path`, a missing file having none.
+fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    let content = match fs::read(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    Ok(String::from_utf8_lossy(&content)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Writes `lines` to `file`, one line per entry.
+fn write_lines<'a>(file: File, lines: impl IntoIterator<Item = &'a String>) -> io::Result<()> {
+    let mut writer = BufWriter::new(file);
+
+    for line in lines {
+        writeln!(writer, "{}", line)?;
+    }
+
+    writer.flush()
+}
+
+/// Returns the path of the file where the history is kept between sessions, in the home directory.
+pub fn history_file() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(FILE_NAME))
+}
+
+/// Execute the `history` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `history` command.
+///
+/// It prints the lines entered in the shell, oldest first, each preceded by its number. `history N` prints
+/// the last `N` lines only, and `history -c` clears the history.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `history` command.
+/// * `history` - The lines entered in the shell.
+/// * `streams` - The standard streams of the command.
+pub fn execute(
+    args: Vec<String>,
+    history: &mut History,
+    streams: &mut Streams,
+) -> io::Result<Status> {
+    let count = match args.first().map(String::as_str) {
+        Some("-c") => {
+            history.clear();
+
+            return Ok(Status::SUCCESS);
+        }
+        Some(arg) if arg.starts_with('-') && arg.len() > 1 => {
+            eprintln!("history : invalid option - '{}'", arg);
+
+            return Ok(Status::FAILURE);
+        }
+        Some(arg) => match arg.parse::<usize>() {
+            Ok(count) => count,
+            Err(_) => {
+                eprintln!("history : numeric argument required: {}", arg);
+
+                return Ok(Status::FAILURE);
+            }
+        },
+        None => history.lines.len(),
+    };
+
+    let skipped = history.lines.len().saturating_sub(count);
+
+    for (i, line) in history.lines.iter().enumerate().skip(skipped) {
+        writeln!(
+            streams.stdout,
+            "{:>5}  {}",
+            history.first_number + i + 1,
+            line
+        )?;
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn history_of(lines: &[&str]) -> History {
+        let mut history = History::default();
+
+        for line in lines {
+            history.push(line.to_string());
+        }
+
+        history
+    }
+
+    #[test]
+    fn prints_the_lines_numbered() {
+        let mut history = history_of(&["ls", "cd /tmp", "pwd"]);
+
+        let (status, output) = run("", |streams| execute(args(&[]), &mut history, streams));
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "    1  ls\n    2  cd /tmp\n    3  pwd\n");
+    }
+
+    #[test]
+    fn count_prints_the_last_lines_only() {
+        let mut history = history_of(&["ls", "cd /tmp", "pwd"]);
+
+        let (_, output) = run("", |streams| execute(args(&["2"]), &mut history, streams));
+
+        assert_eq!(output, "    2  cd /tmp\n    3  pwd\n");
+    }
+
+    #[test]
+    fn c_clears_the_history_and_restarts_the_numbering() {
+        let mut history = history_of(&["ls", "pwd"]);
+
+        run("", |streams| execute(args(&["-c"]), &mut history, streams));
+        history.push(String::from("echo"));
+        let (_, output) = run("", |streams| execute(args(&[]), &mut history, streams));
+
+        assert_eq!(output, "    1  echo\n");
+    }
+
+    #[test]
+    fn oldest_lines_are_dropped_beyond_the_capacity() {
+        let lines: Vec<String> = (0..CAPACITY + 2).map(|i| format!("echo {}", i)).collect();
+        let mut history = History::default();
+        lines.iter().for_each(|line| history.push(line.clone()));
+
+        let (_, output) = run("", |streams| execute(args(&["1"]), &mut history, streams));
+
+        assert_eq!(history.lines.len(), CAPACITY);
+        assert_eq!(history.lines[0], "echo 2");
+        assert_eq!(output, format!(" 1002  echo {}\n", CAPACITY + 1));
+    }
+
+    #[test]
+    fn invalid_count_fails() {
+        let mut history = history_of(&["ls"]);
+
+        let (status, output) = run("", |streams| execute(args(&["x"]), &mut history, streams));
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+    }
+
+    #[test]
+    fn appended_history_is_restored_by_the_next_session() {
+        let dir = TempDir::new();
+        let path = dir.path().join(FILE_NAME);
+
+        let mut first = History::load(&path).unwrap();
+        assert!(first.lines.is_empty());
+        first.push(String::from("ls"));
+        first.push(String::from("cd /tmp"));
+        first.append(&path).unwrap();
+
+        let mut second = History::load(&path).unwrap();
+        assert_eq!(second.lines, ["ls", "cd /tmp"]);
+        second.push(String::from("pwd"));
+        second.append(&path).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "ls\ncd /tmp\npwd\n");
+        assert_eq!(
+            History::load(&path).unwrap().lines,
+            ["ls", "cd /tmp", "pwd"]
+        );
+    }
+
+    #[test]
+    fn lines_saved_by_another_session_are_kept() {
+        let dir = TempDir::new();
+        let path = dir.path().join(FILE_NAME);
+
+        let mut first = History::load(&path).unwrap();
+        let mut second = History::load(&path).unwrap();
+        first.push(String::from("first"));
+        second.push(String::from("second"));
+        first.append(&path).unwrap();
+        second.append(&path).unwrap();
+
+        assert_eq!(History::load(&path).unwrap().lines, ["first", "second"]);
+    }
+
+    #[test]
+    fn history_file_is_cut_down_to_the_capacity() {
+        let dir = TempDir::new();
+        let path = dir.path().join(FILE_NAME);
+        let old: String = (0..CAPACITY).map(|i| format!("old {}\n", i)).collect();
+        fs::write(&path, old).unwrap();
+
+        let mut history = History::load(&path).unwrap();
+        history.push(String::from("new"));
+        history.append(&path).unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+
+        assert_eq!(saved.lines().count(), CAPACITY);
+        assert!(saved.starts_with("old 1\n"));
+        assert!(saved.ends_with("\nnew\n"));
+    }
+}
@@ -0,0 +1,212 @@
+use std::io;
+
+use crate::unix::users::{
+    effective_gid, effective_uid, lookup_group, lookup_uid, lookup_user, member_gids, primary_gid,
+    supplementary_gids,
+};
+
+use super::{Status, Streams};
+
+/// The single id printed with `-u` or `-g`, rather than the full description.
+#[derive(Clone, Copy, PartialEq)]
+enum Only {
+    User,
+    Group,
+}
+
+/// Execute the `id` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `id` command.
+///
+/// It prints the uid, gid and groups of the shell in the form `uid=0(root) gid=0(root) groups=0(root)`,
+/// from its effective ids, or those of the user named as argument, read from `/etc/passwd` and
+/// `/etc/group`. With `-u` or `-g`, only the uid or the gid is printed, and `-n` prints it as a name.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `id` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut only = None;
+    let mut name = false;
+    let mut users = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            users.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            let choice = match letter {
+                'u' => Only::User,
+                'g' => Only::Group,
+                'n' => {
+                    name = true;
+                    continue;
+                }
+                _ => {
+                    eprintln!("id : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            };
+
+            if only.is_some_and(|only| only != choice) {
+                eprintln!("id : cannot print only the uid and the gid at once");
+
+                return Ok(Status::FAILURE);
+            }
+
+            only = Some(choice);
+        }
+    }
+
+    if name && only.is_none() {
+        eprintln!("id : cannot print only names in the default format");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let (uid, gid, mut groups) = match users.as_slice() {
+        [] => (effective_uid(), effective_gid(), supplementary_gids()),
+        [user] => {
+            let ids = lookup_uid(user).zip(primary_gid(user));
+
+            let Some((uid, gid)) = ids else {
+                eprintln!("id : '{}': no such user", user);
+
+                return Ok(Status::FAILURE);
+            };
+
+            (uid, gid, member_gids(user))
+        }
+        [_, extra, ..] => {
+            eprintln!("id : extra operand '{}'", extra);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    match only {
+        Some(Only::User) if name => writeln!(streams.stdout, "{}", user_name(uid))?,
+        Some(Only::User) => writeln!(streams.stdout, "{}", uid)?,
+        Some(Only::Group) if name => writeln!(streams.stdout, "{}", group_name(gid))?,
+        Some(Only::Group) => writeln!(streams.stdout, "{}", gid)?,
+        None => {
+            // The primary group comes first, and the others in ascending order, each listed once.
+            groups.retain(|group| *group != gid);
+            groups.sort_unstable();
+            groups.dedup();
+            groups.insert(0, gid);
+
+            let groups: Vec<_> = groups
+                .into_iter()
+                .map(|group| describe(group, lookup_group(group)))
+                .collect();
+
+            writeln!(
+                streams.stdout,
+                "uid={} gid={} groups={}",
+                describe(uid, lookup_user(uid)),
+                describe(gid, lookup_group(gid)),
+                groups.join(",")
+            )?;
+        }
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Formats an id followed by its name in parentheses, like `0(root)`, or alone when it has no name.
+fn describe(id: u32, name: Option<String>) -> String {
+    match name {
+        Some(name) => format!("{}({})", id, name),
+        None => id.to_string(),
+    }
+}
+
+/// Returns the name of the user with the given uid, or the uid itself when it has no name.
+fn user_name(uid: u32) -> String {
+    lookup_user(uid).unwrap_or_else(|| uid.to_string())
+}
+
+/// Returns the name of the group with the given gid, or the gid itself when it has no name.
+fn group_name(gid: u32) -> String {
+    lookup_group(gid).unwrap_or_else(|| gid.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn id(arguments: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn u_prints_the_effective_uid() {
+        assert_eq!(
+            id(&["-u"]),
+            (Status::SUCCESS, format!("{}\n", effective_uid()))
+        );
+        assert_eq!(id(&["-uu"]).1, format!("{}\n", effective_uid()));
+    }
+
+    #[test]
+    fn g_prints_the_effective_gid() {
+        assert_eq!(
+            id(&["-g"]),
+            (Status::SUCCESS, format!("{}\n", effective_gid()))
+        );
+    }
+
+    #[test]
+    fn n_prints_names() {
+        assert_eq!(id(&["-un"]).1, format!("{}\n", user_name(effective_uid())));
+        assert_eq!(
+            id(&["-n", "-g"]).1,
+            format!("{}\n", group_name(effective_gid()))
+        );
+    }
+
+    #[test]
+    fn queries_another_user() {
+        assert_eq!(id(&["-u", "root"]), (Status::SUCCESS, String::from("0\n")));
+        assert_eq!(id(&["-gn", "root"]).1, "root\n");
+        assert!(id(&["root"])
+            .1
+            .starts_with("uid=0(root) gid=0(root) groups=0(root)"));
+    }
+
+    #[test]
+    fn default_format_lists_the_primary_group_first() {
+        let (status, output) = id(&[]);
+        let uid = describe(effective_uid(), lookup_user(effective_uid()));
+        let gid = describe(effective_gid(), lookup_group(effective_gid()));
+
+        assert_eq!(status, Status::SUCCESS);
+        assert!(output.starts_with(&format!("uid={} gid={} groups={}", uid, gid, gid)));
+    }
+
+    #[test]
+    fn describes_ids_with_or_without_a_name() {
+        assert_eq!(describe(0, Some(String::from("root"))), "0(root)");
+        assert_eq!(describe(12345, None), "12345");
+        assert_eq!(user_name(4_000_000_000), "4000000000");
+    }
+
+    #[test]
+    fn invalid_usage_fails() {
+        for arguments in [
+            &["-ug"][..],
+            &["-n"],
+            &["-x"],
+            &["no-such-user"],
+            &["root", "root"],
+        ] {
+            assert_eq!(id(arguments), (Status::FAILURE, String::new()));
+        }
+    }
+}
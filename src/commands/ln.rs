@@ -0,0 +1,204 @@
+use std::{fs, io, os::unix::fs::symlink, path::Path};
+
+use super::{split_options, Status};
+
+/// Execute the `ln` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `ln` command.
+///
+/// It creates a hard link named by the last argument to the file given before it, or links to several
+/// files inside a destination directory, keeping their base names. With a single file, the link is created
+/// in the current directory. With `-s`, symbolic links are created instead, holding the files as given.
+/// With `-f`, existing destinations are replaced.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `ln` command.
+pub fn execute(args: Vec<String>) -> io::Result<Status> {
+    let (paths, options) = split_options(args);
+
+    if let Some(wrong_option) = options.chars().find(|c| !"sf".contains(*c)) {
+        eprintln!("ln : invalid option - '{}'", wrong_option);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let symbolic = options.contains('s');
+    let force = options.contains('f');
+
+    let (directory, targets) = match paths.as_slice() {
+        [] => {
+            eprintln!("ln : missing file operand");
+
+            return Ok(Status::FAILURE);
+        }
+        [target] => (Path::new("."), std::slice::from_ref(target)),
+        [targets @ .., destination] => (Path::new(destination), targets),
+    };
+
+    let into_dir = paths.len() == 1 || directory.is_dir();
+
+    if targets.len() > 1 && !into_dir {
+        eprintln!("ln : target '{}' is not a directory", directory.display());
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for target in targets {
+        let target = Path::new(target);
+
+        let link = match target.file_name() {
+            Some(name) if into_dir => directory.join(name),
+            _ => directory.to_path_buf(),
+        };
+
+        if let Err(e) = create_link(target, &link, symbolic, force) {
+            handle_error(e, &link);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Creates a link at `link` to `target`, removing the file at `link` first when `force` is set.
+fn create_link(target: &Path, link: &Path, symbolic: bool, force: bool) -> io::Result<()> {
+    if force {
+        match fs::symlink_metadata(link) {
+            Ok(metadata) if metadata.is_dir() => {
+                return Err(io::Error::from(io::ErrorKind::IsADirectory))
+            }
+            Ok(_) => fs::remove_file(link)?,
+            Err(_) => {}
+        }
+    }
+
+    if symbolic {
+        symlink(target, link)
+    } else {
+        fs::hard_link(target, link)
+    }
+}
+
+fn handle_error(error: io::Error, path: &Path) {
+    match error.kind() {
+        io::ErrorKind::NotFound => {
+            eprintln!("ln : no such file or directory: {}", path.display())
+        }
+        io::ErrorKind::PermissionDenied => {
+            eprintln!("ln : permission denied: {}", path.display())
+        }
+        io::ErrorKind::AlreadyExists => {
+            eprintln!("ln : file exists: {}", path.display())
+        }
+        _ => eprintln!("ln : cannot create link '{}': {}", path.display(), error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::MetadataExt;
+
+    use super::*;
+    use crate::testing::{args, TempDir};
+
+    #[test]
+    fn creates_a_hard_link_by_default() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "content");
+        let link = dir.join("link");
+
+        assert_eq!(execute(args(&[&file, &link])).unwrap(), Status::SUCCESS);
+
+        let metadata = fs::symlink_metadata(&link).unwrap();
+        assert!(metadata.is_file());
+        assert_eq!(metadata.ino(), fs::metadata(&file).unwrap().ino());
+        assert_eq!(fs::metadata(&file).unwrap().nlink(), 2);
+    }
+
+    #[test]
+    fn creates_a_symbolic_link_with_s() {
+        let dir = TempDir::new();
+        dir.write("file", "content");
+        let link = dir.join("link");
+
+        assert_eq!(
+            execute(args(&["-s", "file", &link])).unwrap(),
+            Status::SUCCESS
+        );
+
+        assert!(fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(fs::read_link(&link).unwrap(), Path::new("file"));
+        assert_eq!(fs::read_to_string(&link).unwrap(), "content");
+    }
+
+    #[test]
+    fn existing_destination_is_kept_without_f() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "new");
+        let link = dir.write("link", "old");
+
+        assert_eq!(execute(args(&[&file, &link])).unwrap(), Status::FAILURE);
+        assert_eq!(fs::read_to_string(&link).unwrap(), "old");
+
+        assert_eq!(
+            execute(args(&["-sf", &file, &link])).unwrap(),
+            Status::SUCCESS
+        );
+        assert_eq!(fs::read_to_string(&link).unwrap(), "new");
+    }
+
+    #[test]
+    fn links_several_files_into_a_directory() {
+        let dir = TempDir::new();
+        let first = dir.write("first", "1");
+        let second = dir.write("second", "2");
+        let destination = dir.join("destination");
+        fs::create_dir(&destination).unwrap();
+
+        let status = execute(args(&["-s", &first, &second, &destination])).unwrap();
+
+        assert_eq!(status, Status::SUCCESS);
+        for name in ["first", "second"] {
+            let link = Path::new(&destination).join(name);
+            assert_eq!(fs::read_link(link).unwrap(), Path::new(&dir.join(name)));
+        }
+    }
+
+    #[test]
+    fn several_files_need_a_directory_destination() {
+        let dir = TempDir::new();
+        let first = dir.write("first", "1");
+        let second = dir.write("second", "2");
+        let missing = dir.join("missing");
+
+        let status = execute(args(&[&first, &second, &missing])).unwrap();
+
+        assert_eq!(status, Status::FAILURE);
+        assert!(fs::symlink_metadata(&missing).is_err());
+    }
+
+    #[test]
+    fn failing_target_does_not_stop_the_others() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "content");
+        let destination = dir.join("destination");
+        fs::create_dir(&destination).unwrap();
+
+        let status = execute(args(&[&dir.join("missing"), &file, &destination])).unwrap();
+
+        assert_eq!(status, Status::FAILURE);
+        assert!(Path::new(&destination).join("file").is_file());
+    }
+
+    #[test]
+    fn invalid_option_or_missing_operand_fails() {
+        assert_eq!(execute(args(&["-x", "a", "b"])).unwrap(), Status::FAILURE);
+        assert_eq!(execute(args(&["-s"])).unwrap(), Status::FAILURE);
+    }
+}
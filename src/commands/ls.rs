@@ -1,32 +1,161 @@
 use std::{
+    cmp::Reverse,
     collections::HashSet,
+    ffi::OsString,
     fs::{self, DirEntry, ReadDir},
     io,
-    os::{linux::fs::MetadataExt, unix::prelude::FileTypeExt},
-    path::PathBuf,
+    os::{linux::fs::MetadataExt, unix::ffi::OsStrExt},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
 };
 
-use crate::unix::permissions::UnixPermissions;
-
-struct FileType(std::fs::FileType);
-
-impl std::fmt::Display for FileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let file_type = self.0;
-
-        write!(
-            f,
-            "{}",
-            match file_type {
-                _ if file_type.is_dir() => 'd',
-                _ if file_type.is_symlink() => 's',
-                _ if file_type.is_block_device() => 'b',
-                _ if file_type.is_char_device() => 'c',
-                _ if file_type.is_socket() => 's',
-                _ if file_type.is_fifo() => 'p',
-                _ => '-',
+use crate::{
+    format::human_size,
+    unix::{
+        permissions::{FileType, UnixPermissions},
+        terminal,
+        time::{DateTime, MONTHS},
+        users::{lookup_group, lookup_user},
+    },
+};
+
+use super::{print_help, Status, Streams};
+
+const HELP: &str = "\
+Usage: ls [OPTION]... [FILE]...
+List the files given as arguments, and the content of the directories, the current one by default.
+
+  -a  do not hide the entries starting with .
+  -A  do not hide the entries starting with ., except . and ..
+  -l  use a long listing format
+  -n  like -l, listing numeric user and group ids
+  -h  with -l, print sizes in a human readable format, like 1K or 234M
+  -r  reverse the order of the sort
+  -S  sort by size, largest first
+  -t  sort by modification time, newest first
+  -1  list one file per line
+  -R  list the subdirectories recursively
+  -F  append an indicator to the names: / for directories, * for executables, @ for symlinks, | for
+      FIFOs and = for sockets
+
+  --color[=WHEN]  colorize the names by file type: always (the default WHEN), never, or auto, when the
+                  output is a terminal, which is the default without --color
+";
+
+/// The width assumed for the terminal when it cannot be determined.
+const DEFAULT_WIDTH: usize = 80;
+
+/// The number of spaces between two columns of the grid output.
+const COLUMN_SEPARATOR: usize = 2;
+
+/// The age beyond which `ls -l` prints the year of a modification time instead of its time of day.
+/// Like GNU ls, six months are taken as half of an average Gregorian year.
+const SIX_MONTHS: Duration = Duration::from_secs(31_556_952 / 2);
+
+/// When the names of the entries are colorized, as set with `--color`.
+#[derive(Clone, Copy)]
+enum ColorMode {
+    Always,
+    Never,
+    Auto,
+}
+
+/// An entry of a directory listing.
+struct Entry {
+    name: OsString,
+    path: PathBuf,
+}
+
+impl Entry {
+    fn new(name: OsString, path: PathBuf) -> Self {
+        Entry { name, path }
+    }
+
+    /// Returns whether the entry is a dotfile, hidden from the listing unless `-a` or `-A` is given.
+    fn is_hidden(&self) -> bool {
+        self.name.as_bytes().starts_with(b".")
+    }
+
+    /// Returns the modification time of the entry, or `None` if it cannot be read, in which case the
+    /// entry sorts as the oldest one.
+    fn modified(&self) -> Option<SystemTime> {
+        self.metadata().and_then(|m| m.modified()).ok()
+    }
+
+    /// Returns the size of the entry in bytes, or 0 if it cannot be read.
+    fn size(&self) -> u64 {
+        self.metadata().map(|m| m.st_size()).unwrap_or(0)
+    }
+
+    /// Returns the metadata of the entry itself, without following symlinks.
+    /// Symlinks are thus listed as links, with their own type, size and times, rather than as their
+    /// targets.
+    fn metadata(&self) -> io::Result<fs::Metadata> {
+        fs::symlink_metadata(&self.path)
+    }
+}
+
+/// A line of the long listing, with each column already formatted.
+struct LongRow {
+    mode: String,
+    links: String,
+    owner: String,
+    group: String,
+    size: String,
+    time: String,
+    name: String,
+}
+
+impl LongRow {
+    /// Formats the row of an entry, failing when its metadata cannot be read, as when it was removed
+    /// since the directory was read.
+    fn new(e: &Entry, options: &HashSet<char>, colors: bool) -> io::Result<Self> {
+        let metadata: fs::Metadata = e.metadata()?;
+        let permissions = metadata.permissions();
+
+        let mode = format!(
+            "{}{}{}{}",
+            FileType(metadata.file_type()),
+            permissions.owner(),
+            permissions.group(),
+            permissions.other()
+        );
+
+        let size = if options.contains(&'h') {
+            human_size(metadata.st_size())
+        } else {
+            metadata.st_size().to_string()
+        };
+
+        let (owner, group) = if options.contains(&'n') {
+            (metadata.st_uid().to_string(), metadata.st_gid().to_string())
+        } else {
+            (
+                lookup_user(metadata.st_uid()).unwrap_or_else(|| metadata.st_uid().to_string()),
+                lookup_group(metadata.st_gid()).unwrap_or_else(|| metadata.st_gid().to_string()),
+            )
+        };
+
+        let mut name = paint(&e.name.to_string_lossy(), Some(&metadata), colors);
+
+        // A dangling symlink still has a target to show, even if it doesn't resolve.
+        if metadata.file_type().is_symlink() {
+            if let Ok(target) = fs::read_link(&e.path) {
+                name.push_str(&format!(" -> {}", target.display()));
             }
-        )
+        } else if options.contains(&'F') {
+            name.push_str(indicator(Some(&metadata)));
+        }
+
+        Ok(LongRow {
+            mode,
+            links: metadata.st_nlink().to_string(),
+            owner,
+            group,
+            size,
+            time: format_time(metadata.modified().ok()),
+            name,
+        })
     }
 }
 
@@ -39,69 +168,357 @@ impl std::fmt::Display for FileType {
 /// # Arguments
 ///
 /// * `args` - A vector of strings representing the arguments for the `ls` command.
-pub fn execute(args: Vec<String>) -> io::Result<bool> {
-    let (path, options) = parse(args);
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    if print_help(&args, HELP, streams)? {
+        return Ok(Status::SUCCESS);
+    }
+
+    let (paths, options, color_mode) = match parse(args) {
+        Ok(parsed) => parsed,
+        Err(when) => {
+            eprintln!("ls : invalid argument '{}' for '--color'", when);
+
+            return Ok(Status::FAILURE);
+        }
+    };
 
     if let Err(wrong_option) = validate_ls_options(&options) {
-        println!("ls : invalid option - '{}'", wrong_option);
+        eprintln!("ls : invalid option - '{}'", wrong_option);
 
-        return Ok(true);
+        return Ok(Status::FAILURE);
     }
 
-    match fs::read_dir(path.clone()) {
-        Ok(read_dir) => {
-            let entries = read_entries(read_dir);
+    let colors = match color_mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => streams.terminal,
+    };
+
+    let mut files = vec![];
+    let mut dirs = vec![];
+    let mut status = Status::SUCCESS;
+
+    for path in paths.iter() {
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.is_dir() => dirs.push(path),
+            Ok(_) => files.push(Entry::new(OsString::from(path), PathBuf::from(path))),
+            Err(e) => {
+                handle_error(e, path);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    // Headers are only printed when several directories may be listed, to tell their listings apart.
+    let show_headers = paths.len() > 1 || options.contains(&'R');
+    let mut first_section = files.is_empty();
+
+    if !files.is_empty() {
+        sort_entries(&mut files, &options);
+        print_entries(files, &options, colors, streams)?;
+    }
 
-            if let Err(errors) = entries {
-                errors
-                    .into_iter()
-                    .for_each(|e| println!("{}", e.to_string()));
+    // The directories left to list, the next one last. With `-R`, the subdirectories of a directory are
+    // pushed once it is listed, so that the tree is walked depth first without recursion.
+    let mut stack: Vec<PathBuf> = dirs.into_iter().rev().map(PathBuf::from).collect();
 
-                return Ok(true);
+    while let Some(dir) = stack.pop() {
+        if show_headers {
+            if !first_section {
+                writeln!(streams.stdout)?;
             }
 
-            let entries = entries.unwrap();
+            writeln!(streams.stdout, "{}:", dir.display())?;
+        }
+
+        first_section = false;
 
-            if options.len() == 0 {
-                let out: String = entries
-                    .into_iter()
-                    .map(|e| e.path().display().to_string())
-                    .collect::<Vec<String>>()
-                    .join(" ");
+        match list_directory(&dir, &options, colors, streams)? {
+            Some(subdirs) => stack.extend(subdirs.into_iter().rev()),
+            None => status = Status::FAILURE,
+        }
+    }
 
-                println!("{}", out);
+    Ok(status)
+}
 
-                return Ok(true);
-            }
+/// Lists the content of the directory at `path`, according to the `ls` options. Returns `None` if the
+/// directory could not be read, and otherwise its subdirectories to list next with `-R`, in the order of
+/// the listing. Hidden subdirectories are only listed when shown, and symlinks to directories never are.
+fn list_directory(
+    path: &Path,
+    options: &HashSet<char>,
+    colors: bool,
+    streams: &mut Streams,
+) -> io::Result<Option<Vec<PathBuf>>> {
+    let read_dir = match fs::read_dir(path) {
+        Ok(read_dir) => read_dir,
+        Err(e) => {
+            handle_error(e, &path.to_string_lossy());
+
+            return Ok(None);
+        }
+    };
+
+    let entries = match read_entries(read_dir) {
+        Ok(entries) => entries,
+        Err(errors) => {
+            errors.into_iter().for_each(|e| eprintln!("{}", e));
+
+            return Ok(None);
+        }
+    };
+
+    // `-A` takes precedence over `-a` when both are given.
+    let almost_all = options.contains(&'A');
+    let show_hidden = almost_all || options.contains(&'a');
 
-            if options.contains(&'l') {
-                entries.into_iter().for_each(|e| {
-                    let metadata: fs::Metadata = e.metadata().unwrap();
-                    let path: PathBuf = e.path();
-                    let permissions = metadata.permissions();
-
-                    let permissions_str = format!(
-                        "{}{}{}",
-                        permissions.owner(),
-                        permissions.group(),
-                        permissions.other()
-                    );
-
-                    println!(
-                        "{}{} {} {} {} {}",
-                        FileType(metadata.file_type()),
-                        permissions_str,
-                        metadata.st_uid(),
-                        metadata.st_gid(),
-                        metadata.st_size(),
-                        path.display()
-                    )
-                });
+    let mut entries: Vec<Entry> = entries
+        .into_iter()
+        .map(|e| Entry::new(e.file_name(), e.path()))
+        .filter(|e| show_hidden || !e.is_hidden())
+        .collect();
+
+    if show_hidden && !almost_all {
+        entries.insert(0, Entry::new(OsString::from("."), path.join(".")));
+        entries.insert(1, Entry::new(OsString::from(".."), path.join("..")));
+    }
+
+    sort_entries(&mut entries, options);
+
+    let subdirs = if options.contains(&'R') {
+        entries
+            .iter()
+            .filter(|e| e.name != "." && e.name != "..")
+            .filter(|e| e.metadata().is_ok_and(|m| m.is_dir()))
+            .map(|e| e.path.clone())
+            .collect()
+    } else {
+        vec![]
+    };
+
+    print_entries(entries, options, colors, streams)?;
+
+    Ok(Some(subdirs))
+}
+
+/// Prints the entries in the short or long format, according to the `ls` options. With `colors`, their
+/// names are colorized by file type.
+fn print_entries(
+    entries: Vec<Entry>,
+    options: &HashSet<char>,
+    colors: bool,
+    streams: &mut Streams,
+) -> io::Result<()> {
+    // `-n` implies the long format, with numeric ids.
+    if options.contains(&'l') || options.contains(&'n') {
+        let rows: Vec<LongRow> = entries
+            .iter()
+            .filter_map(|e| match LongRow::new(e, options, colors) {
+                Ok(row) => Some(row),
+                Err(error) => {
+                    eprintln!("ls : cannot access {}: {}", e.path.display(), error);
+
+                    None
+                }
+            })
+            .collect();
+
+        // Every row is collected first so that each column can be as wide as its widest value.
+        let links_width = rows.iter().map(|r| r.links.len()).max().unwrap_or(0);
+        let owner_width = rows.iter().map(|r| r.owner.len()).max().unwrap_or(0);
+        let group_width = rows.iter().map(|r| r.group.len()).max().unwrap_or(0);
+        let size_width = rows.iter().map(|r| r.size.len()).max().unwrap_or(0);
+
+        for r in rows {
+            writeln!(
+                streams.stdout,
+                "{} {:>links_width$} {:<owner_width$} {:<group_width$} {:>size_width$} {} {}",
+                r.mode, r.links, r.owner, r.group, r.size, r.time, r.name
+            )?;
+        }
+    } else {
+        let mut names = Vec::with_capacity(entries.len());
+        let mut lengths = Vec::with_capacity(entries.len());
+
+        for e in entries.iter() {
+            let name = e.name.to_string_lossy();
+            let metadata = e.metadata().ok();
+            let indicator = if options.contains(&'F') {
+                indicator(metadata.as_ref())
+            } else {
+                ""
+            };
+
+            // The lengths are taken before colorizing, since escape sequences take no room on the
+            // terminal.
+            lengths.push(name.chars().count() + indicator.len());
+            names.push(format!(
+                "{}{}",
+                paint(&name, metadata.as_ref(), colors),
+                indicator
+            ));
+        }
+
+        if options.contains(&'1') || !streams.terminal {
+            for name in names {
+                writeln!(streams.stdout, "{}", name)?;
             }
+        } else {
+            let width = terminal::width().unwrap_or(DEFAULT_WIDTH);
 
-            return Ok(true);
+            write!(streams.stdout, "{}", format_grid(&names, &lengths, width))?;
         }
-        Err(e) => handle_error(e, path),
+    }
+
+    streams.stdout.flush()
+}
+
+/// Wraps `name` in the escape sequences giving it the color of its file type, like the default colors of
+/// GNU ls: blue for directories, cyan for symlinks, yellow for FIFOs and devices, magenta for sockets and
+/// green for executable files. Other files, and files whose metadata cannot be read, keep the default
+/// color, as does every name when `colors` is false.
+fn paint(name: &str, metadata: Option<&fs::Metadata>, colors: bool) -> String {
+    let Some(metadata) = metadata.filter(|_| colors) else {
+        return name.to_string();
+    };
+
+    let color = match FileType(metadata.file_type()).symbol() {
+        'd' => "01;34",
+        'l' => "01;36",
+        'p' => "40;33",
+        's' => "01;35",
+        'b' | 'c' => "40;33;01",
+        _ if is_executable(metadata) => "01;32",
+        _ => return name.to_string(),
+    };
+
+    format!("\x1b[{}m{}\x1b[0m", color, name)
+}
+
+/// Returns the indicator `-F` appends to a name for its file type: `/` for directories, `@` for symlinks,
+/// `|` for FIFOs, `=` for sockets and `*` for executable files. Other files, and files whose metadata
+/// cannot be read, have none.
+fn indicator(metadata: Option<&fs::Metadata>) -> &'static str {
+    let Some(metadata) = metadata else {
+        return "";
+    };
+
+    match FileType(metadata.file_type()).symbol() {
+        'd' => "/",
+        'l' => "@",
+        'p' => "|",
+        's' => "=",
+        '-' if is_executable(metadata) => "*",
+        _ => "",
+    }
+}
+
+/// Returns whether any class of users may execute the file.
+fn is_executable(metadata: &fs::Metadata) -> bool {
+    let permissions = metadata.permissions();
+
+    permissions.owner().executable()
+        || permissions.group().executable()
+        || permissions.other().executable()
+}
+
+/// Formats a modification time like `ls -l` does: `Mon DD HH:MM` for times within the last six months,
+/// and `Mon DD  YYYY` for older times or times in the future. An unknown time is printed as `?`.
+fn format_time(time: Option<SystemTime>) -> String {
+    let Some((time, date)) = time.and_then(|time| Some((time, DateTime::local(time)?))) else {
+        return String::from("?");
+    };
+
+    // The age is an error for times in the future, which are never recent.
+    let recent = SystemTime::now()
+        .duration_since(time)
+        .is_ok_and(|age| age < SIX_MONTHS);
+
+    if recent {
+        format!(
+            "{} {:>2} {:02}:{:02}",
+            MONTHS[date.month as usize - 1],
+            date.day,
+            date.hour,
+            date.minute
+        )
+    } else {
+        format!(
+            "{} {:>2}  {}",
+            MONTHS[date.month as usize - 1],
+            date.day,
+            date.year
+        )
+    }
+}
+
+/// Formats the names into aligned columns fitting in `width`, filled down then across like GNU ls.
+///
+/// The layout uses as many columns as possible, each one being as wide as its longest name, and
+/// separated from the next one by two spaces. A name longer than `width` ends up alone on its line.
+/// `lengths` holds the number of columns each name takes on the terminal.
+fn format_grid(names: &[String], lengths: &[usize], width: usize) -> String {
+    let mut layout = (1, vec![lengths.iter().copied().max().unwrap_or(0)]);
+
+    for columns in (2..=names.len()).rev() {
+        let rows = names.len().div_ceil(columns);
+
+        // Fewer columns may be needed once the names are spread over the rows.
+        if names.len().div_ceil(rows) != columns {
+            continue;
+        }
+
+        let widths: Vec<usize> = lengths
+            .chunks(rows)
+            .map(|column| column.iter().copied().max().unwrap_or(0))
+            .collect();
+
+        if widths.iter().sum::<usize>() + COLUMN_SEPARATOR * (columns - 1) <= width {
+            layout = (rows, widths);
+            break;
+        }
+    }
+
+    let (rows, widths) = layout;
+    let mut out = String::new();
+
+    for row in 0..rows {
+        let mut line = String::new();
+
+        for (column, column_width) in widths.iter().enumerate() {
+            if let Some(name) = names.get(column * rows + row) {
+                line.push_str(name);
+                line.extend(std::iter::repeat_n(
+                    ' ',
+                    column_width - lengths[column * rows + row] + COLUMN_SEPARATOR,
+                ));
+            }
+        }
+
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Sorts the entries by name, comparing the raw bytes like GNU ls in the C locale. With the `-t` option,
+/// entries are sorted by modification time instead, newest first, and with the `-S` option by size,
+/// largest first, `-S` taking precedence over `-t`. Both fall back to the name on ties. The order is
+/// reversed when the `-r` option is given.
+fn sort_entries(entries: &mut [Entry], options: &HashSet<char>) {
+    if options.contains(&'S') {
+        entries.sort_by_cached_key(|e| (Reverse(e.size()), e.name.clone()));
+    } else if options.contains(&'t') {
+        entries.sort_by_cached_key(|e| (Reverse(e.modified()), e.name.clone()));
+    } else {
+        entries.sort_by(|a, b| a.name.as_bytes().cmp(b.name.as_bytes()));
+    }
+
+    if options.contains(&'r') {
+        entries.reverse();
     }
 }
 
@@ -114,14 +531,14 @@ fn read_entries(read_dir: ReadDir) -> Result<Vec<DirEntry>, Vec<io::Error>> {
         })
         .collect();
 
-    if errors.len() > 0 {
+    if !errors.is_empty() {
         return Err(errors);
     }
 
-    return Ok(entries);
+    Ok(entries)
 }
 
-fn handle_error(error: io::Error, path: String) -> io::Result<bool> {
+fn handle_error(error: io::Error, path: &str) {
     match error.kind() {
         io::ErrorKind::NotFound => eprintln!("no such file or directory: {}", path),
         io::ErrorKind::PermissionDenied => {
@@ -129,60 +546,352 @@ fn handle_error(error: io::Error, path: String) -> io::Result<bool> {
         }
         _ => eprintln!("file is not a directory: {}", path),
     }
-
-    return Ok(true);
 }
 
-fn parse(args: Vec<String>) -> (String, HashSet<char>) {
+/// Splits the arguments of the `ls` command into paths and options.
+///
+/// Options may appear anywhere among the paths, and their letters are accumulated into a set, apart from
+/// `--color`, whose mode is returned on its own. The `--` argument marks the end of the options, every
+/// argument after it being treated as a path. Returns the value given to `--color` if it is invalid.
+fn parse(args: Vec<String>) -> Result<(Vec<String>, HashSet<char>, ColorMode), String> {
     let mut uniques: HashSet<char> = HashSet::new();
+    let mut color_mode = ColorMode::Auto;
+    let mut paths = vec![];
+    let mut args = args.into_iter();
 
-    if args.len() == 0 {
-        return (String::from("."), uniques);
-    }
-
-    let first_arg = args.get(0).unwrap();
+    for arg in args.by_ref() {
+        if arg == "--" {
+            break;
+        }
 
-    if first_arg.starts_with("-") {
-        if let Some(letters) = first_arg.get(1..) {
-            letters
-                .chars()
-                .collect::<Vec<char>>()
-                .retain(|e| uniques.insert(*e));
+        if let Some(when) = arg.strip_prefix("--color") {
+            color_mode = match when {
+                "" | "=always" => ColorMode::Always,
+                "=never" => ColorMode::Never,
+                "=auto" => ColorMode::Auto,
+                _ => return Err(when.trim_start_matches('=').to_string()),
+            };
+            continue;
+        }
 
-            if let Some(second_arg) = args.get(1) {
-                (second_arg.to_string(), uniques)
-            } else {
-                (String::from("."), uniques)
-            }
-        } else {
-            (first_arg.to_string(), uniques)
+        match arg.strip_prefix('-') {
+            Some(letters) if !letters.is_empty() => uniques.extend(letters.chars()),
+            _ => paths.push(arg),
         }
-    } else {
-        (first_arg.to_string(), uniques)
     }
+
+    paths.extend(args);
+
+    if paths.is_empty() {
+        paths.push(String::from("."));
+    }
+
+    Ok((paths, uniques, color_mode))
 }
 
 /// Validates the provided options for the `ls` command.
 ///
 /// This function takes a reference to a `HashSet<char>` containing the options for the `ls` command.
 ///
-/// It checks if each option is valid and only allows the option 'l' for the moment.
+/// It checks if each option is valid and only allows the options 'l', 'a', 'A', 'r', 't', 'S', 'h',
+/// '1', 'n', 'F' and 'R' for the moment.
 ///
 /// # Arguments
 ///
 /// * `options` - A reference to a `HashSet<char>` containing the options for the `ls` command.
 fn validate_ls_options(options: &HashSet<char>) -> Result<(), &char> {
-    let valid_options = ['l'];
+    let valid_options = ['l', 'a', 'A', 'r', 't', 'S', 'h', '1', 'n', 'F', 'R'];
 
     if options.is_empty() {
         return Ok(());
     }
 
-    for (_, option) in options.iter().enumerate() {
+    for option in options.iter() {
         if !valid_options.contains(option) {
             return Err(option);
         }
     }
 
-    return Ok(());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn options(letters: &str) -> HashSet<char> {
+        letters.chars().collect()
+    }
+
+    fn ls(arg: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arg), streams))
+    }
+
+    #[test]
+    fn long_listing_skips_missing_entries() {
+        let dir = TempDir::new();
+        let entries = vec![Entry::new(OsString::from("gone"), dir.path().join("gone"))];
+
+        let (_, output) = run("", |streams| {
+            print_entries(entries, &options("l"), false, streams).map(|_| Status::SUCCESS)
+        });
+
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn almost_all_shows_dotfiles_but_not_dot_and_dot_dot() {
+        let dir = TempDir::new();
+        dir.write(".hidden", "");
+        dir.write("visible", "");
+
+        assert_eq!(ls(&[&dir.join("")]).1, "visible\n");
+        assert_eq!(ls(&["-A", &dir.join("")]).1, ".hidden\nvisible\n");
+        assert_eq!(ls(&["-a", &dir.join("")]).1, ".\n..\n.hidden\nvisible\n");
+        assert_eq!(ls(&["-aA", &dir.join("")]).1, ".hidden\nvisible\n");
+    }
+
+    #[test]
+    fn sorts_names_bytewise() {
+        let dir = TempDir::new();
+
+        for name in ["b", "a", "C", "_", "c"] {
+            dir.write(name, "");
+        }
+
+        assert_eq!(ls(&[&dir.join("")]).1, "C\n_\na\nb\nc\n");
+        assert_eq!(ls(&["-r", &dir.join("")]).1, "c\nb\na\n_\nC\n");
+    }
+
+    #[test]
+    fn s_sorts_by_size_largest_first() {
+        let dir = TempDir::new();
+        dir.write("small", "1");
+        dir.write("large", "12345");
+        dir.write("medium", "123");
+        dir.write("tie", "123");
+
+        assert_eq!(ls(&["-S", &dir.join("")]).1, "large\nmedium\ntie\nsmall\n");
+        assert_eq!(ls(&["-Sr", &dir.join("")]).1, "small\ntie\nmedium\nlarge\n");
+    }
+
+    #[test]
+    fn long_listing_shows_symlinks_as_links() {
+        let dir = TempDir::new();
+        fs::create_dir(dir.join("target")).unwrap();
+        std::os::unix::fs::symlink(dir.join("target"), dir.join("link")).unwrap();
+
+        let (_, output) = ls(&["-l", &dir.join("")]);
+        let link = output.lines().find(|line| line.contains("link")).unwrap();
+
+        assert!(link.starts_with('l'), "{}", link);
+        assert!(link.ends_with(&format!("link -> {}", dir.join("target"))));
+    }
+
+    #[test]
+    fn lists_basenames_of_directory_entries() {
+        let dir = TempDir::new();
+        dir.write("foo", "");
+        fs::create_dir(dir.join("sub")).unwrap();
+
+        assert_eq!(ls(&[&dir.join("")]).1, "foo\nsub\n");
+        assert_eq!(ls(&[&dir.join("sub/..")]).1, "foo\nsub\n");
+    }
+
+    #[test]
+    fn lists_file_arguments_as_given() {
+        let dir = TempDir::new();
+        let foo = dir.write("foo", "");
+
+        assert_eq!(ls(&[&foo]).1, format!("{}\n", foo));
+    }
+
+    #[test]
+    fn options_may_come_before_after_or_between_paths() {
+        let dir = TempDir::new();
+        dir.write("a", "");
+
+        let before = parse(args(&["-l", "x", "y"])).unwrap();
+        let after = parse(args(&["x", "y", "-l"])).unwrap();
+        let between = parse(args(&["x", "-l", "y", "-r"])).unwrap();
+
+        assert_eq!((before.0, before.1), (args(&["x", "y"]), options("l")));
+        assert_eq!((after.0, after.1), (args(&["x", "y"]), options("l")));
+        assert_eq!((between.0, between.1), (args(&["x", "y"]), options("lr")));
+        assert!(ls(&[&dir.join(""), "-l"]).1.ends_with(" a\n"));
+    }
+
+    #[test]
+    fn double_dash_ends_the_options() {
+        let (paths, letters, _) = parse(args(&["-r", "--", "-l"])).unwrap();
+
+        assert_eq!(paths, args(&["-l"]));
+        assert_eq!(letters, options("r"));
+    }
+
+    #[test]
+    fn long_listing_shows_the_link_count() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+        fs::hard_link(&file, dir.join("other")).unwrap();
+
+        let (_, output) = ls(&["-l", &file]);
+
+        assert_eq!(output.split_whitespace().nth(1), Some("2"));
+    }
+
+    #[test]
+    fn long_listing_aligns_its_columns() {
+        let dir = TempDir::new();
+        dir.write("big", &"x".repeat(12345));
+        dir.write("small", "x");
+        fs::hard_link(dir.join("small"), dir.join("tiny")).unwrap();
+
+        let (_, output) = ls(&["-ln", &dir.join("")]);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        // The sizes are right-aligned, and the names start at the same offset.
+        assert!(lines[0].contains(" 12345 "), "{}", output);
+        assert!(lines[1].contains("     1 "), "{}", output);
+        assert_eq!(lines[0].find("big"), lines[1].find("small"));
+        assert_eq!(lines[1].find("small"), lines[2].find("tiny"));
+    }
+
+    #[test]
+    fn invalid_option_prints_nothing_on_the_standard_output() {
+        assert_eq!(ls(&["-j"]), (Status::FAILURE, String::new()));
+    }
+
+    /// Creates a directory, an executable file, a plain file and a symlink in `dir`.
+    fn typed_entries(dir: &TempDir) {
+        fs::create_dir(dir.join("dir")).unwrap();
+        let program = dir.write("program", "");
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+        dir.write("plain", "");
+        std::os::unix::fs::symlink("plain", dir.join("link")).unwrap();
+    }
+
+    #[test]
+    fn color_never_prints_no_escape_sequences() {
+        let dir = TempDir::new();
+        typed_entries(&dir);
+        let path = dir.join("");
+
+        for arguments in [&["--color=never"][..], &["--color=never", "-l"], &[]] {
+            let arguments = [arguments, &[path.as_str()]].concat();
+            let (status, output) = ls(&arguments);
+
+            assert_eq!(status, Status::SUCCESS);
+            assert!(!output.contains('\x1b'), "{:?}", output);
+        }
+    }
+
+    #[test]
+    fn color_always_colorizes_by_file_type() {
+        let dir = TempDir::new();
+        typed_entries(&dir);
+
+        let (_, output) = ls(&["--color=always", &dir.join("")]);
+
+        assert_eq!(
+            output,
+            "\x1b[01;34mdir\x1b[0m\n\x1b[01;36mlink\x1b[0m\nplain\n\x1b[01;32mprogram\x1b[0m\n"
+        );
+        assert_eq!(ls(&["--color", &dir.join("")]).1, output);
+    }
+
+    #[test]
+    fn invalid_color_mode_fails() {
+        assert_eq!(ls(&["--color=sometimes"]), (Status::FAILURE, String::new()));
+    }
+
+    #[test]
+    fn f_appends_the_type_indicators() {
+        let dir = TempDir::new();
+        typed_entries(&dir);
+
+        let (status, output) = ls(&["-F", &dir.join("")]);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "dir/\nlink@\nplain\nprogram*\n");
+        assert_eq!(ls(&["-1F", &dir.join("")]).1, output);
+    }
+
+    #[test]
+    fn f_gives_a_directory_a_trailing_slash_in_columns() {
+        let dir = TempDir::new();
+        fs::create_dir(dir.join("sub")).unwrap();
+        dir.write("file", "");
+        let mut stdin = io::empty();
+        let mut stdout = vec![];
+        let mut streams = Streams {
+            stdin: &mut stdin,
+            stdout: &mut stdout,
+            terminal: true,
+            shell_stdin: false,
+        };
+
+        execute(args(&["-F", "--color=never", &dir.join("")]), &mut streams).unwrap();
+
+        let output = String::from_utf8(stdout).unwrap();
+        assert_eq!(
+            output.split_whitespace().collect::<Vec<_>>(),
+            ["file", "sub/"]
+        );
+    }
+
+    /// Returns the headers `ls -R` prints with the given options for a tree holding the directories `a`,
+    /// `a/c`, `b` and `.hidden`, a file and a symlink to `a`, relative to the root of the tree.
+    fn recursive_headers(options: &str) -> Vec<String> {
+        let dir = TempDir::new();
+        fs::create_dir_all(dir.join("a/c")).unwrap();
+        fs::create_dir_all(dir.join(".hidden")).unwrap();
+        fs::create_dir(dir.join("b")).unwrap();
+        dir.write("file", "");
+        std::os::unix::fs::symlink("a", dir.join("link")).unwrap();
+        let root = dir.path().display().to_string();
+
+        let (_, output) = ls(&[options, &root]);
+
+        output
+            .lines()
+            .filter_map(|line| line.strip_suffix(':'))
+            .map(|header| header.replacen(&root, "", 1))
+            .collect()
+    }
+
+    #[test]
+    fn recursive_listing_prints_a_header_before_each_directory() {
+        let dir = TempDir::new();
+        fs::create_dir_all(dir.join("a/c")).unwrap();
+        dir.write("a/file", "");
+        let root = dir.path().display().to_string();
+
+        let (status, output) = ls(&["-R", &root]);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(
+            output,
+            format!("{0}:\na\n\n{0}/a:\nc\nfile\n\n{0}/a/c:\n", root)
+        );
+    }
+
+    #[test]
+    fn recursive_listing_walks_the_tree_depth_first_in_order() {
+        assert_eq!(recursive_headers("-R"), ["", "/a", "/a/c", "/b"]);
+        assert_eq!(recursive_headers("-Rr"), ["", "/b", "/a", "/a/c"]);
+    }
+
+    #[test]
+    fn recursive_listing_descends_into_hidden_directories_with_a() {
+        assert_eq!(
+            recursive_headers("-Ra"),
+            ["", "/.hidden", "/a", "/a/c", "/b"]
+        );
+        assert_eq!(recursive_headers("-RA"), recursive_headers("-Ra"));
+    }
 }
@@ -0,0 +1,98 @@
+use std::{fs, io, os::unix::fs::PermissionsExt, path::Path};
+
+use crate::unix::permissions::Mode;
+
+use super::Status;
+
+/// Execute the `mkdir` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `mkdir` command.
+///
+/// It creates each directory given as argument. With `-p`, missing parent directories are created too and
+/// existing directories are not an error. With `-m MODE`, the mode of the new directories is set to `MODE`,
+/// symbolic modes being relative to `a=rwx`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `mkdir` command.
+pub fn execute(args: Vec<String>) -> io::Result<Status> {
+    let mut parents = false;
+    let mut mode = None;
+    let mut dirs = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" => parents = true,
+            "-m" => match args.next() {
+                Some(value) => mode = Some(value),
+                None => {
+                    eprintln!("mkdir : option requires an argument - 'm'");
+
+                    return Ok(Status::FAILURE);
+                }
+            },
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("mkdir : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => dirs.push(arg),
+        }
+    }
+
+    let mode = match mode.map(|mode| Mode::parse_relative(&mode, 0o777)) {
+        Some(Ok(mode)) => Some(mode),
+        Some(Err(e)) => {
+            eprintln!("mkdir : {}", e);
+
+            return Ok(Status::FAILURE);
+        }
+        None => None,
+    };
+
+    if dirs.is_empty() {
+        eprintln!("mkdir : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for dir in dirs {
+        if let Err(e) = create_dir(&dir, parents, mode) {
+            handle_error(e, &dir);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Creates the directory at `path`, then sets its mode if one is given. Only the directory itself gets the
+/// mode, not the parents created along with it.
+fn create_dir(path: &str, parents: bool, mode: Option<u32>) -> io::Result<()> {
+    if parents {
+        if Path::new(path).is_dir() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(path)?;
+    } else {
+        fs::create_dir(path)?;
+    }
+
+    match mode {
+        Some(mode) => fs::set_permissions(path, fs::Permissions::from_mode(mode)),
+        None => Ok(()),
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::AlreadyExists => eprintln!("mkdir : file exists: {}", path),
+        io::ErrorKind::NotFound => eprintln!("mkdir : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("mkdir : permission denied: {}", path),
+        _ => eprintln!("mkdir : {}: {}", path, error),
+    }
+}
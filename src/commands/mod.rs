@@ -1,3 +1,1000 @@
+pub mod alias;
+pub mod basename;
+pub mod cat;
+pub mod cd;
+pub mod chmod;
+pub mod chown;
+pub mod clear;
+pub mod comm;
+pub mod cp;
+pub mod cut;
+pub mod date;
+pub mod df;
+pub mod diff;
+pub mod dirname;
+pub mod du;
 pub mod echo;
+pub mod env;
 pub mod exit;
-pub mod ls;
\ No newline at end of file
+pub mod export;
+pub mod r#false;
+pub mod find;
+pub mod grep;
+pub mod head;
+pub mod help;
+pub mod history;
+pub mod id;
+pub mod ln;
+pub mod ls;
+pub mod mkdir;
+pub mod mv;
+pub mod nl;
+pub mod printf;
+pub mod pwd;
+pub mod readlink;
+pub mod realpath;
+pub mod rev;
+pub mod rm;
+pub mod seq;
+pub mod sleep;
+pub mod sort;
+pub mod split;
+pub mod stat;
+pub mod tac;
+pub mod tail;
+pub mod tee;
+pub mod touch;
+pub mod tr;
+pub mod r#true;
+pub mod unalias;
+pub mod uniq;
+pub mod wc;
+pub mod which;
+pub mod whoami;
+pub mod xargs;
+pub mod yes;
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Read, Write},
+    mem,
+    os::unix::process::{CommandExt, ExitStatusExt},
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TryRecvError, TrySendError},
+    thread,
+    time::Duration,
+};
+
+use crate::unix::path::find_executables;
+
+use alias::Aliases;
+use cd::DirectoryState;
+use env::Environment;
+use history::History;
+
+/// The standard streams of a command, which the shell may redirect away from the terminal.
+pub struct Streams<'a> {
+    pub stdin: &'a mut dyn BufRead,
+    pub stdout: &'a mut dyn Write,
+    /// Whether `stdout` is the terminal, rather than a file.
+    pub terminal: bool,
+    /// Whether `stdin` is the standard input of the shell, rather than a file or a pipe, in which case
+    /// external programs read it directly.
+    pub shell_stdin: bool,
+}
+
+/// The outcome of a command: its exit status, and whether the shell must exit after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Status {
+    pub code: i32,
+    pub exit: bool,
+}
+
+impl Status {
+    pub const SUCCESS: Status = Status {
+        code: 0,
+        exit: false,
+    };
+
+    pub const FAILURE: Status = Status {
+        code: 1,
+        exit: false,
+    };
+
+    /// The status of a command asking the shell to exit with `code`.
+    pub fn exit(code: i32) -> Status {
+        Status { code, exit: true }
+    }
+}
+
+/// Runs a command of the shell with its arguments, for commands running other commands.
+pub type Runner<'a> = dyn FnMut(String, Vec<String>, &mut Streams) -> io::Result<Status> + 'a;
+
+/// Runs a command of the shell with its arguments in the given environment, for `env`.
+pub type EnvRunner<'a> =
+    dyn FnMut(Environment, String, Vec<String>, &mut Streams) -> io::Result<Status> + 'a;
+
+/// The state of the shell, kept across command executions.
+#[derive(Default, Clone)]
+pub struct ShellState {
+    pub aliases: Aliases,
+    pub directory: DirectoryState,
+    /// The variables set by `env` for the command it is running.
+    pub environment: Environment,
+    pub history: History,
+    /// Whether the shell reads its commands from a terminal, in which case it prints a prompt, edits the
+    /// lines typed and catches Ctrl-C. It is detected once, when the shell starts.
+    pub interactive: bool,
+    /// The exit status of the last command.
+    pub last_status: i32,
+    pub registry: CommandRegistry,
+    /// Whether the commands run in a stage of a pipeline but the last, like in the subshells of other
+    /// shells. The current directory and the environment are shared by every stage, so `cd` and `export`
+    /// then only check their arguments, without changing them.
+    pub subshell: bool,
+}
+
+/// The signature shared by the commands of the registry.
+pub type Handler = fn(Vec<String>, &mut ShellState, &mut Streams) -> io::Result<Status>;
+
+/// A command of the registry, along with the descriptions printed by `help`.
+#[derive(Clone, Copy)]
+pub struct Builtin {
+    pub handler: Handler,
+    /// The synopsis of the arguments of the command, starting with its name.
+    pub usage: &'static str,
+    /// A one-line description of the command.
+    pub summary: &'static str,
+}
+
+/// The commands known to the shell, by name.
+#[derive(Clone)]
+pub struct CommandRegistry {
+    builtins: HashMap<&'static str, Builtin>,
+}
+
+impl CommandRegistry {
+    /// Registers the command `name`, replacing any command previously registered under that name.
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        usage: &'static str,
+        summary: &'static str,
+        handler: Handler,
+    ) {
+        self.builtins.insert(
+            name,
+            Builtin {
+                handler,
+                usage,
+                summary,
+            },
+        );
+    }
+
+    /// Returns the names of the registered commands, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.builtins.keys().copied()
+    }
+
+    /// Returns the command `name`, if there is one.
+    pub fn builtin(&self, name: &str) -> Option<&Builtin> {
+        self.builtins.get(name)
+    }
+
+    /// Returns the handler of the command `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<Handler> {
+        self.builtin(name).map(|builtin| builtin.handler)
+    }
+}
+
+impl Default for CommandRegistry {
+    /// Returns a registry holding every builtin command of the shell.
+    fn default() -> Self {
+        let mut registry = CommandRegistry {
+            builtins: HashMap::new(),
+        };
+
+        registry.register(
+            "alias",
+            "alias [NAME[=VALUE]]...",
+            "Define or print aliases.",
+            |args, state, streams| alias::execute(args, &mut state.aliases, streams),
+        );
+        registry.register(
+            "basename",
+            "basename NAME [SUFFIX] | basename [-a] [-s SUFFIX] NAME...",
+            "Print the last component of paths.",
+            |args, _, streams| basename::execute(args, streams),
+        );
+        registry.register(
+            "cat",
+            "cat [FILE]...",
+            "Concatenate files and print them on the standard output.",
+            |args, _, streams| cat::execute(args, streams),
+        );
+        registry.register(
+            "cd",
+            "cd [DIR | -]",
+            "Change the current directory.",
+            |args, state, streams| cd::execute(args, &mut state.directory, state.subshell, streams),
+        );
+        registry.register(
+            "chmod",
+            "chmod MODE FILE...",
+            "Change the mode of files.",
+            |args, _, _| chmod::execute(args),
+        );
+        registry.register(
+            "chown",
+            "chown [-R] OWNER[:GROUP] FILE...",
+            "Change the owner and group of files.",
+            |args, _, _| chown::execute(args),
+        );
+        registry.register(
+            "clear",
+            "clear",
+            "Clear the terminal screen.",
+            |args, _, streams| clear::execute(args, streams),
+        );
+        registry.register(
+            "comm",
+            "comm [-123] FILE1 FILE2",
+            "Compare two sorted files line by line.",
+            |args, _, streams| comm::execute(args, streams),
+        );
+        registry.register(
+            "cp",
+            "cp [-rRpi] SOURCE... DEST",
+            "Copy files and directories.",
+            |args, _, streams| cp::execute(args, streams),
+        );
+        registry.register(
+            "cut",
+            "cut (-b LIST | -c LIST | -f LIST [-d DELIM] [-s]) [FILE]...",
+            "Print selected bytes, characters or fields of each line.",
+            |args, _, streams| cut::execute(args, streams),
+        );
+        registry.register(
+            "date",
+            "date [-u] [+FORMAT]",
+            "Print the current date and time.",
+            |args, _, streams| date::execute(args, streams),
+        );
+        registry.register(
+            "df",
+            "df [-ah]",
+            "Print the space used and available on the mounted filesystems.",
+            |args, _, streams| df::execute(args, streams),
+        );
+        registry.register(
+            "diff",
+            "diff [-u] [-U N] FILE1 FILE2",
+            "Print the differences between two files.",
+            |args, _, streams| diff::execute(args, streams),
+        );
+        registry.register(
+            "dirname",
+            "dirname NAME...",
+            "Print paths without their last component.",
+            |args, _, streams| dirname::execute(args, streams),
+        );
+        registry.register(
+            "du",
+            "du [-hs] [-d N] [FILE]...",
+            "Print the disk usage of directory trees.",
+            |args, _, streams| du::execute(args, streams),
+        );
+        registry.register(
+            "echo",
+            "echo [-ne] [STRING]...",
+            "Print a line of text.",
+            |args, _, streams| echo::execute(args, streams),
+        );
+        registry.register(
+            "env",
+            "env [NAME=VALUE]... [COMMAND [ARG]...]",
+            "Run a command in a modified environment, or print the environment.",
+            |args, state, streams| {
+                let environment = state.environment.clone();
+
+                env::execute(
+                    args,
+                    &environment,
+                    streams,
+                    &mut |environment, command_name, args, streams| {
+                        let outer = mem::replace(&mut state.environment, environment);
+                        let result = execute_command(command_name, args, state, streams);
+                        state.environment = outer;
+
+                        result
+                    },
+                )
+            },
+        );
+        registry.register(
+            "exit",
+            "exit [STATUS]",
+            "Exit the shell.",
+            |args, state, streams| exit::execute(args, state.last_status, streams),
+        );
+        registry.register(
+            "export",
+            "export [NAME[=VALUE]]...",
+            "Set environment variables, or print them.",
+            |args, state, streams| export::execute(args, state.subshell, streams),
+        );
+        registry.register(
+            "false",
+            "false",
+            "Do nothing, unsuccessfully.",
+            |args, _, _| r#false::execute(args),
+        );
+        registry.register(
+            "find",
+            "find [PATH]... [-name PATTERN] [-type f|d|l] [-maxdepth N]",
+            "Search for files in directory trees.",
+            |args, _, streams| find::execute(args, streams),
+        );
+        registry.register(
+            "grep",
+            "grep [-invc] PATTERN [FILE]...",
+            "Print the lines containing a pattern.",
+            |args, _, streams| grep::execute(args, streams),
+        );
+        registry.register(
+            "head",
+            "head [-n N | -c N] [FILE]...",
+            "Print the first lines of files.",
+            |args, _, streams| head::execute(args, streams),
+        );
+        registry.register(
+            "help",
+            "help [COMMAND]...",
+            "Describe the commands of the shell.",
+            |args, state, streams| help::execute(args, &state.registry, streams),
+        );
+        registry.register(
+            "history",
+            "history [N | -c]",
+            "Print or clear the command history.",
+            |args, state, streams| history::execute(args, &mut state.history, streams),
+        );
+        registry.register(
+            "id",
+            "id [-u|-g] [-n] [USER]",
+            "Print the user and group ids of the current or given user.",
+            |args, _, streams| id::execute(args, streams),
+        );
+        registry.register(
+            "ln",
+            "ln [-sf] TARGET... [DEST]",
+            "Create hard or symbolic links to files.",
+            |args, _, _| ln::execute(args),
+        );
+        registry.register(
+            "ls",
+            "ls [-laArtSh1n] [FILE]...",
+            "List the content of directories.",
+            |args, _, streams| ls::execute(args, streams),
+        );
+        registry.register(
+            "mkdir",
+            "mkdir [-p] [-m MODE] DIR...",
+            "Create directories.",
+            |args, _, _| mkdir::execute(args),
+        );
+        registry.register(
+            "mv",
+            "mv [-in] SOURCE... DEST",
+            "Move or rename files.",
+            |args, _, streams| mv::execute(args, streams),
+        );
+        registry.register(
+            "nl",
+            "nl [-b STYLE] [-w N] [-s SEP] [FILE]...",
+            "Print the lines of the files, numbered.",
+            |args, _, streams| nl::execute(args, streams),
+        );
+        registry.register(
+            "printf",
+            "printf FORMAT [ARGUMENT]...",
+            "Print the arguments following a format.",
+            |args, _, streams| printf::execute(args, streams),
+        );
+        registry.register(
+            "pwd",
+            "pwd [-LP]",
+            "Print the current directory.",
+            |args, _, streams| pwd::execute(args, streams),
+        );
+        registry.register(
+            "readlink",
+            "readlink [-f | -e] FILE...",
+            "Print the target of symbolic links.",
+            |args, _, streams| readlink::execute(args, streams),
+        );
+        registry.register(
+            "realpath",
+            "realpath [-ms] FILE...",
+            "Print the resolved absolute path of files.",
+            |args, _, streams| realpath::execute(args, streams),
+        );
+        registry.register(
+            "rev",
+            "rev [FILE]...",
+            "Print the lines of the files with their characters reversed.",
+            |args, _, streams| rev::execute(args, streams),
+        );
+        registry.register(
+            "rm",
+            "rm [-rRfi] FILE...",
+            "Remove files and directories.",
+            |args, _, streams| rm::execute(args, streams),
+        );
+        registry.register(
+            "seq",
+            "seq [-w] [-s SEP] [FIRST [INCREMENT]] LAST",
+            "Print a sequence of numbers.",
+            |args, _, streams| seq::execute(args, streams),
+        );
+        registry.register(
+            "sleep",
+            "sleep NUMBER[SUFFIX]...",
+            "Pause for the given duration.",
+            |args, _, _| sleep::execute(args),
+        );
+        registry.register(
+            "sort",
+            "sort [-rnuf] [-k START[,END]] [FILE]...",
+            "Print the lines of the files sorted.",
+            |args, _, streams| sort::execute(args, streams),
+        );
+        registry.register(
+            "split",
+            "split [-l N | -b N] [FILE [PREFIX]]",
+            "Split a file into pieces.",
+            |args, _, streams| split::execute(args, streams),
+        );
+        registry.register(
+            "stat",
+            "stat [-c FORMAT] FILE...",
+            "Print the metadata of files.",
+            |args, _, streams| stat::execute(args, streams),
+        );
+        registry.register(
+            "tac",
+            "tac [-s SEP] [FILE]...",
+            "Print the lines of the files in reverse order.",
+            |args, _, streams| tac::execute(args, streams),
+        );
+        registry.register(
+            "tail",
+            "tail [-f] [-n N | -c N] [FILE]...",
+            "Print the last lines of files.",
+            |args, _, streams| tail::execute(args, streams),
+        );
+        registry.register(
+            "tee",
+            "tee [-a] [FILE]...",
+            "Copy the standard input to the standard output and to files.",
+            |args, _, streams| tee::execute(args, streams),
+        );
+        registry.register(
+            "touch",
+            "touch [-c] [-t STAMP | -d DATE] FILE...",
+            "Change the timestamps of files, creating them if needed.",
+            |args, _, _| touch::execute(args),
+        );
+        registry.register(
+            "tr",
+            "tr [-ds] SET1 [SET2]",
+            "Translate, delete or squeeze bytes of the standard input.",
+            |args, _, streams| tr::execute(args, streams),
+        );
+        registry.register("true", "true", "Do nothing, successfully.", |args, _, _| {
+            r#true::execute(args)
+        });
+        registry.register(
+            "unalias",
+            "unalias [-a] NAME...",
+            "Remove aliases.",
+            |args, state, _| unalias::execute(args, &mut state.aliases),
+        );
+        registry.register(
+            "uniq",
+            "uniq [-cdui] [FILE]",
+            "Print the lines of a file, collapsing adjacent equal lines.",
+            |args, _, streams| uniq::execute(args, streams),
+        );
+        registry.register(
+            "wc",
+            "wc [-lwcm] [FILE]...",
+            "Print the line, word and byte counts of files.",
+            |args, _, streams| wc::execute(args, streams),
+        );
+        registry.register(
+            "which",
+            "which [-a] NAME...",
+            "Locate a command of the shell or a program in PATH.",
+            |args, state, streams| {
+                which::execute(args, &state.registry, &state.environment, streams)
+            },
+        );
+        registry.register(
+            "whoami",
+            "whoami",
+            "Print the name of the current user.",
+            |args, _, streams| whoami::execute(args, streams),
+        );
+        registry.register(
+            "xargs",
+            "xargs [-n N] [-I REPLACE] [COMMAND [ARG]...]",
+            "Run a command with arguments read from the standard input.",
+            |args, state, streams| {
+                xargs::execute(args, streams, &mut |command_name, args, streams| {
+                    execute_command(command_name, args, state, streams)
+                })
+            },
+        );
+        registry.register(
+            "yes",
+            "yes [STRING]...",
+            "Print a line repeatedly until the output is closed.",
+            |args, _, streams| yes::execute(args, streams),
+        );
+
+        registry
+    }
+}
+
+/// Prints the help of a command when `--help` is one of its arguments, returning whether it did, in which
+/// case the command returns without doing its normal work.
+///
+/// # Arguments
+///
+/// * `args` - The arguments of the command.
+/// * `help` - The help of the command, describing its usage and options.
+/// * `streams` - The standard streams of the command.
+pub fn print_help(args: &[String], help: &str, streams: &mut Streams) -> io::Result<bool> {
+    if !args.iter().any(|arg| arg == "--help") {
+        return Ok(false);
+    }
+
+    write!(streams.stdout, "{}", help)?;
+
+    Ok(true)
+}
+
+/// Splits the arguments of a command into its operands and the letters of its options, for the commands
+/// whose options take no value. Options may appear anywhere among the operands, and the `--` argument
+/// marks the end of the options, every argument after it being an operand.
+pub fn split_options(args: Vec<String>) -> (Vec<String>, String) {
+    let mut options = String::new();
+    let mut operands = vec![];
+    let mut args = args.into_iter();
+
+    for arg in args.by_ref() {
+        if arg == "--" {
+            break;
+        }
+
+        match arg.strip_prefix('-') {
+            Some(letters) if !letters.is_empty() => options.push_str(letters),
+            _ => operands.push(arg),
+        }
+    }
+
+    operands.extend(args);
+
+    (operands, options)
+}
+
+/// Execute a command with the provided arguments.
+///
+/// This function takes a command string `command` and a vector of strings `args` representing the arguments
+/// for the command. It looks the command up in the registry of the shell, runs it and returns its status.
+/// A command that isn't one of the shell is run as an external program, looked up in the directories of
+/// `$PATH` unless its name holds a `/`.
+///
+/// # Arguments
+///
+/// * `command` - A string representing the name of the command to execute.
+/// * `args` - A vector of strings representing the arguments for the command.
+/// * `state` - The state of the shell, kept across command executions.
+/// * `streams` - The standard streams of the command.
+pub fn execute_command(
+    command_name: String,
+    args: Vec<String>,
+    state: &mut ShellState,
+    streams: &mut Streams,
+) -> io::Result<Status> {
+    if let Some(handler) = state.registry.get(&command_name) {
+        return handler(args, state, streams);
+    }
+
+    let program = if command_name.contains('/') {
+        Some(PathBuf::from(&command_name)).filter(|path| path.exists())
+    } else {
+        find_executables(
+            &command_name,
+            &state.environment.var_os("PATH").unwrap_or_default(),
+        )
+        .into_iter()
+        .next()
+    };
+
+    match program {
+        Some(program) => run_program(&program, &command_name, args, &state.environment, streams),
+        None => {
+            eprintln!("command not found : {}", command_name);
+            eprintln!("run `help` to list the available commands");
+
+            // 127 is the status used by other shells for commands that cannot be found.
+            Ok(Status {
+                code: 127,
+                exit: false,
+            })
+        }
+    }
+}
+
+/// The number of chunks of data held between an external program and the streams of its command.
+const PROGRAM_CHANNEL_CAPACITY: usize = 16;
+
+/// The delay between two attempts to feed an external program whose input is full.
+const PROGRAM_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Runs the external program at `path` and waits for it to end.
+///
+/// The program shares the standard error of the shell, and its standard input and output when they
+/// aren't redirected. Otherwise, they are connected to the streams of the command through pipes, which
+/// are copied as the program runs, so that a program can be fed an endless input or stop reading early.
+/// The variables of `environment` are added to the environment the program inherits.
+///
+/// Like in other shells, the status is the exit code of the program, or 128 plus the number of the signal
+/// that killed it, and 126 when the program cannot be run.
+fn run_program(
+    path: &Path,
+    command_name: &str,
+    args: Vec<String>,
+    environment: &Environment,
+    streams: &mut Streams,
+) -> io::Result<Status> {
+    let piped = |inherited: bool| {
+        if inherited {
+            Stdio::inherit()
+        } else {
+            Stdio::piped()
+        }
+    };
+
+    // The output of the previous commands is written first, since the program writes past it.
+    streams.stdout.flush()?;
+
+    let spawned = process::Command::new(path)
+        .arg0(command_name)
+        .args(args)
+        .envs(environment.overrides())
+        .stdin(piped(streams.shell_stdin))
+        .stdout(piped(streams.terminal))
+        .spawn();
+
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("{} : {}", command_name, e);
+
+            return Ok(Status {
+                code: 126,
+                exit: false,
+            });
+        }
+    };
+
+    // The input is written and the output read by threads of their own, which the data goes through by
+    // bounded channels, so that neither side of the program can block the other.
+    let (input, feeder) = match child.stdin.take() {
+        Some(mut program_input) => {
+            let (sender, receiver) = mpsc::sync_channel::<Vec<u8>>(PROGRAM_CHANNEL_CAPACITY);
+
+            let feeder = thread::spawn(move || {
+                for chunk in receiver {
+                    match program_input.write_all(&chunk) {
+                        // A program that exits without reading its whole input is not an error.
+                        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => break,
+                        result => result?,
+                    }
+                }
+
+                Ok(())
+            });
+
+            (Some(sender), Some(feeder))
+        }
+        None => (None, None),
+    };
+
+    let (output, reader) = match child.stdout.take() {
+        Some(mut program_output) => {
+            let (sender, receiver) = mpsc::sync_channel(PROGRAM_CHANNEL_CAPACITY);
+
+            let reader = thread::spawn(move || {
+                let mut buffer = vec![0; 8192];
+
+                loop {
+                    let read = program_output.read(&mut buffer)?;
+
+                    if read == 0 || sender.send(buffer[..read].to_vec()).is_err() {
+                        return Ok(());
+                    }
+                }
+            });
+
+            (Some(receiver), Some(reader))
+        }
+        None => (None, None),
+    };
+
+    let copied = copy_program_streams(streams, input, output);
+
+    // A program whose output can no longer be written is stopped, like it would be by a broken pipe.
+    if copied.is_err() {
+        let _ = child.kill();
+    }
+
+    let exit_status = child.wait()?;
+
+    for thread in [feeder, reader].into_iter().flatten() {
+        thread.join().unwrap_or_else(|_| {
+            Err(io::Error::other(
+                "the streams of the program could not be copied",
+            ))
+        })?;
+    }
+
+    copied?;
+
+    let code = match (exit_status.code(), exit_status.signal()) {
+        (Some(code), _) => code,
+        (None, Some(signal)) => 128 + signal,
+        (None, None) => 1,
+    };
+
+    Ok(Status { code, exit: false })
+}
+
+/// Copies the standard input of a command to the program it runs and the output of the program to the
+/// standard output of the command, through the channels of the threads writing and reading the streams of
+/// the program, until the program closes its output.
+///
+/// The input is sent without waiting while the program reads it, and the output is copied in between, so
+/// that a program not reading its input while it prints cannot block the shell.
+fn copy_program_streams(
+    streams: &mut Streams,
+    mut input: Option<SyncSender<Vec<u8>>>,
+    mut output: Option<Receiver<Vec<u8>>>,
+) -> io::Result<()> {
+    let mut pending: Option<Vec<u8>> = None;
+
+    while input.is_some() || output.is_some() {
+        if let Some(sender) = &input {
+            let chunk = match pending.take() {
+                Some(chunk) => chunk,
+                None => {
+                    let chunk = streams.stdin.fill_buf()?.to_vec();
+                    streams.stdin.consume(chunk.len());
+
+                    chunk
+                }
+            };
+
+            if chunk.is_empty() {
+                // Dropping the sender closes the input of the program.
+                input = None;
+                continue;
+            }
+
+            match sender.try_send(chunk) {
+                Ok(()) => {}
+                Err(TrySendError::Full(chunk)) => pending = Some(chunk),
+                Err(TrySendError::Disconnected(_)) => input = None,
+            }
+        }
+
+        let Some(receiver) = &output else {
+            if pending.is_some() {
+                thread::sleep(PROGRAM_POLL_INTERVAL);
+            }
+
+            continue;
+        };
+
+        // The output is waited for only when there is no input to send in the meantime.
+        let received = match (&input, &pending) {
+            (None, _) => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            (Some(_), Some(_)) => receiver.recv_timeout(PROGRAM_POLL_INTERVAL),
+            (Some(_), None) => receiver.try_recv().map_err(|e| match e {
+                TryRecvError::Empty => RecvTimeoutError::Timeout,
+                TryRecvError::Disconnected => RecvTimeoutError::Disconnected,
+            }),
+        };
+
+        match received {
+            Ok(chunk) => streams.stdout.write_all(&chunk)?,
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => output = None,
+        }
+    }
+
+    streams.stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    #[test]
+    fn split_options_separates_letters_from_operands() {
+        assert_eq!(
+            split_options(args(&["-r", "a", "-fi", "b"])),
+            (args(&["a", "b"]), String::from("rfi"))
+        );
+        assert_eq!(
+            split_options(args(&["-", "-r", "--", "-f"])),
+            (args(&["-", "-f"]), String::from("r"))
+        );
+    }
+
+    #[test]
+    fn registry_finds_registered_commands_only() {
+        let registry = CommandRegistry::default();
+
+        assert!(registry.get("echo").is_some());
+        assert_eq!(
+            registry.builtin("ls").unwrap().usage.split(' ').next(),
+            Some("ls")
+        );
+        assert!(registry.get("no-such-command").is_none());
+    }
+
+    #[test]
+    fn registered_command_replaces_the_previous_one() {
+        let mut registry = CommandRegistry::default();
+        registry.register("echo", "echo", "Fail.", |_, _, _| Ok(Status::FAILURE));
+
+        let handler = registry.get("echo").unwrap();
+        let (status, output) = run("", |streams| {
+            handler(args(&["a"]), &mut ShellState::default(), streams)
+        });
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+    }
+
+    #[test]
+    fn execute_command_dispatches_through_the_registry() {
+        let mut state = ShellState::default();
+
+        let (status, output) = run("", |streams| {
+            execute_command(String::from("echo"), args(&["hi"]), &mut state, streams)
+        });
+
+        assert_eq!((status, output.as_str()), (Status::SUCCESS, "hi\n"));
+    }
+
+    #[test]
+    fn unknown_command_has_status_127() {
+        let mut state = ShellState::default();
+
+        let (status, _) = run("", |streams| {
+            execute_command(String::from("no-such-command"), vec![], &mut state, streams)
+        });
+
+        assert_eq!(status.code, 127);
+    }
+
+    #[test]
+    fn help_is_printed_only_with_the_help_option() {
+        let help = |arg: &[&str]| {
+            let mut printed = false;
+            let (_, output) = run("", |streams| {
+                printed = print_help(&args(arg), "Usage: x\n", streams)?;
+
+                Ok(Status::SUCCESS)
+            });
+
+            (printed, output)
+        };
+
+        assert_eq!(help(&["a", "--help"]), (true, String::from("Usage: x\n")));
+        assert_eq!(help(&["-h"]), (false, String::new()));
+    }
+
+    #[test]
+    fn echo_ls_and_exit_print_their_usage_with_help() {
+        let mut state = ShellState::default();
+
+        for name in ["echo", "ls", "exit"] {
+            let (status, output) = run("", |streams| {
+                execute_command(name.to_string(), args(&["--help"]), &mut state, streams)
+            });
+
+            assert_eq!(status, Status::SUCCESS, "{}", name);
+            assert!(
+                output.starts_with(&format!("Usage: {}", name)),
+                "{}",
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn external_program_status_is_its_exit_code() {
+        let mut state = ShellState::default();
+        let mut status = |command_name: &str, arguments: &[&str]| {
+            run("", |streams| {
+                execute_command(
+                    command_name.to_string(),
+                    args(arguments),
+                    &mut state,
+                    streams,
+                )
+            })
+            .0
+        };
+
+        assert_eq!(status("/bin/true", &[]), Status::SUCCESS);
+        assert_eq!(status("/bin/false", &[]), Status::FAILURE);
+        // Programs without a `/` are looked up in the directories of `$PATH`.
+        assert_eq!(status("sh", &["-c", "exit 3"]).code, 3);
+    }
+
+    #[test]
+    fn program_killed_by_a_signal_has_status_128_plus_the_signal() {
+        let (status, _) = run("", |streams| {
+            let args = args(&["-c", "kill -TERM $$"]);
+
+            execute_command(
+                String::from("sh"),
+                args,
+                &mut ShellState::default(),
+                streams,
+            )
+        });
+
+        assert_eq!(status.code, 128 + 15);
+    }
+
+    #[test]
+    fn file_that_cannot_be_run_has_status_126() {
+        let dir = TempDir::new();
+        let file = dir.write("not-executable", "");
+
+        let (status, _) = run("", |streams| {
+            execute_command(file, vec![], &mut ShellState::default(), streams)
+        });
+
+        assert_eq!(status.code, 126);
+    }
+
+    #[test]
+    fn external_program_reads_and_writes_the_streams_of_its_command() {
+        let (status, output) = run("world\n", |streams| {
+            let args = args(&["-c", "read name; echo \"hello $name\"; echo \"$0\""]);
+
+            execute_command(
+                String::from("sh"),
+                args,
+                &mut ShellState::default(),
+                streams,
+            )
+        });
+
+        assert_eq!(
+            (status, output.as_str()),
+            (Status::SUCCESS, "hello world\nsh\n")
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    fs::{self, File, FileTimes},
+    io,
+    os::unix::fs::symlink,
+    path::Path,
+};
+
+use crate::prompt::confirm;
+
+use super::{split_options, Status, Streams};
+
+/// Execute the `mv` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `mv` command.
+///
+/// It renames a source to a destination, or moves several sources into a destination directory, keeping
+/// their base names. When the destination lies on another filesystem, the source is copied then removed.
+/// With `-i`, a confirmation is read from the standard input before overwriting a file, and with `-n`,
+/// existing files are never overwritten, `-n` taking precedence over `-i`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `mv` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let (paths, options) = split_options(args);
+
+    if let Some(wrong_option) = options.chars().find(|c| !"in".contains(*c)) {
+        eprintln!("mv : invalid option - '{}'", wrong_option);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let no_clobber = options.contains('n');
+    let interactive = options.contains('i') && !no_clobber;
+
+    let Some((target, sources)) = paths
+        .split_last()
+        .filter(|(_, sources)| !sources.is_empty())
+    else {
+        eprintln!("mv : missing file operand");
+
+        return Ok(Status::FAILURE);
+    };
+
+    let target = Path::new(target);
+    let into_dir = target.is_dir();
+
+    if sources.len() > 1 && !into_dir {
+        eprintln!("mv : target '{}' is not a directory", target.display());
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for source in sources {
+        let source = Path::new(source);
+
+        let destination = match source.file_name() {
+            Some(name) if into_dir => target.join(name),
+            _ => target.to_path_buf(),
+        };
+
+        if destination.symlink_metadata().is_ok() {
+            if no_clobber {
+                continue;
+            }
+
+            let question = format!("mv : overwrite '{}'?", destination.display());
+
+            if interactive && !confirm(&question, streams.stdin)? {
+                continue;
+            }
+        }
+
+        if let Err(e) = move_path(source, &destination) {
+            handle_error(e, source);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Moves `source` to `destination`, copying it then removing it when both lie on different filesystems.
+fn move_path(source: &Path, destination: &Path) -> io::Result<()> {
+    match fs::rename(source, destination) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            copy_all(source, destination)?;
+
+            if fs::symlink_metadata(source)?.is_dir() {
+                fs::remove_dir_all(source)
+            } else {
+                fs::remove_file(source)
+            }
+        }
+        result => result,
+    }
+}
+
+/// Copies `source` to `destination` along with its content, mode and timestamps, stopping at the first
+/// error so that the source is never removed after a partial copy.
+fn copy_all(source: &Path, destination: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(source)?;
+
+    if metadata.is_symlink() {
+        if destination.symlink_metadata().is_ok() {
+            fs::remove_file(destination)?;
+        }
+
+        return symlink(fs::read_link(source)?, destination);
+    }
+
+    if metadata.is_dir() {
+        if !destination.is_dir() {
+            fs::create_dir(destination)?;
+        }
+
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+
+            copy_all(&entry.path(), &destination.join(entry.file_name()))?;
+        }
+    } else {
+        fs::copy(source, destination)?;
+    }
+
+    fs::set_permissions(destination, metadata.permissions())?;
+
+    let times = FileTimes::new()
+        .set_accessed(metadata.accessed()?)
+        .set_modified(metadata.modified()?);
+
+    File::open(destination)?.set_times(times)
+}
+
+fn handle_error(error: io::Error, path: &Path) {
+    match error.kind() {
+        io::ErrorKind::NotFound => {
+            eprintln!("mv : no such file or directory: {}", path.display())
+        }
+        io::ErrorKind::PermissionDenied => {
+            eprintln!("mv : permission denied: {}", path.display())
+        }
+        _ => eprintln!("mv : cannot move '{}': {}", path.display(), error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn mv(arg: &[&str], input: &str) -> Status {
+        run(input, |streams| execute(args(arg), streams)).0
+    }
+
+    #[test]
+    fn renames_a_file() {
+        let dir = TempDir::new();
+        let source = dir.write("source", "content");
+
+        assert_eq!(mv(&[&source, &dir.join("renamed")], ""), Status::SUCCESS);
+        assert!(!Path::new(&source).exists());
+        assert_eq!(fs::read_to_string(dir.join("renamed")).unwrap(), "content");
+    }
+
+    #[test]
+    fn moves_files_into_a_directory() {
+        let dir = TempDir::new();
+        let a = dir.write("a", "1");
+        fs::create_dir(dir.join("sub")).unwrap();
+        fs::create_dir(dir.join("into")).unwrap();
+
+        assert_eq!(
+            mv(&[&a, &dir.join("sub"), &dir.join("into")], ""),
+            Status::SUCCESS
+        );
+        assert_eq!(fs::read_to_string(dir.join("into/a")).unwrap(), "1");
+        assert!(Path::new(&dir.join("into/sub")).is_dir());
+    }
+
+    #[test]
+    fn n_never_overwrites() {
+        let dir = TempDir::new();
+        let source = dir.write("source", "new");
+        let destination = dir.write("destination", "old");
+
+        assert_eq!(mv(&["-n", &source, &destination], ""), Status::SUCCESS);
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "old");
+        assert!(Path::new(&source).exists());
+    }
+
+    #[test]
+    fn i_overwrites_only_when_confirmed() {
+        let dir = TempDir::new();
+        let source = dir.write("source", "new");
+        let destination = dir.write("destination", "old");
+
+        mv(&["-i", &source, &destination], "no\n");
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "old");
+
+        mv(&["-i", &source, &destination], "y\n");
+        assert_eq!(fs::read_to_string(&destination).unwrap(), "new");
+        assert!(!Path::new(&source).exists());
+    }
+
+    #[test]
+    fn missing_source_fails() {
+        let dir = TempDir::new();
+
+        assert_eq!(
+            mv(&[&dir.join("missing"), &dir.join("other")], ""),
+            Status::FAILURE
+        );
+    }
+
+    #[test]
+    fn copy_all_keeps_content_mode_and_links() {
+        let dir = TempDir::new();
+        fs::create_dir(dir.join("tree")).unwrap();
+        let file = dir.write("tree/file", "content");
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o750)).unwrap();
+        symlink("file", dir.join("tree/link")).unwrap();
+
+        copy_all(Path::new(&dir.join("tree")), Path::new(&dir.join("copy"))).unwrap();
+
+        let copied = fs::metadata(dir.join("copy/file")).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("copy/file")).unwrap(),
+            "content"
+        );
+        assert_eq!(copied.permissions().mode() & 0o777, 0o750);
+        assert_eq!(
+            fs::read_link(dir.join("copy/link")).unwrap(),
+            Path::new("file")
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+};
+
+use super::{Status, Streams};
+
+/// How `nl` numbers the lines of its input.
+struct Options {
+    /// Whether empty lines are numbered too, with `-b a`, rather than only the others with `-b t`.
+    all: bool,
+    /// Whether no line is numbered, with `-b n`.
+    none: bool,
+    width: usize,
+    separator: String,
+}
+
+/// Execute the `nl` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `nl` command.
+///
+/// It prints the lines of the files given as arguments, numbering the ones that aren't empty. Numbers are
+/// right-aligned on 6 columns, or `N` columns with `-w N`, and separated from the line by a tab, or by
+/// `SEP` with `-s SEP`. With `-b a`, every line is numbered, and with `-b n`, none is. Unlike GNU nl, the
+/// input is a single body, without sections for headers and footers. The numbering carries on from a file
+/// to the next one. The argument `-` stands for the standard input, which is also read when no file is
+/// given.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `nl` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut options = Options {
+        all: false,
+        none: false,
+        width: 6,
+        separator: String::from("\t"),
+    };
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let option = match arg.as_str() {
+            _ if ["-b", "-w", "-s"]
+                .iter()
+                .any(|option| arg.starts_with(option)) =>
+            {
+                arg[1..2].to_string()
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("nl : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => {
+                paths.push(arg);
+                continue;
+            }
+        };
+
+        let value = match &arg[2..] {
+            "" => args.next(),
+            value => Some(value.to_string()),
+        };
+
+        let Some(value) = value else {
+            eprintln!("nl : option requires an argument - '{}'", option);
+
+            return Ok(Status::FAILURE);
+        };
+
+        match (option.as_str(), value.as_str()) {
+            ("b", "a") => (options.all, options.none) = (true, false),
+            ("b", "t") => (options.all, options.none) = (false, false),
+            ("b", "n") => (options.all, options.none) = (false, true),
+            ("b", _) => {
+                eprintln!("nl : invalid body numbering style: '{}'", value);
+
+                return Ok(Status::FAILURE);
+            }
+            ("w", _) => match value.parse() {
+                Ok(width) if width > 0 => options.width = width,
+                _ => {
+                    eprintln!("nl : invalid line number field width: '{}'", value);
+
+                    return Ok(Status::FAILURE);
+                }
+            },
+            _ => options.separator = value,
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let mut number = 1;
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let reader: io::Result<Box<dyn BufRead>> = if path == "-" {
+            Ok(Box::new(&mut *streams.stdin))
+        } else {
+            File::open(&path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+        };
+
+        let result =
+            reader.and_then(|mut reader| nl(&mut reader, streams.stdout, &options, &mut number));
+
+        if let Err(e) = result {
+            if e.kind() == io::ErrorKind::BrokenPipe {
+                return Err(e);
+            }
+
+            handle_error(e, &path);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Copies the lines of `reader` to `writer`, preceded by their numbers starting at `number`, which is
+/// left at the number of the next line.
+fn nl(
+    reader: &mut dyn BufRead,
+    writer: &mut dyn Write,
+    options: &Options,
+    number: &mut u64,
+) -> io::Result<()> {
+    let mut line = vec![];
+
+    loop {
+        line.clear();
+
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(());
+        }
+
+        let empty = line == b"\n";
+
+        if options.none || (empty && !options.all) {
+            // Unnumbered lines are indented like the numbered ones.
+            let indent = options.width + options.separator.chars().count();
+
+            write!(writer, "{:indent$}", "", indent = indent)?;
+        } else {
+            write!(
+                writer,
+                "{:>width$}{}",
+                number,
+                options.separator,
+                width = options.width
+            )?;
+            *number += 1;
+        }
+
+        writer.write_all(&line)?;
+
+        // Like with GNU nl, a last line without a newline gets one.
+        if !line.ends_with(b"\n") {
+            writer.write_all(b"\n")?;
+        }
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("nl : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("nl : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("nl : is a directory: {}", path),
+        _ => eprintln!("nl : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    const INPUT: &str = "one\n\ntwo\n";
+
+    fn nl(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn numbers_non_empty_lines_by_default() {
+        assert_eq!(
+            nl(&[], INPUT),
+            (
+                Status::SUCCESS,
+                String::from("     1\tone\n       \n     2\ttwo\n")
+            )
+        );
+    }
+
+    #[test]
+    fn b_a_numbers_every_line() {
+        assert_eq!(
+            nl(&["-b", "a"], INPUT).1,
+            "     1\tone\n     2\t\n     3\ttwo\n"
+        );
+        assert_eq!(nl(&["-ba", "-bt"], INPUT).1, nl(&[], INPUT).1);
+    }
+
+    #[test]
+    fn b_n_numbers_no_line() {
+        assert_eq!(nl(&["-b", "n", "-w2", "-s", ": "], "a\n").1, "    a\n");
+    }
+
+    #[test]
+    fn w_and_s_set_the_width_and_separator() {
+        assert_eq!(
+            nl(&["-w", "3", "-s", ". "], INPUT).1,
+            "  1. one\n     \n  2. two\n"
+        );
+        assert_eq!(
+            nl(&["-w1"], &"x\n".repeat(10)).1.lines().last(),
+            Some("10\tx")
+        );
+    }
+
+    #[test]
+    fn numbering_carries_on_across_files() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "a\nb");
+
+        assert_eq!(nl(&["-w1", &file, "-"], "c\n").1, "1\ta\n2\tb\n3\tc\n");
+    }
+
+    #[test]
+    fn invalid_usage_fails() {
+        for arguments in [&["-b", "x"][..], &["-w", "0"], &["-w"], &["-x"]] {
+            assert_eq!(nl(arguments, INPUT), (Status::FAILURE, String::new()));
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use std::io;
+
+use super::{echo::interpret_escapes, Status, Streams};
+
+/// A conversion specification of the format, like `%-8s` or `%05.2d`.
+struct Conversion {
+    /// Whether the value is aligned to the left of the field, with `-`.
+    left: bool,
+    /// Whether numbers are padded with zeros rather than spaces, with `0`.
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    specifier: char,
+}
+
+/// The state of the formatting: the arguments not consumed yet and the output built so far.
+struct Formatter {
+    args: Vec<String>,
+    next: usize,
+    output: Vec<u8>,
+    /// Whether every numeric argument could be parsed.
+    valid: bool,
+}
+
+/// Execute the `printf` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `printf` command.
+///
+/// It prints its first argument, the format, in which the backslash escapes of `echo -e` are interpreted
+/// and conversion specifications are replaced by the other arguments, in order: `%s` for a string, `%b`
+/// for a string whose escapes are interpreted, `%c` for its first character, `%d` or `%i` for an integer,
+/// `%u`, `%o`, `%x` and `%X` for an unsigned integer in decimal, octal and hexadecimal, and `%%` for a
+/// `%`. Specifications may hold a width, a precision and the `-` and `0` flags, as in `%-10s` or `%05d`.
+/// Missing arguments count as empty strings or zeros, and the format is reused as long as arguments
+/// remain. Unlike `echo`, no newline is added.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `printf` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut args = args.into_iter();
+
+    let Some(format) = args.next() else {
+        eprintln!("printf : missing operand");
+
+        return Ok(Status::FAILURE);
+    };
+
+    let mut formatter = Formatter {
+        args: args.collect(),
+        next: 0,
+        output: vec![],
+        valid: true,
+    };
+
+    loop {
+        let consumed = formatter.next;
+
+        if formatter.format(&format) {
+            break;
+        }
+
+        // The format is reused for the remaining arguments, unless it consumes none of them.
+        if formatter.next >= formatter.args.len() || formatter.next == consumed {
+            break;
+        }
+    }
+
+    streams.stdout.write_all(&formatter.output)?;
+
+    Ok(if formatter.valid {
+        Status::SUCCESS
+    } else {
+        Status::FAILURE
+    })
+}
+
+impl Formatter {
+    /// Formats the arguments following `format` once. Returns whether a `\c` escape stopped the output.
+    fn format(&mut self, format: &str) -> bool {
+        let mut literal = String::new();
+        let mut chars = format.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+
+            if chars.peek() == Some(&'%') {
+                chars.next();
+                literal.push('%');
+                continue;
+            }
+
+            // The escapes of the text preceding the conversion are interpreted on their own, so that an
+            // escape such as `\045` cannot produce a conversion.
+            if self.push_escaped(&literal) {
+                return true;
+            }
+
+            literal.clear();
+
+            let mut conversion = Conversion {
+                left: false,
+                zero: false,
+                width: 0,
+                precision: None,
+                specifier: '\0',
+            };
+
+            while let Some(flag) = chars.next_if(|c| *c == '-' || *c == '0') {
+                match flag {
+                    '-' => conversion.left = true,
+                    _ => conversion.zero = true,
+                }
+            }
+
+            conversion.width = take_number(&mut chars);
+
+            if chars.next_if_eq(&'.').is_some() {
+                conversion.precision = Some(take_number(&mut chars));
+            }
+
+            let Some(specifier) = chars.next() else {
+                eprintln!("printf : missing conversion specifier at the end of the format");
+                self.valid = false;
+
+                return true;
+            };
+
+            conversion.specifier = specifier;
+
+            if self.convert(&conversion) {
+                return true;
+            }
+        }
+
+        self.push_escaped(&literal)
+    }
+
+    /// Replaces a conversion specification with the next argument. Returns whether a `\c` escape in a
+    /// `%b` argument stopped the output.
+    fn convert(&mut self, conversion: &Conversion) -> bool {
+        let arg = self.args.get(self.next).cloned();
+        self.next += 1;
+
+        let (text, numeric) = match conversion.specifier {
+            's' => {
+                let text = arg.unwrap_or_default();
+
+                match conversion.precision {
+                    Some(precision) => (text.chars().take(precision).collect(), false),
+                    None => (text, false),
+                }
+            }
+            'b' => {
+                let (bytes, stopped) = interpret_escapes(&arg.unwrap_or_default());
+
+                self.pad(&bytes, conversion, false);
+
+                return stopped;
+            }
+            'c' => (
+                arg.and_then(|arg| arg.chars().next())
+                    .map(String::from)
+                    .unwrap_or_default(),
+                false,
+            ),
+            'd' | 'i' | 'u' | 'o' | 'x' | 'X' => {
+                let value = match arg.as_deref().map(parse_number) {
+                    Some(Some(value)) => value,
+                    Some(None) => {
+                        eprintln!("printf : invalid number '{}'", arg.unwrap_or_default());
+                        self.valid = false;
+                        0
+                    }
+                    None => 0,
+                };
+
+                (format_integer(value, conversion), true)
+            }
+            other => {
+                eprintln!("printf : invalid conversion specifier - '{}'", other);
+                self.valid = false;
+
+                return true;
+            }
+        };
+
+        self.pad(text.as_bytes(), conversion, numeric);
+
+        false
+    }
+
+    /// Pushes `text` padded to the width of the conversion, with zeros placed after the sign for numbers
+    /// with the `0` flag, and with spaces otherwise.
+    fn pad(&mut self, text: &[u8], conversion: &Conversion, numeric: bool) {
+        let padding = conversion
+            .width
+            .saturating_sub(String::from_utf8_lossy(text).chars().count());
+
+        if conversion.left {
+            self.output.extend_from_slice(text);
+            self.output.extend(std::iter::repeat_n(b' ', padding));
+        } else if numeric && conversion.zero && conversion.precision.is_none() {
+            let digits = match text.strip_prefix(b"-") {
+                Some(digits) => {
+                    self.output.push(b'-');
+                    digits
+                }
+                None => text,
+            };
+
+            self.output.extend(std::iter::repeat_n(b'0', padding));
+            self.output.extend_from_slice(digits);
+        } else {
+            self.output.extend(std::iter::repeat_n(b' ', padding));
+            self.output.extend_from_slice(text);
+        }
+    }
+
+    /// Pushes `text` with its escapes interpreted. Returns whether a `\c` escape stopped the output.
+    fn push_escaped(&mut self, text: &str) -> bool {
+        let (bytes, stopped) = interpret_escapes(text);
+
+        self.output.extend_from_slice(&bytes);
+
+        stopped
+    }
+}
+
+/// Consumes the decimal digits at the start of `chars`, returning their value, 0 if there are none.
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars>) -> usize {
+    let mut value = 0usize;
+
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        value = value.saturating_mul(10).saturating_add(digit as usize);
+        chars.next();
+    }
+
+    value
+}
+
+/// Parses a numeric argument: a decimal number, a hexadecimal one with `0x`, an octal one with a leading
+/// `0`, or a character preceded by a quote, like `'A`, which stands for its code point.
+fn parse_number(arg: &str) -> Option<i64> {
+    if let Some(quoted) = arg.strip_prefix(['\'', '"']) {
+        return quoted.chars().next().map(|c| c as i64);
+    }
+
+    let (negative, digits) = match arg.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, arg.strip_prefix('+').unwrap_or(arg)),
+    };
+
+    let value = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hex, 16).ok()?
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8).ok()?
+    } else if digits.starts_with(|c: char| c.is_ascii_digit()) {
+        digits.parse().ok()?
+    } else {
+        return None;
+    };
+
+    Some(if negative { -value } else { value })
+}
+
+/// Formats an integer following its conversion, with at least as many digits as the precision. Negative
+/// values are printed as their two's complement by the unsigned conversions, like in C.
+fn format_integer(value: i64, conversion: &Conversion) -> String {
+    let (sign, digits) = match conversion.specifier {
+        'd' | 'i' if value < 0 => ("-", value.unsigned_abs().to_string()),
+        'd' | 'i' => ("", value.to_string()),
+        'o' => ("", format!("{:o}", value as u64)),
+        'x' => ("", format!("{:x}", value as u64)),
+        'X' => ("", format!("{:X}", value as u64)),
+        _ => ("", (value as u64).to_string()),
+    };
+
+    let precision = conversion.precision.unwrap_or(0);
+
+    format!("{}{:0>precision$}", sign, digits, precision = precision)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn printf(arguments: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn formats_integers() {
+        assert_eq!(printf(&["%d %i|", "42", "-7"]).1, "42 -7|");
+        assert_eq!(printf(&["%d %d %d", "0x1f", "010", "'A"]).1, "31 8 65");
+        assert_eq!(
+            printf(&["%x %X %o %u", "255", "255", "8", "3"]).1,
+            "ff FF 10 3"
+        );
+        assert_eq!(printf(&["%x", "-1"]).1, "ffffffffffffffff");
+    }
+
+    #[test]
+    fn formats_strings_and_characters() {
+        assert_eq!(printf(&["%s, %s!", "hello", "world"]).1, "hello, world!");
+        assert_eq!(printf(&["%c%c", "abc", "xyz"]).1, "ax");
+        assert_eq!(printf(&["[%.3s]", "abcdef"]).1, "[abc]");
+    }
+
+    #[test]
+    fn pads_to_the_width() {
+        assert_eq!(printf(&["[%5s][%-5s]", "ab", "cd"]).1, "[   ab][cd   ]");
+        assert_eq!(
+            printf(&["[%5d][%-5d][%05d]", "42", "42", "-42"]).1,
+            "[   42][42   ][-0042]"
+        );
+        assert_eq!(printf(&["[%.3d][%6.3d]", "7", "-7"]).1, "[007][  -007]");
+        assert_eq!(printf(&["[%3s]", "héllo"]).1, "[héllo]");
+    }
+
+    #[test]
+    fn interprets_escapes() {
+        assert_eq!(printf(&["a\\tb\\n"]).1, "a\tb\n");
+        assert_eq!(printf(&["\\0101\\0102"]).1, "AB");
+        assert_eq!(printf(&["100%%\\n"]).1, "100%\n");
+        assert_eq!(printf(&["[%s|%b]", "a\\tb", "a\\tb"]).1, "[a\\tb|a\tb]");
+    }
+
+    #[test]
+    fn c_escape_stops_the_output() {
+        assert_eq!(printf(&["a\\cb %s", "x"]).1, "a");
+        assert_eq!(printf(&["%b-%s\\n", "a\\cb", "x"]).1, "a");
+    }
+
+    #[test]
+    fn escape_cannot_produce_a_conversion() {
+        assert_eq!(printf(&["\\045s", "x"]).1, "%s");
+    }
+
+    #[test]
+    fn format_is_reused_for_extra_arguments() {
+        assert_eq!(
+            printf(&["%s=%d\\n", "a", "1", "b", "2", "c"]).1,
+            "a=1\nb=2\nc=0\n"
+        );
+        assert_eq!(printf(&["no conversion\\n", "extra"]).1, "no conversion\n");
+    }
+
+    #[test]
+    fn missing_arguments_are_empty_or_zero() {
+        assert_eq!(
+            printf(&["[%s][%d][%c]"]),
+            (Status::SUCCESS, String::from("[][0][]"))
+        );
+    }
+
+    #[test]
+    fn invalid_number_prints_zero_and_fails() {
+        assert_eq!(
+            printf(&["%d|%d", "abc", "5"]),
+            (Status::FAILURE, String::from("0|5"))
+        );
+    }
+
+    #[test]
+    fn invalid_format_fails() {
+        assert_eq!(printf(&["a%y"]), (Status::FAILURE, String::from("a")));
+        assert_eq!(printf(&["a%5"]), (Status::FAILURE, String::from("a")));
+        assert_eq!(printf(&[]), (Status::FAILURE, String::new()));
+    }
+}
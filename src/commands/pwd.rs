@@ -0,0 +1,70 @@
+use std::{
+    env, fs, io,
+    os::unix::fs::MetadataExt,
+    path::{Path, PathBuf},
+};
+
+use super::{Status, Streams};
+
+/// Execute the `pwd` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `pwd` command.
+///
+/// It prints the absolute path of the current working directory. With `-L` (the default), the logical
+/// path from `$PWD` is printed when it still refers to the current directory; with `-P`, every symlink
+/// is resolved.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `pwd` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut physical = false;
+
+    for arg in args.iter() {
+        match arg.as_str() {
+            "-L" => physical = false,
+            "-P" => physical = true,
+            _ => {
+                eprintln!("pwd : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+        }
+    }
+
+    let current_dir = if physical {
+        env::current_dir().and_then(fs::canonicalize)
+    } else {
+        logical_current_dir()
+    };
+
+    match current_dir {
+        Ok(path) => writeln!(streams.stdout, "{}", path.display())?,
+        Err(e) => {
+            eprintln!("pwd : cannot get current directory: {}", e);
+
+            return Ok(Status::FAILURE);
+        }
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Returns `$PWD` when it is an absolute path naming the current directory, and the current directory
+/// as reported by the operating system otherwise.
+pub fn logical_current_dir() -> io::Result<PathBuf> {
+    let current_dir = env::current_dir()?;
+
+    match env::var_os("PWD").map(PathBuf::from) {
+        Some(pwd) if pwd.is_absolute() && same_file(&pwd, &current_dir) => Ok(pwd),
+        _ => Ok(current_dir),
+    }
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (fs::metadata(a), fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+        _ => false,
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{Status, Streams};
+
+/// How `readlink` resolves the paths given as arguments.
+enum Mode {
+    /// Print the target of a symlink as it is stored.
+    Target,
+    /// Resolve every symlink, every component but the last one having to exist, with `-f`.
+    Canonicalize,
+    /// Resolve every symlink, every component having to exist, with `-e`.
+    CanonicalizeExisting,
+}
+
+/// Execute the `readlink` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `readlink` command.
+///
+/// It prints the target of each symlink given as argument, as it is stored in the link. With `-f`, the
+/// absolute path of each argument is printed instead, with every symlink resolved, the last component
+/// not having to exist; `-e` does the same but requires every component to exist.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `readlink` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut mode = Mode::Target;
+    let mut paths = vec![];
+
+    for arg in args {
+        match arg.as_str() {
+            "-f" => mode = Mode::Canonicalize,
+            "-e" => mode = Mode::CanonicalizeExisting,
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("readlink : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("readlink : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let resolved = match mode {
+            Mode::Target => read_link(Path::new(&path)),
+            Mode::Canonicalize => canonicalize_missing(Path::new(&path)),
+            Mode::CanonicalizeExisting => fs::canonicalize(&path),
+        };
+
+        match resolved {
+            Ok(resolved) => writeln!(streams.stdout, "{}", resolved.display())?,
+            Err(e) => {
+                handle_error(e, &path);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Returns the target of the symlink at `path`, reporting other files as an invalid input.
+fn read_link(path: &Path) -> io::Result<PathBuf> {
+    if !fs::symlink_metadata(path)?.is_symlink() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "not a symbolic link",
+        ));
+    }
+
+    fs::read_link(path)
+}
+
+/// Returns the canonical form of `path`, which may not exist as long as its parent directory does. A
+/// dangling symlink is resolved to the canonical form of its target.
+fn canonicalize_missing(path: &Path) -> io::Result<PathBuf> {
+    match fs::canonicalize(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let Some(name) = path.file_name() else {
+                return Err(e);
+            };
+
+            let parent = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+
+            if let Ok(target) = fs::read_link(path) {
+                return canonicalize_missing(&parent.join(target));
+            }
+
+            Ok(fs::canonicalize(parent)?.join(name))
+        }
+        result => result,
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("readlink : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("readlink : permission denied: {}", path),
+        _ => eprintln!("readlink : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::symlink;
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    /// Creates `dir/file` and the symlinks `dir/link` to `file` and `dir/dangling` to `missing`.
+    fn links(dir: &TempDir) {
+        dir.write("file", "content");
+        symlink("file", dir.join("link")).unwrap();
+        symlink("missing", dir.join("dangling")).unwrap();
+    }
+
+    #[test]
+    fn prints_the_target_as_stored() {
+        let dir = TempDir::new();
+        links(&dir);
+
+        let (status, output) = run("", |streams| {
+            execute(args(&[&dir.join("link"), &dir.join("dangling")]), streams)
+        });
+
+        assert_eq!(
+            (status, output.as_str()),
+            (Status::SUCCESS, "file\nmissing\n")
+        );
+    }
+
+    #[test]
+    fn file_that_is_not_a_symlink_fails() {
+        let dir = TempDir::new();
+        links(&dir);
+
+        let (status, output) = run("", |streams| execute(args(&[&dir.join("file")]), streams));
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+    }
+
+    #[test]
+    fn f_prints_the_canonical_path() {
+        let dir = TempDir::new();
+        links(&dir);
+        let root = fs::canonicalize(dir.path()).unwrap();
+        let through_dot = format!("{}/./link", dir.path().display());
+
+        let (status, output) = run("", |streams| {
+            execute(args(&["-f", &through_dot, &dir.join("dangling")]), streams)
+        });
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(
+            output,
+            format!(
+                "{}\n{}\n",
+                root.join("file").display(),
+                root.join("missing").display()
+            )
+        );
+    }
+
+    #[test]
+    fn e_requires_every_component_to_exist() {
+        let dir = TempDir::new();
+        links(&dir);
+        let root = fs::canonicalize(dir.path()).unwrap();
+
+        let (status, output) = run("", |streams| {
+            execute(
+                args(&["-e", &dir.join("link"), &dir.join("dangling")]),
+                streams,
+            )
+        });
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(output, format!("{}\n", root.join("file").display()));
+    }
+
+    #[test]
+    fn missing_operand_or_invalid_option_fails() {
+        let (status, _) = run("", |streams| execute(args(&["-f"]), streams));
+        assert_eq!(status, Status::FAILURE);
+
+        let (status, _) = run("", |streams| execute(args(&["-x", "a"]), streams));
+        assert_eq!(status, Status::FAILURE);
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    env, fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use super::{Status, Streams};
+
+/// Execute the `realpath` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `realpath` command.
+///
+/// It prints the absolute path of each file given as argument, with every symlink resolved and without
+/// `.` or `..` components. With `-m`, the path doesn't need to exist: it is resolved as far as it exists,
+/// and the rest of it is appended as is. With `-s`, symlinks are not resolved, `..` simply removing the
+/// previous component.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `realpath` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut missing = false;
+    let mut no_symlinks = false;
+    let mut paths = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            paths.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'm' => missing = true,
+                's' => no_symlinks = true,
+                _ => {
+                    eprintln!("realpath : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("realpath : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let resolved = match (missing, no_symlinks) {
+            (false, false) => fs::canonicalize(&path),
+            (true, false) => resolve_existing(Path::new(&path)),
+            (true, true) => normalize(Path::new(&path)),
+            (false, true) => normalize(Path::new(&path))
+                .and_then(|normalized| fs::symlink_metadata(&normalized).map(|_| normalized)),
+        };
+
+        match resolved {
+            Ok(resolved) => writeln!(streams.stdout, "{}", resolved.display())?,
+            Err(e) => {
+                handle_error(e, &path);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Returns the absolute form of `path`, resolving its symlinks as long as its components exist, the
+/// remaining components being appended without being resolved.
+fn resolve_existing(path: &Path) -> io::Result<PathBuf> {
+    let mut resolved = env::current_dir()?;
+    let mut exists = true;
+
+    for component in path.components() {
+        match component {
+            Component::RootDir => resolved = PathBuf::from("/"),
+            Component::CurDir | Component::Prefix(_) => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            Component::Normal(name) => {
+                let joined = resolved.join(name);
+
+                resolved = match fs::canonicalize(&joined) {
+                    Ok(canonical) if exists => canonical,
+                    _ => {
+                        exists = false;
+                        joined
+                    }
+                };
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Returns the absolute form of `path` without `.` and `..` components, without resolving symlinks.
+fn normalize(path: &Path) -> io::Result<PathBuf> {
+    let mut normalized = env::current_dir()?;
+
+    for component in path.components() {
+        match component {
+            Component::RootDir => normalized = PathBuf::from("/"),
+            Component::CurDir | Component::Prefix(_) => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::Normal(name) => normalized.push(name),
+        }
+    }
+
+    Ok(normalized)
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("realpath : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("realpath : permission denied: {}", path),
+        _ => eprintln!("realpath : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::symlink;
+
+    use super::*;
+    use crate::testing::{args, lock_process, run, CurrentDirGuard, TempDir};
+
+    /// Runs `realpath` from a tree holding `dir/file` and `link` to `dir`, returning its status, its output
+    /// and the canonical path of the tree.
+    fn realpath(arg: &[&str]) -> (Status, String, PathBuf) {
+        let _lock = lock_process();
+        let _guard = CurrentDirGuard::new();
+        let tree = TempDir::new();
+        let root = fs::canonicalize(tree.path()).unwrap();
+        fs::create_dir(root.join("dir")).unwrap();
+        fs::write(root.join("dir/file"), "").unwrap();
+        symlink("dir", root.join("link")).unwrap();
+        env::set_current_dir(&root).unwrap();
+
+        let (status, output) = run("", |streams| execute(args(arg), streams));
+
+        (status, output, root)
+    }
+
+    #[test]
+    fn relative_path_is_made_absolute() {
+        let (status, output, root) = realpath(&["dir/./file", "dir/../dir"]);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(
+            output,
+            format!("{}/dir/file\n{}/dir\n", root.display(), root.display())
+        );
+    }
+
+    #[test]
+    fn symlinks_are_resolved() {
+        let (_, output, root) = realpath(&["link/file"]);
+
+        assert_eq!(output, format!("{}/dir/file\n", root.display()));
+    }
+
+    #[test]
+    fn s_keeps_symlinks() {
+        let (_, output, root) = realpath(&["-s", "link/file"]);
+
+        assert_eq!(output, format!("{}/link/file\n", root.display()));
+    }
+
+    #[test]
+    fn m_resolves_missing_paths_as_far_as_possible() {
+        let (status, output, root) = realpath(&["-m", "link/missing/../other"]);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, format!("{}/dir/other\n", root.display()));
+    }
+
+    #[test]
+    fn missing_path_fails_without_stopping_the_others() {
+        let (status, output, root) = realpath(&["missing", "dir"]);
+
+        assert_eq!(status, Status::FAILURE);
+        assert_eq!(output, format!("{}/dir\n", root.display()));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+};
+
+use super::{Status, Streams};
+
+/// Execute the `rev` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `rev` command.
+///
+/// It prints each line of the files given as arguments with its characters in reverse order, keeping the
+/// newline at its end. Characters are reversed whole, so that multibyte characters remain valid. The
+/// argument `-` stands for the standard input, which is also read when no file is given.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `rev` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut paths = vec![];
+
+    for arg in args {
+        if arg.starts_with('-') && arg.len() > 1 {
+            eprintln!("rev : invalid option - '{}'", arg);
+
+            return Ok(Status::FAILURE);
+        }
+
+        paths.push(arg);
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let reader: io::Result<Box<dyn BufRead>> = if path == "-" {
+            Ok(Box::new(&mut *streams.stdin))
+        } else {
+            File::open(&path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+        };
+
+        let result = reader.and_then(|mut reader| rev(&mut reader, streams.stdout));
+
+        if let Err(e) = result {
+            if e.kind() == io::ErrorKind::BrokenPipe {
+                return Err(e);
+            }
+
+            handle_error(e, &path);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Copies the lines of `reader` to `writer` with their characters reversed. A last line without a newline
+/// is printed without one.
+fn rev(reader: &mut dyn BufRead, writer: &mut dyn Write) -> io::Result<()> {
+    let mut line = vec![];
+
+    loop {
+        line.clear();
+
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(());
+        }
+
+        let newline = line.ends_with(b"\n");
+        let content = line.strip_suffix(b"\n").unwrap_or(&line);
+        let reversed: String = String::from_utf8_lossy(content).chars().rev().collect();
+
+        writer.write_all(reversed.as_bytes())?;
+
+        if newline {
+            writer.write_all(b"\n")?;
+        }
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("rev : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("rev : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("rev : is a directory: {}", path),
+        _ => eprintln!("rev : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn rev(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn reverses_ascii_lines() {
+        assert_eq!(
+            rev(&[], "hello world\nabc\n"),
+            (Status::SUCCESS, String::from("dlrow olleh\ncba\n"))
+        );
+    }
+
+    #[test]
+    fn reverses_multibyte_characters_whole() {
+        assert_eq!(rev(&[], "héllo wörld ✓\n").1, "✓ dlröw olléh\n");
+    }
+
+    #[test]
+    fn last_line_without_newline_is_printed_without_one() {
+        assert_eq!(rev(&[], "ab\n\ncd").1, "ba\n\ndc");
+    }
+
+    #[test]
+    fn reads_files_in_order() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "12\n");
+
+        assert_eq!(rev(&[&file, "-"], "34\n").1, "21\n43\n");
+    }
+
+    #[test]
+    fn missing_file_does_not_stop_the_others() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "12\n");
+
+        assert_eq!(
+            rev(&[&dir.join("missing"), &file], ""),
+            (Status::FAILURE, String::from("21\n"))
+        );
+        assert_eq!(rev(&["-x"], "ab\n"), (Status::FAILURE, String::new()));
+    }
+}
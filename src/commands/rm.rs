@@ -0,0 +1,166 @@
+use std::{fs, io};
+
+use crate::prompt::confirm;
+
+use super::{split_options, Status, Streams};
+
+/// Execute the `rm` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `rm` command.
+///
+/// It removes each file given as argument. Directories are only removed, along with their content, with
+/// `-r` or `-R`. With `-f`, missing files are ignored silently; with `-i`, a confirmation is read from the
+/// standard input before each removal. When both are given, `-f` takes precedence.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `rm` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let (paths, options) = split_options(args);
+
+    if let Some(wrong_option) = options.chars().find(|c| !"rRfi".contains(*c)) {
+        eprintln!("rm : invalid option - '{}'", wrong_option);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let recursive = options.contains('r') || options.contains('R');
+    let force = options.contains('f');
+    let interactive = options.contains('i') && !force;
+
+    if paths.is_empty() && !force {
+        eprintln!("rm : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        if is_protected(&path) {
+            eprintln!("rm : refusing to remove '{}'", path);
+            status = Status::FAILURE;
+            continue;
+        }
+
+        if interactive && !confirm(&format!("rm : remove '{}'?", path), streams.stdin)? {
+            continue;
+        }
+
+        match remove(&path, recursive) {
+            Err(e) if force && e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => {
+                handle_error(e, &path);
+                status = Status::FAILURE;
+            }
+            Ok(()) => {}
+        }
+    }
+
+    Ok(status)
+}
+
+/// Removes the file at `path`, or the directory and its content if `recursive` is set. Symlinks are
+/// removed themselves, never followed.
+fn remove(path: &str, recursive: bool) -> io::Result<()> {
+    if fs::symlink_metadata(path)?.is_dir() {
+        if !recursive {
+            return Err(io::Error::from(io::ErrorKind::IsADirectory));
+        }
+
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Returns whether `path` is `.`, `..` or the root directory, which are never removed.
+fn is_protected(path: &str) -> bool {
+    let path = path.trim_end_matches('/');
+
+    path.is_empty() || matches!(path.rsplit('/').next(), Some("." | ".."))
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("rm : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("rm : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("rm : cannot remove '{}': is a directory", path),
+        _ => eprintln!("rm : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn rm(arg: &[&str], input: &str) -> Status {
+        run(input, |streams| execute(args(arg), streams)).0
+    }
+
+    #[test]
+    fn removes_files() {
+        let dir = TempDir::new();
+        let a = dir.write("a", "");
+        let b = dir.write("b", "");
+
+        assert_eq!(rm(&[&a, &b], ""), Status::SUCCESS);
+        assert!(!Path::new(&a).exists() && !Path::new(&b).exists());
+    }
+
+    #[test]
+    fn directories_need_r() {
+        let dir = TempDir::new();
+        let sub = dir.join("sub");
+        fs::create_dir(&sub).unwrap();
+        fs::write(dir.path().join("sub/file"), "").unwrap();
+
+        assert_eq!(rm(&[&sub], ""), Status::FAILURE);
+        assert!(Path::new(&sub).exists());
+
+        assert_eq!(rm(&["-r", &sub], ""), Status::SUCCESS);
+        assert!(!Path::new(&sub).exists());
+    }
+
+    #[test]
+    fn f_ignores_missing_files() {
+        let dir = TempDir::new();
+
+        assert_eq!(rm(&[&dir.join("missing")], ""), Status::FAILURE);
+        assert_eq!(rm(&["-f", &dir.join("missing")], ""), Status::SUCCESS);
+        assert_eq!(rm(&["-f"], ""), Status::SUCCESS);
+    }
+
+    #[test]
+    fn i_removes_only_confirmed_files() {
+        let dir = TempDir::new();
+        let kept = dir.write("kept", "");
+        let removed = dir.write("removed", "");
+
+        assert_eq!(rm(&["-i", &kept, &removed], "n\nyes\n"), Status::SUCCESS);
+        assert!(Path::new(&kept).exists());
+        assert!(!Path::new(&removed).exists());
+    }
+
+    #[test]
+    fn i_keeps_files_at_the_end_of_the_input() {
+        let dir = TempDir::new();
+        let kept = dir.write("kept", "");
+
+        assert_eq!(rm(&["-i", &kept], ""), Status::SUCCESS);
+        assert!(Path::new(&kept).exists());
+    }
+
+    #[test]
+    fn refuses_to_remove_dot_and_dot_dot() {
+        let dir = TempDir::new();
+
+        assert_eq!(rm(&["-rf", &dir.join(".")], ""), Status::FAILURE);
+        assert_eq!(rm(&["-rf", &dir.join("..")], ""), Status::FAILURE);
+        assert!(dir.path().exists());
+    }
+}
@@ -0,0 +1,207 @@
+use std::io;
+
+use super::{Status, Streams};
+
+/// Execute the `seq` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `seq` command.
+///
+/// It prints the numbers from `FIRST` to `LAST`, counting by `INCREMENT`, from the forms `seq LAST`,
+/// `seq FIRST LAST` and `seq FIRST INCREMENT LAST`, where `FIRST` and `INCREMENT` default to 1. A negative
+/// increment counts down. The numbers may be decimal, in which case they are printed with as many decimals
+/// as `FIRST` and `INCREMENT`. The numbers are separated by newlines, or by `SEP` with `-s SEP`, and `-w`
+/// pads them with leading zeros to the same width.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `seq` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut separator = String::from("\n");
+    let mut equal_width = false;
+    let mut operands = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-w" => equal_width = true,
+            "-s" => match args.next() {
+                Some(value) => separator = value,
+                None => {
+                    eprintln!("seq : option requires an argument - 's'");
+
+                    return Ok(Status::FAILURE);
+                }
+            },
+            // A negative number is an operand rather than an option.
+            _ if arg.starts_with('-') && arg.parse::<f64>().is_err() => {
+                eprintln!("seq : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => operands.push(arg),
+        }
+    }
+
+    let (first, increment, last) = match operands.as_slice() {
+        [last] => ("1", "1", last.as_str()),
+        [first, last] => (first.as_str(), "1", last.as_str()),
+        [first, increment, last] => (first.as_str(), increment.as_str(), last.as_str()),
+        [] => {
+            eprintln!("seq : missing operand");
+
+            return Ok(Status::FAILURE);
+        }
+        [_, _, _, extra, ..] => {
+            eprintln!("seq : extra operand '{}'", extra);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    let mut numbers = [0.0; 3];
+
+    for (number, operand) in numbers.iter_mut().zip([first, increment, last]) {
+        match operand.parse::<f64>() {
+            Ok(parsed) if parsed.is_finite() => *number = parsed,
+            _ => {
+                eprintln!("seq : invalid floating point argument: '{}'", operand);
+
+                return Ok(Status::FAILURE);
+            }
+        }
+    }
+
+    let [first_value, step, last_value] = numbers;
+
+    if step == 0.0 {
+        eprintln!("seq : invalid zero increment value: '{}'", increment);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let precision = decimals(first).max(decimals(increment));
+    let width = if equal_width {
+        format_number(first_value, precision, 0)
+            .len()
+            .max(format_number(last_value, precision, 0).len())
+    } else {
+        0
+    };
+
+    let mut previous = None;
+
+    for i in 0..u64::MAX {
+        // Each number is computed from the first one, rather than by adding the increment repeatedly,
+        // so that rounding errors don't accumulate.
+        let value = round(first_value + i as f64 * step, precision);
+
+        if (step > 0.0 && value > last_value) || (step < 0.0 && value < last_value) {
+            break;
+        }
+
+        // Past the precision of a float, the increment may no longer change the value, which would
+        // otherwise be printed forever.
+        if previous == Some(value) {
+            break;
+        }
+
+        if previous.is_some() {
+            write!(streams.stdout, "{}", separator)?;
+        }
+
+        write!(streams.stdout, "{}", format_number(value, precision, width))?;
+        previous = Some(value);
+    }
+
+    if previous.is_some() {
+        writeln!(streams.stdout)?;
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Returns the number of decimals written in `number`.
+fn decimals(number: &str) -> usize {
+    number
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+fn round(value: f64, precision: usize) -> f64 {
+    let factor = 10f64.powi(precision as i32);
+
+    (value * factor).round() / factor
+}
+
+/// Formats `value` with `precision` decimals, padded with zeros after its sign up to `width` characters.
+fn format_number(value: f64, precision: usize, width: usize) -> String {
+    let formatted = format!("{:.*}", precision, value);
+
+    match formatted.strip_prefix('-') {
+        Some(digits) => format!("-{:0>width$}", digits, width = width.saturating_sub(1)),
+        None => format!("{:0>width$}", formatted, width = width),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn seq(arg: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arg), streams))
+    }
+
+    #[test]
+    fn last_alone_counts_from_one() {
+        assert_eq!(seq(&["3"]), (Status::SUCCESS, String::from("1\n2\n3\n")));
+        assert_eq!(seq(&["0"]), (Status::SUCCESS, String::new()));
+    }
+
+    #[test]
+    fn first_and_last_count_by_one() {
+        assert_eq!(seq(&["2", "4"]).1, "2\n3\n4\n");
+    }
+
+    #[test]
+    fn increment_sets_the_step() {
+        assert_eq!(seq(&["1", "2", "8"]).1, "1\n3\n5\n7\n");
+        assert_eq!(seq(&["3", "-1", "1"]).1, "3\n2\n1\n");
+        assert_eq!(seq(&["1", "-1", "3"]).1, "");
+    }
+
+    #[test]
+    fn decimals_set_the_precision() {
+        assert_eq!(seq(&["0", "0.1", "0.3"]).1, "0.0\n0.1\n0.2\n0.3\n");
+        assert_eq!(seq(&["1.50", "2"]).1, "1.50\n");
+    }
+
+    #[test]
+    fn w_pads_to_the_same_width() {
+        assert_eq!(seq(&["-w", "8", "10"]).1, "08\n09\n10\n");
+        assert_eq!(seq(&["-w", "-1", "1"]).1, "-1\n00\n01\n");
+    }
+
+    #[test]
+    fn s_sets_the_separator() {
+        assert_eq!(seq(&["-s", ", ", "3"]).1, "1, 2, 3\n");
+    }
+
+    #[test]
+    fn value_too_large_to_change_stops_the_sequence() {
+        assert_eq!(seq(&["1e17", "1e17"]).1, "100000000000000000\n");
+        assert_eq!(seq(&["1e17", "1", "1e18"]).1, "100000000000000000\n");
+    }
+
+    #[test]
+    fn zero_increment_fails() {
+        assert_eq!(seq(&["1", "0", "3"]), (Status::FAILURE, String::new()));
+    }
+
+    #[test]
+    fn invalid_number_fails() {
+        assert_eq!(seq(&["x"]).0, Status::FAILURE);
+        assert_eq!(seq(&["1", "inf"]).0, Status::FAILURE);
+    }
+}
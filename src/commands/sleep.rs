@@ -0,0 +1,127 @@
+use std::{
+    io, thread,
+    time::{Duration, Instant},
+};
+
+use crate::unix::signals;
+
+use super::Status;
+
+/// How often a pause checks whether Ctrl-C was pressed.
+const INTERRUPT_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Execute the `sleep` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `sleep` command.
+///
+/// It pauses for the sum of the durations given as arguments. A duration is a number of seconds, which
+/// may be decimal, optionally followed by `s` for seconds, `m` for minutes, `h` for hours or `d` for days,
+/// like `1.5m`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `sleep` command.
+pub fn execute(args: Vec<String>) -> io::Result<Status> {
+    if args.is_empty() {
+        eprintln!("sleep : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut total = Duration::ZERO;
+
+    for arg in args {
+        let Some(duration) = parse_duration(&arg) else {
+            eprintln!("sleep : invalid time interval '{}'", arg);
+
+            return Ok(Status::FAILURE);
+        };
+
+        total = total.saturating_add(duration);
+    }
+
+    // The pause is made of short ones, so that Ctrl-C can interrupt it. A pause too long to have an end
+    // lasts until then.
+    let deadline = Instant::now().checked_add(total);
+
+    while !signals::interrupted() {
+        let left = deadline.map_or(Duration::MAX, |deadline| {
+            deadline.saturating_duration_since(Instant::now())
+        });
+
+        if left.is_zero() {
+            break;
+        }
+
+        thread::sleep(left.min(INTERRUPT_CHECK_INTERVAL));
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Parses a duration like `10`, `0.5s`, `2m`, `1h` or `1d`. Returns `None` if the duration is negative or
+/// malformed.
+fn parse_duration(arg: &str) -> Option<Duration> {
+    let (number, unit) = match arg.strip_suffix(['s', 'm', 'h', 'd']) {
+        Some(number) => (number, &arg[number.len()..]),
+        None => (arg, "s"),
+    };
+
+    let multiplier = match unit {
+        "s" => 1.0,
+        "m" => 60.0,
+        "h" => 60.0 * 60.0,
+        _ => 24.0 * 60.0 * 60.0,
+    };
+
+    // Rules out the forms accepted by `f64::from_str` that aren't numbers, like `inf` or `nan`.
+    if !number.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    let seconds = number.parse::<f64>().ok()? * multiplier;
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, lock_process};
+
+    #[test]
+    fn parses_seconds_by_default() {
+        assert_eq!(parse_duration("10"), Some(Duration::from_secs(10)));
+        assert_eq!(parse_duration("0.5"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration(".25s"), Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn parses_suffixes() {
+        assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5h"), Some(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("1d"), Some(Duration::from_secs(86400)));
+    }
+
+    #[test]
+    fn rejects_negative_or_malformed_durations() {
+        for arg in ["-1", "", "s", "1x", "1ms", "inf", "nan", "+1", "1e400"] {
+            assert_eq!(parse_duration(arg), None, "{}", arg);
+        }
+    }
+
+    #[test]
+    fn pauses_for_the_sum_of_the_durations() {
+        let _lock = lock_process();
+        let start = Instant::now();
+
+        assert_eq!(execute(args(&["0.05", "0.05s"])).unwrap(), Status::SUCCESS);
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn invalid_or_missing_duration_fails() {
+        assert_eq!(execute(args(&[])).unwrap(), Status::FAILURE);
+        assert_eq!(execute(args(&["1", "soon"])).unwrap(), Status::FAILURE);
+    }
+}
@@ -0,0 +1,336 @@
+use std::{
+    cmp::Ordering,
+    fs::File,
+    io::{self, Read},
+};
+
+use super::{Status, Streams};
+
+/// How `sort` compares lines.
+struct Options {
+    reverse: bool,
+    numeric: bool,
+    unique: bool,
+    fold_case: bool,
+    /// The fields, counted from 1, that the lines are compared by, from `-k START[,END]`.
+    key: Option<(usize, Option<usize>)>,
+}
+
+/// Execute the `sort` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `sort` command.
+///
+/// It prints the lines of the files given as arguments sorted together, byte by byte. With `-n`, lines
+/// are compared by the number they start with, after any leading blanks, and lines that don't start with a
+/// number count as zero. `-f` ignores case, `-r` reverses the order, and `-u` prints the lines comparing
+/// equal only once. With `-k START[,END]`, lines are compared by their fields from `START` to `END`, or to
+/// the end of the line, fields being separated by blanks. Lines comparing equal are ordered by their whole
+/// content, except with `-u`, which keeps the first of them. The argument `-` stands for the standard
+/// input, which is also read when no file is given.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `sort` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut options = Options {
+        reverse: false,
+        numeric: false,
+        unique: false,
+        fold_case: false,
+        key: None,
+    };
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let key = match arg.strip_prefix("-k") {
+            Some("") => args.next(),
+            Some(key) => Some(key.to_string()),
+            None => None,
+        };
+
+        if arg.starts_with("-k") {
+            let Some(key) = key else {
+                eprintln!("sort : option requires an argument - 'k'");
+
+                return Ok(Status::FAILURE);
+            };
+
+            match parse_key(&key) {
+                Some(key) => options.key = Some(key),
+                None => {
+                    eprintln!("sort : invalid field specification '{}'", key);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+
+            continue;
+        }
+
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            paths.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'r' => options.reverse = true,
+                'n' => options.numeric = true,
+                'u' => options.unique = true,
+                'f' => options.fold_case = true,
+                _ => {
+                    eprintln!("sort : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let mut content = vec![];
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let read = if path == "-" {
+            streams.stdin.read_to_end(&mut content)
+        } else {
+            File::open(&path).and_then(|mut file| file.read_to_end(&mut content))
+        };
+
+        match read {
+            // Each file ends a line, even when its last line lacks a newline.
+            Ok(_) if !content.is_empty() && !content.ends_with(b"\n") => content.push(b'\n'),
+            Ok(_) => {}
+            Err(e) => {
+                handle_error(e, &path);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    let mut lines: Vec<&[u8]> = content
+        .split_inclusive(|byte| *byte == b'\n')
+        .map(|line| line.strip_suffix(b"\n").unwrap_or(line))
+        .collect();
+
+    lines.sort_by(|a, b| {
+        // With `-u`, lines with equal keys keep their input order, so that the first one is printed.
+        let ordering = if options.unique {
+            compare_keys(a, b, &options)
+        } else {
+            compare_keys(a, b, &options).then_with(|| a.cmp(b))
+        };
+
+        if options.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    if options.unique {
+        lines.dedup_by(|a, b| compare_keys(a, b, &options) == Ordering::Equal);
+    }
+
+    for line in lines {
+        streams.stdout.write_all(line)?;
+        streams.stdout.write_all(b"\n")?;
+    }
+
+    Ok(status)
+}
+
+/// Parses a `-k` specification, `START` or `START,END`, with fields counted from 1.
+fn parse_key(key: &str) -> Option<(usize, Option<usize>)> {
+    let (start, end) = match key.split_once(',') {
+        Some((start, end)) => (start, Some(end)),
+        None => (key, None),
+    };
+
+    let start = start.parse().ok().filter(|start| *start > 0)?;
+    let end = match end {
+        Some(end) => Some(end.parse().ok().filter(|end| *end >= start)?),
+        None => None,
+    };
+
+    Some((start, end))
+}
+
+/// Compares two lines by their keys, following the options but regardless of `-r`.
+fn compare_keys(a: &[u8], b: &[u8], options: &Options) -> Ordering {
+    let (a, b) = match options.key {
+        Some((start, end)) => (field_range(a, start, end), field_range(b, start, end)),
+        None => (a, b),
+    };
+
+    if options.numeric {
+        numeric_value(a).total_cmp(&numeric_value(b))
+    } else if options.fold_case {
+        a.iter()
+            .map(u8::to_ascii_uppercase)
+            .cmp(b.iter().map(u8::to_ascii_uppercase))
+    } else {
+        a.cmp(b)
+    }
+}
+
+/// Returns the part of `line` from the start of field `start` to the end of field `end`, or to the end
+/// of the line. Fields are separated by runs of blanks, which are left out of the range.
+fn field_range(line: &[u8], start: usize, end: Option<usize>) -> &[u8] {
+    let mut fields = vec![];
+    let mut i = 0;
+
+    while i < line.len() {
+        while i < line.len() && line[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let field_start = i;
+
+        while i < line.len() && !line[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        if field_start < i {
+            fields.push((field_start, i));
+        }
+    }
+
+    let Some(&(from, _)) = fields.get(start - 1) else {
+        return &[];
+    };
+
+    let to = match end.and_then(|end| fields.get(end - 1)) {
+        Some(&(_, to)) => to,
+        None => line.len(),
+    };
+
+    &line[from..to]
+}
+
+/// Returns the number at the start of `text`, after any leading blanks, or 0 if there is none.
+fn numeric_value(text: &[u8]) -> f64 {
+    let text = String::from_utf8_lossy(text);
+    let text = text.trim_start();
+
+    // The longest prefix made of an optional sign, digits and a decimal point.
+    let length = text
+        .char_indices()
+        .take_while(|(i, c)| c.is_ascii_digit() || *c == '.' || (*i == 0 && *c == '-'))
+        .count();
+
+    (1..=length)
+        .rev()
+        .find_map(|length| text[..length].parse().ok())
+        .unwrap_or(0.0)
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("sort : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("sort : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("sort : is a directory: {}", path),
+        _ => eprintln!("sort : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn sort(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn sorts_lines_byte_by_byte() {
+        assert_eq!(
+            sort(&[], "pear\nApple\napple\nbanana"),
+            (
+                Status::SUCCESS,
+                String::from("Apple\napple\nbanana\npear\n")
+            )
+        );
+        assert_eq!(sort(&["-r"], "b\na\nc\n").1, "c\nb\na\n");
+        assert_eq!(sort(&[], "").1, "");
+    }
+
+    #[test]
+    fn f_folds_case() {
+        assert_eq!(sort(&["-f"], "b\nA\nC\na\n").1, "A\na\nb\nC\n");
+    }
+
+    #[test]
+    fn n_compares_numbers() {
+        assert_eq!(
+            sort(&["-n"], "10\n9\n-2\n1.5\n100\n").1,
+            "-2\n1.5\n9\n10\n100\n"
+        );
+        assert_eq!(sort(&["-nr"], "1\n3\n2\n").1, "3\n2\n1\n");
+    }
+
+    #[test]
+    fn n_skips_leading_blanks_and_counts_other_lines_as_zero() {
+        assert_eq!(
+            sort(&["-n"], "  3 apples\nabc\n\t2\n-1\n").1,
+            "-1\nabc\n\t2\n  3 apples\n"
+        );
+        assert_eq!(numeric_value(b"12.5.3kg"), 12.5);
+        assert_eq!(numeric_value(b"-"), 0.0);
+    }
+
+    #[test]
+    fn u_prints_equal_lines_once() {
+        assert_eq!(sort(&["-u"], "b\na\nb\na\n").1, "a\nb\n");
+        assert_eq!(sort(&["-fu"], "b\nB\na\n").1, "a\nb\n");
+        assert_eq!(sort(&["-nu"], "01\n1\n2\n").1, "01\n2\n");
+    }
+
+    #[test]
+    fn k_sorts_by_fields() {
+        let input = "x 3 b\ny 1 c\nz 2 a\n";
+
+        assert_eq!(sort(&["-k", "2"], input).1, "y 1 c\nz 2 a\nx 3 b\n");
+        assert_eq!(sort(&["-k3"], input).1, "z 2 a\nx 3 b\ny 1 c\n");
+        assert_eq!(sort(&["-n", "-k", "2,2"], "a  10\nb 9\n").1, "b 9\na  10\n");
+    }
+
+    #[test]
+    fn field_range_leaves_the_separating_blanks_out() {
+        assert_eq!(field_range(b"  a  b c", 2, None), b"b c");
+        assert_eq!(field_range(b"a b c", 1, Some(2)), b"a b");
+        assert_eq!(field_range(b"a", 3, None), b"");
+    }
+
+    #[test]
+    fn parses_key_specifications() {
+        assert_eq!(parse_key("2"), Some((2, None)));
+        assert_eq!(parse_key("2,3"), Some((2, Some(3))));
+        assert_eq!(parse_key("0"), None);
+        assert_eq!(parse_key("3,2"), None);
+        assert_eq!(parse_key("a"), None);
+    }
+
+    #[test]
+    fn invalid_key_fails() {
+        assert_eq!(sort(&["-k", "0"], "a\n"), (Status::FAILURE, String::new()));
+        assert_eq!(sort(&["-k"], "a\n"), (Status::FAILURE, String::new()));
+    }
+
+    #[test]
+    fn sorts_files_and_standard_input_together() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "c\na");
+
+        let (status, output) = sort(&[&file, "-", &dir.join("missing")], "b\n");
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, "a\nb\nc\n"));
+    }
+}
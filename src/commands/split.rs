@@ -0,0 +1,389 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+};
+
+use super::{Status, Streams};
+
+/// The number of lines of each piece when no size is given.
+const DEFAULT_LINES: u64 = 1000;
+
+/// The letters the suffixes of the pieces are made of, two per piece, from `aa` to `zz`.
+const SUFFIX_LETTERS: u8 = 26;
+
+/// The size of each piece: a number of lines with `-l`, or of bytes with `-b`.
+#[derive(Clone, Copy)]
+enum Size {
+    Lines(u64),
+    Bytes(u64),
+}
+
+/// The pieces written so far, and the one being written.
+struct Pieces<'a> {
+    prefix: &'a str,
+    count: usize,
+    current: Option<BufWriter<File>>,
+}
+
+/// Execute the `split` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `split`
+/// command.
+///
+/// It splits the file given as argument, or the standard input when there is none or it is `-`, into
+/// pieces of 1000 lines, or `N` lines with `-l N`, or `N` bytes with `-b N`, where `N` may end with `K`,
+/// `M` or `G` for kibibytes, mebibytes or gibibytes. The pieces are named after `PREFIX`, `x` by default,
+/// followed by a suffix going from `aa` to `zz`, like `xaa`, `xab` and so on. The input is read as it is
+/// written, so that its size isn't limited by the memory available.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `split` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut size = Size::Lines(DEFAULT_LINES);
+    let mut operands = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let option = match arg.as_str() {
+            _ if arg.starts_with("-l") || arg.starts_with("-b") => arg[1..2].to_string(),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("split : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => {
+                operands.push(arg);
+                continue;
+            }
+        };
+
+        let value = match &arg[2..] {
+            "" => args.next(),
+            value => Some(value.to_string()),
+        };
+
+        let Some(value) = value else {
+            eprintln!("split : option requires an argument - '{}'", option);
+
+            return Ok(Status::FAILURE);
+        };
+
+        size = match (option.as_str(), parse_size(&value)) {
+            ("l", Some(lines)) => Size::Lines(lines),
+            ("b", Some(bytes)) => Size::Bytes(bytes),
+            _ => {
+                eprintln!("split : invalid number: '{}'", value);
+
+                return Ok(Status::FAILURE);
+            }
+        };
+    }
+
+    let (path, prefix) = match operands.as_slice() {
+        [] => ("-", "x"),
+        [path] => (path.as_str(), "x"),
+        [path, prefix] => (path.as_str(), prefix.as_str()),
+        [_, _, extra, ..] => {
+            eprintln!("split : extra operand '{}'", extra);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    let reader: io::Result<Box<dyn BufRead>> = if path == "-" {
+        Ok(Box::new(&mut *streams.stdin))
+    } else {
+        File::open(path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+    };
+
+    let mut reader = match reader {
+        Ok(reader) => reader,
+        Err(e) => {
+            handle_error(e, path);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    let mut pieces = Pieces {
+        prefix,
+        count: 0,
+        current: None,
+    };
+
+    match split(&mut reader, path, size, &mut pieces) {
+        Ok(()) => Ok(Status::SUCCESS),
+        Err((e, path)) => {
+            handle_error(e, &path);
+
+            Ok(Status::FAILURE)
+        }
+    }
+}
+
+/// Parses a size, a positive number optionally followed by `K`, `M` or `G`.
+fn parse_size(value: &str) -> Option<u64> {
+    let (number, multiplier) = match value.strip_suffix(['K', 'M', 'G']) {
+        Some(number) => (
+            number,
+            1024u64.pow(1 + "KMG".find(&value[number.len()..])? as u32),
+        ),
+        None => (value, 1),
+    };
+
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|number| number.checked_mul(multiplier))
+        .filter(|size| *size > 0)
+}
+
+/// Copies `reader`, the input at `path`, into pieces of the given size. On failure, returns the error
+/// along with the path of the file it happened on.
+fn split(
+    reader: &mut dyn BufRead,
+    path: &str,
+    size: Size,
+    pieces: &mut Pieces,
+) -> Result<(), (io::Error, String)> {
+    let input_error = |e| (e, path.to_string());
+    // What is left to write to the current piece before starting the next one.
+    let mut left = 0;
+
+    match size {
+        Size::Lines(lines) => {
+            let mut line = vec![];
+
+            loop {
+                line.clear();
+
+                if reader.read_until(b'\n', &mut line).map_err(input_error)? == 0 {
+                    break;
+                }
+
+                if left == 0 {
+                    pieces.start()?;
+                    left = lines;
+                }
+
+                pieces.write(&line)?;
+                left -= 1;
+            }
+        }
+        Size::Bytes(bytes) => loop {
+            let input = reader.fill_buf().map_err(input_error)?;
+
+            if input.is_empty() {
+                break;
+            }
+
+            if left == 0 {
+                pieces.start()?;
+                left = bytes;
+            }
+
+            let length = input.len().min(left.try_into().unwrap_or(usize::MAX));
+
+            pieces.write(&input[..length])?;
+            reader.consume(length);
+            left -= length as u64;
+        },
+    }
+
+    pieces.finish()
+}
+
+impl Pieces<'_> {
+    fn name(&self, index: usize) -> String {
+        let letters = SUFFIX_LETTERS as usize;
+        let first = (b'a' + (index / letters) as u8) as char;
+        let second = (b'a' + (index % letters) as u8) as char;
+
+        format!("{}{}{}", self.prefix, first, second)
+    }
+
+    /// Finishes the current piece, if any, and creates the next one.
+    fn start(&mut self) -> Result<(), (io::Error, String)> {
+        self.finish()?;
+
+        let limit = SUFFIX_LETTERS as usize * SUFFIX_LETTERS as usize;
+
+        if self.count == limit {
+            let error = io::Error::other("output file suffixes exhausted");
+
+            return Err((error, self.name(limit - 1)));
+        }
+
+        let name = self.name(self.count);
+        let file = File::create(&name).map_err(|e| (e, name))?;
+
+        self.current = Some(BufWriter::new(file));
+        self.count += 1;
+
+        Ok(())
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), (io::Error, String)> {
+        let name = self.name(self.count - 1);
+
+        match &mut self.current {
+            Some(writer) => writer.write_all(bytes).map_err(|e| (e, name)),
+            None => Ok(()),
+        }
+    }
+
+    /// Flushes the current piece, so that write errors are reported rather than lost on drop.
+    fn finish(&mut self) -> Result<(), (io::Error, String)> {
+        match self.current.take() {
+            Some(mut writer) => writer.flush().map_err(|e| (e, self.name(self.count - 1))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("split : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("split : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("split : is a directory: {}", path),
+        _ => eprintln!("split : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    /// Returns the names and contents of the files of `dir` starting with `prefix`, in order.
+    fn pieces(dir: &TempDir, prefix: &str) -> Vec<(String, String)> {
+        let mut pieces: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.starts_with(prefix))
+            .map(|name| {
+                let content = fs::read_to_string(dir.path().join(&name)).unwrap();
+
+                (name, content)
+            })
+            .collect();
+
+        pieces.sort();
+        pieces
+    }
+
+    fn piece(name: &str, content: &str) -> (String, String) {
+        (name.to_string(), content.to_string())
+    }
+
+    #[test]
+    fn l_splits_into_pieces_of_lines() {
+        let dir = TempDir::new();
+        let input = dir.write("input", "1\n2\n3\n4\n5");
+        let prefix = dir.join("x");
+
+        let (status, output) = run("", |streams| {
+            execute(args(&["-l", "2", &input, &prefix]), streams)
+        });
+
+        assert_eq!((status, output.as_str()), (Status::SUCCESS, ""));
+        assert_eq!(
+            pieces(&dir, "x"),
+            [
+                piece("xaa", "1\n2\n"),
+                piece("xab", "3\n4\n"),
+                piece("xac", "5")
+            ]
+        );
+    }
+
+    #[test]
+    fn b_splits_into_pieces_of_bytes() {
+        let dir = TempDir::new();
+        let prefix = dir.join("part-");
+
+        let (status, _) = run("abcdefghij", |streams| {
+            execute(args(&["-b4", "-", &prefix]), streams)
+        });
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(
+            pieces(&dir, "part-"),
+            [
+                piece("part-aa", "abcd"),
+                piece("part-ab", "efgh"),
+                piece("part-ac", "ij")
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_into_pieces_of_1000_lines_by_default() {
+        let dir = TempDir::new();
+        let input = dir.write("input", &"line\n".repeat(2500));
+        let prefix = dir.join("x");
+
+        run("", |streams| execute(args(&[&input, &prefix]), streams));
+
+        let lines: Vec<_> = pieces(&dir, "x")
+            .iter()
+            .map(|(_, content)| content.lines().count())
+            .collect();
+        assert_eq!(lines, [1000, 1000, 500]);
+    }
+
+    #[test]
+    fn empty_input_makes_no_piece() {
+        let dir = TempDir::new();
+        let prefix = dir.join("x");
+
+        run("", |streams| execute(args(&["-", &prefix]), streams));
+
+        assert!(pieces(&dir, "x").is_empty());
+    }
+
+    #[test]
+    fn names_the_pieces_from_aa_to_zz() {
+        let pieces = Pieces {
+            prefix: "x",
+            count: 0,
+            current: None,
+        };
+
+        assert_eq!(pieces.name(0), "xaa");
+        assert_eq!(pieces.name(27), "xbb");
+        assert_eq!(pieces.name(675), "xzz");
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("10"), Some(10));
+        assert_eq!(parse_size("2K"), Some(2048));
+        assert_eq!(parse_size("1M"), Some(1 << 20));
+        assert_eq!(parse_size("1G"), Some(1 << 30));
+        for size in ["0", "-1", "K", "1k", "99999999999G"] {
+            assert_eq!(parse_size(size), None, "{}", size);
+        }
+    }
+
+    #[test]
+    fn invalid_usage_fails() {
+        let dir = TempDir::new();
+
+        for arguments in [
+            &["-l", "0"][..],
+            &["-b"],
+            &["-x"],
+            &["a", "b", "c"],
+            &[&dir.join("missing")],
+        ] {
+            let (status, _) = run("", |streams| execute(args(arguments), streams));
+
+            assert_eq!(status, Status::FAILURE);
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    fs::{self, Metadata},
+    io,
+    os::{linux::fs::MetadataExt, unix::fs::FileTypeExt},
+};
+
+use crate::unix::{
+    permissions::{FileType, UnixPermissions},
+    time::{from_unix_seconds, DateTime},
+    users::{lookup_group, lookup_user},
+};
+
+use super::{Status, Streams};
+
+/// Execute the `stat` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `stat` command.
+///
+/// It prints the metadata of each file given as argument: its size, blocks, device, inode, number of
+/// links, mode, owner and group, and its access, modification and change times. Symlinks are described
+/// themselves rather than the file they point to.
+///
+/// With `-c FORMAT`, a line is printed for each file following `FORMAT`, in which `%n` stands for the file
+/// name, `%s` for the size, `%b` for the number of blocks, `%i` for the inode, `%h` for the number of
+/// links, `%a` and `%A` for the mode in octal and human readable forms, `%F` for the file type, `%u` and
+/// `%U` for the owner id and name, `%g` and `%G` for the group id and name, `%x`, `%y` and `%z` for the
+/// access, modification and change times, `%X`, `%Y` and `%Z` for the same times in seconds since the
+/// Unix epoch, and `%%` for a `%`.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `stat` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut format = None;
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-c" => match args.next() {
+                Some(value) => format = Some(value),
+                None => {
+                    eprintln!("stat : option requires an argument - 'c'");
+
+                    return Ok(Status::FAILURE);
+                }
+            },
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("stat : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("stat : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let metadata = match fs::symlink_metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                handle_error(e, &path);
+                status = Status::FAILURE;
+                continue;
+            }
+        };
+
+        match &format {
+            Some(format) => writeln!(
+                streams.stdout,
+                "{}",
+                format_metadata(format, &path, &metadata)
+            )?,
+            None => print_metadata(&path, &metadata, streams)?,
+        }
+    }
+
+    Ok(status)
+}
+
+/// Prints the metadata of the file at `path` in the default format, similar to the one of GNU stat.
+fn print_metadata(path: &str, metadata: &Metadata, streams: &mut Streams) -> io::Result<()> {
+    let name = match fs::read_link(path) {
+        Ok(target) if metadata.file_type().is_symlink() => {
+            format!("{} -> {}", path, target.display())
+        }
+        _ => path.to_string(),
+    };
+
+    writeln!(streams.stdout, "  File: {}", name)?;
+    writeln!(
+        streams.stdout,
+        "  Size: {:<15} Blocks: {:<10} IO Block: {:<6} {}",
+        metadata.st_size(),
+        metadata.st_blocks(),
+        metadata.st_blksize(),
+        file_type_name(metadata)
+    )?;
+    writeln!(
+        streams.stdout,
+        "Device: {:x}h/{}d\tInode: {:<11} Links: {}",
+        metadata.st_dev(),
+        metadata.st_dev(),
+        metadata.st_ino(),
+        metadata.st_nlink()
+    )?;
+    writeln!(
+        streams.stdout,
+        "Access: ({:04o}/{})  Uid: ({:>5}/{:>8})   Gid: ({:>5}/{:>8})",
+        metadata.st_mode() & 0o7777,
+        mode_string(metadata),
+        metadata.st_uid(),
+        user_name(metadata),
+        metadata.st_gid(),
+        group_name(metadata)
+    )?;
+    writeln!(
+        streams.stdout,
+        "Access: {}",
+        format_time(metadata.st_atime(), metadata.st_atime_nsec())
+    )?;
+    writeln!(
+        streams.stdout,
+        "Modify: {}",
+        format_time(metadata.st_mtime(), metadata.st_mtime_nsec())
+    )?;
+    writeln!(
+        streams.stdout,
+        "Change: {}",
+        format_time(metadata.st_ctime(), metadata.st_ctime_nsec())
+    )?;
+
+    Ok(())
+}
+
+/// Formats the metadata of the file at `path` following the `-c` format. Unknown specifiers are kept
+/// as they are.
+fn format_metadata(format: &str, path: &str, metadata: &Metadata) -> String {
+    let mut output = String::new();
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            output.push(c);
+            continue;
+        }
+
+        let Some(specifier) = chars.next() else {
+            output.push('%');
+            break;
+        };
+
+        let value = match specifier {
+            'n' => path.to_string(),
+            's' => metadata.st_size().to_string(),
+            'b' => metadata.st_blocks().to_string(),
+            'i' => metadata.st_ino().to_string(),
+            'h' => metadata.st_nlink().to_string(),
+            'a' => format!("{:o}", metadata.st_mode() & 0o7777),
+            'A' => mode_string(metadata),
+            'F' => file_type_name(metadata).to_string(),
+            'u' => metadata.st_uid().to_string(),
+            'U' => user_name(metadata),
+            'g' => metadata.st_gid().to_string(),
+            'G' => group_name(metadata),
+            'x' => format_time(metadata.st_atime(), metadata.st_atime_nsec()),
+            'y' => format_time(metadata.st_mtime(), metadata.st_mtime_nsec()),
+            'z' => format_time(metadata.st_ctime(), metadata.st_ctime_nsec()),
+            'X' => metadata.st_atime().to_string(),
+            'Y' => metadata.st_mtime().to_string(),
+            'Z' => metadata.st_ctime().to_string(),
+            '%' => String::from("%"),
+            _ => format!("%{}", specifier),
+        };
+
+        output.push_str(&value);
+    }
+
+    output
+}
+
+/// Returns the mode of a file in the form printed by `ls -l`, like `-rw-r--r--`.
+fn mode_string(metadata: &Metadata) -> String {
+    let permissions = metadata.permissions();
+
+    format!(
+        "{}{}{}{}",
+        FileType(metadata.file_type()),
+        permissions.owner(),
+        permissions.group(),
+        permissions.other()
+    )
+}
+
+fn file_type_name(metadata: &Metadata) -> &'static str {
+    let file_type = metadata.file_type();
+
+    match file_type {
+        _ if file_type.is_dir() => "directory",
+        _ if file_type.is_symlink() => "symbolic link",
+        _ if file_type.is_block_device() => "block special file",
+        _ if file_type.is_char_device() => "character special file",
+        _ if file_type.is_socket() => "socket",
+        _ if file_type.is_fifo() => "fifo",
+        _ if metadata.st_size() == 0 => "regular empty file",
+        _ => "regular file",
+    }
+}
+
+/// Returns the name of the owner of a file, or its uid when it has no name.
+fn user_name(metadata: &Metadata) -> String {
+    lookup_user(metadata.st_uid()).unwrap_or_else(|| metadata.st_uid().to_string())
+}
+
+/// Returns the name of the group of a file, or its gid when it has no name.
+fn group_name(metadata: &Metadata) -> String {
+    lookup_group(metadata.st_gid()).unwrap_or_else(|| metadata.st_gid().to_string())
+}
+
+/// Formats a time given in seconds and nanoseconds since the Unix epoch, in the local time zone.
+fn format_time(seconds: i64, nanoseconds: i64) -> String {
+    let date = from_unix_seconds(seconds).and_then(DateTime::local);
+
+    match date {
+        Some(date) => format!(
+            "{}-{:02}-{:02} {:02}:{:02}:{:02}.{:09}",
+            date.year, date.month, date.day, date.hour, date.minute, date.second, nanoseconds
+        ),
+        None => String::from("?"),
+    }
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("stat : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("stat : permission denied: {}", path),
+        _ => eprintln!("stat : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::{symlink, PermissionsExt};
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn stat(arg: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arg), streams))
+    }
+
+    #[test]
+    fn default_output_describes_the_file() {
+        let dir = TempDir::new();
+        let path = dir.write("file", "hello");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        let (status, output) = stat(&[&path]);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], format!("  File: {}", path));
+        assert!(lines[1].starts_with("  Size: 5 "), "{}", lines[1]);
+        assert!(lines[1].ends_with(" regular file"), "{}", lines[1]);
+        assert!(lines[2].contains("Links: 1"), "{}", lines[2]);
+        assert!(
+            lines[3].starts_with("Access: (0640/-rw-r-----)"),
+            "{}",
+            lines[3]
+        );
+        assert!(lines[5].starts_with("Modify: "), "{}", lines[5]);
+    }
+
+    #[test]
+    fn symlink_is_described_rather_than_followed() {
+        let dir = TempDir::new();
+        dir.write("target", "");
+        let link = dir.join("link");
+        symlink("target", &link).unwrap();
+
+        let (_, output) = stat(&[&link]);
+
+        assert!(output.starts_with(&format!("  File: {} -> target\n", link)));
+        assert!(output.contains(" symbolic link\n"));
+    }
+
+    #[test]
+    fn c_formats_the_specifiers() {
+        let dir = TempDir::new();
+        let path = dir.write("file", "hello");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o754)).unwrap();
+
+        let (_, output) = stat(&["-c", "%n %s %a %A %F %h 100%% %q", &path]);
+
+        assert_eq!(
+            output,
+            format!("{} 5 754 -rwxr-xr-- regular file 1 100% %q\n", path)
+        );
+    }
+
+    #[test]
+    fn c_prints_the_owner_and_times() {
+        let dir = TempDir::new();
+        let path = dir.write("file", "");
+        let metadata = fs::metadata(&path).unwrap();
+
+        let (_, output) = stat(&["-c", "%u %g %Y", &path]);
+
+        assert_eq!(
+            output,
+            format!(
+                "{} {} {}\n",
+                metadata.st_uid(),
+                metadata.st_gid(),
+                metadata.st_mtime()
+            )
+        );
+    }
+
+    #[test]
+    fn missing_file_fails_without_stopping_the_others() {
+        let dir = TempDir::new();
+        let path = dir.write("file", "");
+
+        let (status, output) = stat(&["-c", "%n", &dir.join("missing"), &path]);
+
+        assert_eq!((status, output), (Status::FAILURE, format!("{}\n", path)));
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+};
+
+use super::{Status, Streams};
+
+/// Execute the `tac` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `tac` command.
+///
+/// It prints the lines of each file given as argument in reverse order, the last line first. With
+/// `-s SEP`, the file is split into records ending with `SEP` rather than with a newline. Like with GNU
+/// tac, each record keeps its separator, so that a last record without one is joined to the record
+/// printed after it. Each file is read whole before being printed, so its size is limited by the memory
+/// available. The argument `-` stands for the standard input, which is also read when no file is given.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `tac` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut separator = String::from("\n");
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-s" => match args.next() {
+                Some(value) if !value.is_empty() => separator = value,
+                Some(_) => {
+                    eprintln!("tac : separator cannot be empty");
+
+                    return Ok(Status::FAILURE);
+                }
+                None => {
+                    eprintln!("tac : option requires an argument - 's'");
+
+                    return Ok(Status::FAILURE);
+                }
+            },
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("tac : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        let mut content = vec![];
+        let read = if path == "-" {
+            streams.stdin.read_to_end(&mut content)
+        } else {
+            File::open(&path).and_then(|mut file| file.read_to_end(&mut content))
+        };
+
+        if let Err(e) = read {
+            handle_error(e, &path);
+            status = Status::FAILURE;
+            continue;
+        }
+
+        for record in records(&content, separator.as_bytes()).iter().rev() {
+            streams.stdout.write_all(record)?;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Splits `content` into records, each of them ending with `separator` except possibly the last one.
+fn records<'a>(content: &'a [u8], separator: &[u8]) -> Vec<&'a [u8]> {
+    let mut records = vec![];
+    let mut start = 0;
+    let mut i = 0;
+
+    while i + separator.len() <= content.len() {
+        if content[i..].starts_with(separator) {
+            i += separator.len();
+            records.push(&content[start..i]);
+            start = i;
+        } else {
+            i += 1;
+        }
+    }
+
+    if start < content.len() {
+        records.push(&content[start..]);
+    }
+
+    records
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("tac : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("tac : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("tac : is a directory: {}", path),
+        _ => eprintln!("tac : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn tac(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn prints_the_lines_of_a_file_last_first() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "one\ntwo\nthree\n");
+
+        assert_eq!(
+            tac(&[&file], ""),
+            (Status::SUCCESS, String::from("three\ntwo\none\n"))
+        );
+    }
+
+    #[test]
+    fn last_line_without_newline_is_joined_to_the_next() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "one\ntwo\nthree");
+
+        assert_eq!(tac(&[&file], "").1, "threetwo\none\n");
+    }
+
+    #[test]
+    fn s_splits_on_a_separator() {
+        assert_eq!(tac(&["-s", ","], "a,b,c,").1, "c,b,a,");
+        assert_eq!(tac(&["-s", "--"], "a--b--c").1, "cb--a--");
+    }
+
+    #[test]
+    fn splits_into_records_keeping_their_separator() {
+        assert_eq!(records(b"a\nb\n", b"\n"), [&b"a\n"[..], b"b\n"]);
+        assert_eq!(records(b"\n\nx", b"\n"), [&b"\n"[..], b"\n", b"x"]);
+        assert!(records(b"", b"\n").is_empty());
+    }
+
+    #[test]
+    fn reverses_each_file_on_its_own() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "1\n2\n");
+
+        assert_eq!(tac(&[&file, "-"], "3\n4\n").1, "2\n1\n4\n3\n");
+    }
+
+    #[test]
+    fn invalid_usage_fails() {
+        let dir = TempDir::new();
+
+        for arguments in [&["-s"][..], &["-s", ""], &["-x"], &[&dir.join("missing")]] {
+            assert_eq!(tac(arguments, "a\n"), (Status::FAILURE, String::new()));
+        }
+    }
+}
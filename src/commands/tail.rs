@@ -0,0 +1,454 @@
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{self, BufRead, Read, Seek, SeekFrom, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::unix::signals;
+
+use super::{Status, Streams};
+
+/// The number of lines printed when no count is given.
+const DEFAULT_COUNT: u64 = 10;
+
+/// The size of the chunks read backwards from the end of a file.
+const CHUNK_SIZE: u64 = 8192;
+
+/// The delay between two checks for new data in follow mode.
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What `tail` counts: lines with `-n`, or bytes with `-c`, either from the end, or from the line or
+/// byte at which to start printing when the count starts with `+`.
+enum Count {
+    Lines(u64),
+    Bytes(u64),
+    LinesFrom(u64),
+    BytesFrom(u64),
+}
+
+/// A file followed with `-f`, along with the position up to which it was printed.
+struct Followed {
+    name: String,
+    file: File,
+    position: u64,
+}
+
+/// Execute the `tail` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `tail` command.
+///
+/// It prints the last 10 lines of each file, or the last `N` lines with `-n N`, or the last `N` bytes with
+/// `-c N`. With `-n +N` or `-c +N`, everything is printed starting at the line or byte `N`. Regular files are read backwards from their end, so that only their last part is read. When
+/// several files are given, each one is preceded by a `==> name <==` header. The argument `-` stands for
+/// the standard input, which is also read when no file is given.
+///
+/// With `-f`, the files are kept open once printed and the data appended to them is printed as it
+/// arrives, until the shell is interrupted.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `tail` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut count = Count::Lines(DEFAULT_COUNT);
+    let mut follow = false;
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (option, value) = match arg.as_str() {
+            "-f" => {
+                follow = true;
+                continue;
+            }
+            "-n" | "-c" => (arg.clone(), args.next()),
+            _ if arg.starts_with("-n") || arg.starts_with("-c") => {
+                (arg[..2].to_string(), Some(arg[2..].to_string()))
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("tail : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => {
+                paths.push(arg);
+                continue;
+            }
+        };
+
+        let Some(value) = value else {
+            eprintln!("tail : option requires an argument - '{}'", &option[1..]);
+
+            return Ok(Status::FAILURE);
+        };
+
+        let (from_start, digits) = match value.strip_prefix('+') {
+            Some(digits) => (true, digits),
+            None => (false, value.as_str()),
+        };
+
+        let Ok(number) = digits.parse() else {
+            eprintln!("tail : invalid number: '{}'", value);
+
+            return Ok(Status::FAILURE);
+        };
+
+        count = match (option.as_str(), from_start) {
+            ("-n", false) => Count::Lines(number),
+            ("-n", true) => Count::LinesFrom(number),
+            (_, false) => Count::Bytes(number),
+            (_, true) => Count::BytesFrom(number),
+        };
+    }
+
+    if paths.is_empty() {
+        paths.push(String::from("-"));
+    }
+
+    let show_headers = paths.len() > 1;
+    let mut stdout = &mut *streams.stdout;
+    let mut followed = vec![];
+    let mut status = Status::SUCCESS;
+
+    for (i, path) in paths.iter().enumerate() {
+        if show_headers {
+            let name = if path == "-" { "standard input" } else { path };
+
+            if i > 0 {
+                writeln!(stdout)?;
+            }
+
+            writeln!(stdout, "==> {} <==", name)?;
+        }
+
+        if path == "-" {
+            if let Err(e) = tail_stream(&mut streams.stdin, &mut stdout, &count) {
+                handle_error(e, path);
+                status = Status::FAILURE;
+            }
+
+            continue;
+        }
+
+        match tail_file(path, &mut stdout, &count) {
+            Ok(file) if follow => followed.push(file),
+            Ok(_) => {}
+            Err(e) => {
+                handle_error(e, path);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    stdout.flush()?;
+
+    if !followed.is_empty() {
+        follow_files(followed, show_headers, &mut stdout)?;
+    }
+
+    Ok(status)
+}
+
+/// Prints the end of the file at `path`, returning it along with the position of its end, to follow it.
+/// Regular files are read backwards from their end, while other files, and files printed from a given
+/// line or byte, are read as streams.
+fn tail_file(path: &str, writer: &mut impl Write, count: &Count) -> io::Result<Followed> {
+    let mut file = File::open(path)?;
+    let metadata = file.metadata()?;
+
+    if metadata.is_dir() {
+        return Err(io::Error::from(io::ErrorKind::IsADirectory));
+    }
+
+    if !metadata.is_file() || matches!(count, Count::LinesFrom(_) | Count::BytesFrom(_)) {
+        tail_stream(&mut io::BufReader::new(&file), writer, count)?;
+    } else {
+        let len = metadata.len();
+
+        let start = match *count {
+            Count::Lines(lines) => find_lines_start(&mut file, len, lines)?,
+            Count::Bytes(bytes) => len.saturating_sub(bytes),
+            Count::LinesFrom(_) | Count::BytesFrom(_) => unreachable!(),
+        };
+
+        file.seek(SeekFrom::Start(start))?;
+        io::copy(&mut (&mut file).take(len - start), writer)?;
+    }
+
+    let position = file.stream_position()?;
+
+    Ok(Followed {
+        name: path.to_string(),
+        file,
+        position,
+    })
+}
+
+/// Returns the offset at which the last `lines` lines of a file of `len` bytes start, reading the file
+/// backwards by chunks. A newline ending the file doesn't start a new line.
+fn find_lines_start(file: &mut File, len: u64, lines: u64) -> io::Result<u64> {
+    if lines == 0 {
+        return Ok(len);
+    }
+
+    let mut buffer = vec![0; CHUNK_SIZE as usize];
+    let mut position = len;
+    let mut newlines = 0;
+
+    while position > 0 {
+        let size = CHUNK_SIZE.min(position);
+        position -= size;
+
+        let chunk = &mut buffer[..size as usize];
+        file.seek(SeekFrom::Start(position))?;
+        file.read_exact(chunk)?;
+
+        for (i, byte) in chunk.iter().enumerate().rev() {
+            let offset = position + i as u64;
+
+            if *byte == b'\n' && offset != len - 1 {
+                newlines += 1;
+
+                if newlines == lines {
+                    return Ok(offset + 1);
+                }
+            }
+        }
+    }
+
+    Ok(0)
+}
+
+/// Prints the end of a stream that cannot be read backwards, keeping only the last lines or bytes read
+/// in memory, or skipping the lines or bytes before the one to start at.
+fn tail_stream(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    count: &Count,
+) -> io::Result<()> {
+    match *count {
+        Count::Lines(lines) => {
+            let mut last_lines: VecDeque<Vec<u8>> = VecDeque::new();
+            let mut line = vec![];
+
+            while reader.read_until(b'\n', &mut line)? > 0 {
+                last_lines.push_back(std::mem::take(&mut line));
+
+                if last_lines.len() as u64 > lines {
+                    last_lines.pop_front();
+                }
+            }
+
+            for line in last_lines {
+                writer.write_all(&line)?;
+            }
+        }
+        Count::Bytes(bytes) => {
+            let mut last_bytes: VecDeque<u8> = VecDeque::new();
+
+            for byte in reader.bytes() {
+                last_bytes.push_back(byte?);
+
+                if last_bytes.len() as u64 > bytes {
+                    last_bytes.pop_front();
+                }
+            }
+
+            writer.write_all(last_bytes.make_contiguous())?;
+        }
+        Count::LinesFrom(start) => {
+            let mut line = vec![];
+
+            for _ in 1..start {
+                if reader.read_until(b'\n', &mut line)? == 0 {
+                    return Ok(());
+                }
+            }
+
+            io::copy(reader, writer)?;
+        }
+        Count::BytesFrom(start) => {
+            io::copy(&mut reader.take(start.saturating_sub(1)), &mut io::sink())?;
+            io::copy(reader, writer)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the data appended to the followed files as it arrives, until Ctrl-C is pressed. A file whose
+/// size drops below its printed position was truncated, and is followed again from its start.
+fn follow_files(
+    mut followed: Vec<Followed>,
+    show_headers: bool,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let mut last_printed = followed.len() - 1;
+
+    // Without new data, nothing is written to fail once interrupted, so the flag is checked on its own.
+    while !signals::interrupted() {
+        for (i, followed) in followed.iter_mut().enumerate() {
+            let len = followed.file.metadata()?.len();
+
+            if len < followed.position {
+                eprintln!("tail : {}: file truncated", followed.name);
+                followed.position = 0;
+            }
+
+            if len == followed.position {
+                continue;
+            }
+
+            if show_headers && i != last_printed {
+                writeln!(writer, "\n==> {} <==", followed.name)?;
+            }
+
+            last_printed = i;
+            followed.file.seek(SeekFrom::Start(followed.position))?;
+            followed.position +=
+                io::copy(&mut (&followed.file).take(len - followed.position), writer)?;
+            writer.flush()?;
+        }
+
+        thread::sleep(FOLLOW_INTERVAL);
+    }
+
+    Ok(())
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("tail : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("tail : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("tail : is a directory: {}", path),
+        _ => eprintln!("tail : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, lock_process, run, TempDir};
+
+    const TWELVE_LINES: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+
+    fn tail(arg: &[&str]) -> (Status, String) {
+        run("", |streams| execute(args(arg), streams))
+    }
+
+    #[test]
+    fn prints_the_last_ten_lines_of_a_file() {
+        let dir = TempDir::new();
+        let path = dir.write("file", TWELVE_LINES);
+
+        let (status, output) = tail(&[&path]);
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n");
+    }
+
+    #[test]
+    fn n_sets_the_number_of_lines() {
+        let dir = TempDir::new();
+        let path = dir.write("file", TWELVE_LINES);
+
+        assert_eq!(tail(&["-n", "2", &path]).1, "11\n12\n");
+        assert_eq!(tail(&["-n0", &path]).1, "");
+    }
+
+    #[test]
+    fn plus_n_starts_at_a_line() {
+        let dir = TempDir::new();
+        let path = dir.write("file", TWELVE_LINES);
+
+        assert_eq!(tail(&["-n", "+10", &path]).1, "10\n11\n12\n");
+        assert_eq!(tail(&["-n+12", &path]).1, "12\n");
+        assert_eq!(tail(&["-n", "+1", &path]).1, TWELVE_LINES);
+        assert_eq!(tail(&["-n", "+14", &path]).1, "");
+    }
+
+    #[test]
+    fn plus_n_reads_the_standard_input_from_a_line() {
+        let (status, output) = run(TWELVE_LINES, |streams| {
+            execute(args(&["-n", "+11"]), streams)
+        });
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "11\n12\n");
+    }
+
+    #[test]
+    fn plus_c_starts_at_a_byte() {
+        let dir = TempDir::new();
+        let path = dir.write("file", "abcdef");
+
+        assert_eq!(tail(&["-c", "+3", &path]).1, "cdef");
+    }
+
+    #[test]
+    fn prints_a_short_file_whole() {
+        let dir = TempDir::new();
+        let path = dir.write("file", "a\nb\n");
+
+        assert_eq!(tail(&[&path]).1, "a\nb\n");
+    }
+
+    #[test]
+    fn keeps_a_last_line_without_newline() {
+        let dir = TempDir::new();
+        let path = dir.write("file", "a\nb\nc");
+
+        assert_eq!(tail(&["-n", "2", &path]).1, "b\nc");
+    }
+
+    #[test]
+    fn reads_files_longer_than_a_chunk_backwards() {
+        let dir = TempDir::new();
+        let content: String = (0..5000).map(|i| format!("{}\n", i)).collect();
+        let path = dir.write("file", &content);
+
+        assert_eq!(tail(&["-n", "3", &path]).1, "4997\n4998\n4999\n");
+    }
+
+    #[test]
+    fn c_counts_bytes() {
+        let dir = TempDir::new();
+        let path = dir.write("file", TWELVE_LINES);
+
+        assert_eq!(tail(&["-c", "4", &path]).1, "\n12\n");
+    }
+
+    #[test]
+    fn reads_the_standard_input() {
+        let (_, lines) = run(TWELVE_LINES, |streams| execute(args(&["-n", "1"]), streams));
+        let (_, bytes) = run(TWELVE_LINES, |streams| execute(args(&["-c", "3"]), streams));
+
+        assert_eq!(lines, "12\n");
+        assert_eq!(bytes, "12\n");
+    }
+
+    #[test]
+    fn missing_file_fails() {
+        let dir = TempDir::new();
+
+        assert_eq!(tail(&[&dir.join("missing")]).0, Status::FAILURE);
+    }
+
+    #[test]
+    fn f_stops_following_once_interrupted() {
+        let _lock = lock_process();
+        let dir = TempDir::new();
+        let path = dir.write("file", "a\n");
+
+        let follow =
+            thread::spawn(move || run("", |streams| execute(args(&["-f", &path]), streams)));
+        thread::sleep(FOLLOW_INTERVAL);
+        signals::interrupt();
+        let result = follow.join().unwrap();
+        signals::clear_interrupt();
+
+        assert_eq!(result, (Status::SUCCESS, String::from("a\n")));
+    }
+}
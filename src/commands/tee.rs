@@ -0,0 +1,152 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+};
+
+use super::{Status, Streams};
+
+/// Execute the `tee` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `tee` command.
+///
+/// It copies the standard input both to the standard output and to each file given as argument, which is
+/// created or truncated, or appended to with `-a`. A file that cannot be opened or written is reported
+/// and left out, while the copy carries on to the others.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `tee` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut append = false;
+    let mut paths = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            paths.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'a' => append = true,
+                _ => {
+                    eprintln!("tee : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    let mut status = Status::SUCCESS;
+    let mut files: Vec<(String, File)> = vec![];
+
+    for path in paths {
+        let file = if append {
+            OpenOptions::new().create(true).append(true).open(&path)
+        } else {
+            File::create(&path)
+        };
+
+        match file {
+            Ok(file) => files.push((path, file)),
+            Err(e) => {
+                handle_error(e, &path);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    loop {
+        let input = streams.stdin.fill_buf()?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        streams.stdout.write_all(input)?;
+
+        files.retain_mut(|(path, file)| match file.write_all(input) {
+            Ok(()) => true,
+            Err(e) => {
+                handle_error(e, path);
+                status = Status::FAILURE;
+                false
+            }
+        });
+
+        let length = input.len();
+        streams.stdin.consume(length);
+        // Flushes as it goes, so that the output of an interactive input shows up line by line.
+        streams.stdout.flush()?;
+    }
+
+    Ok(status)
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("tee : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("tee : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("tee : is a directory: {}", path),
+        _ => eprintln!("tee : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    fn tee(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn copies_the_input_to_the_output_and_every_file() {
+        let dir = TempDir::new();
+        let first = dir.write("first", "old content");
+        let second = dir.join("second");
+
+        assert_eq!(
+            tee(&[&first, &second], "a\nb\n"),
+            (Status::SUCCESS, String::from("a\nb\n"))
+        );
+        assert_eq!(fs::read_to_string(&first).unwrap(), "a\nb\n");
+        assert_eq!(fs::read_to_string(&second).unwrap(), "a\nb\n");
+    }
+
+    #[test]
+    fn a_appends_to_the_files() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "old\n");
+
+        tee(&["-a", &file], "new\n");
+
+        assert_eq!(fs::read_to_string(&file).unwrap(), "old\nnew\n");
+    }
+
+    #[test]
+    fn file_that_cannot_be_opened_is_left_out() {
+        let dir = TempDir::new();
+        let file = dir.join("file");
+
+        let (status, output) = tee(&[&dir.join("missing/file"), &file], "a\n");
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, "a\n"));
+        assert_eq!(fs::read_to_string(&file).unwrap(), "a\n");
+    }
+
+    #[test]
+    fn without_files_copies_to_the_output() {
+        assert_eq!(tee(&[], "a"), (Status::SUCCESS, String::from("a")));
+    }
+
+    #[test]
+    fn invalid_option_fails() {
+        assert_eq!(tee(&["-x"], "a"), (Status::FAILURE, String::new()));
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    fs::{File, FileTimes},
+    io,
+    time::SystemTime,
+};
+
+use crate::unix::time::{from_unix_seconds, DateTime};
+
+use super::Status;
+
+/// Execute the `touch` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `touch` command.
+///
+/// It sets the access and modification times of each file to the current time, creating the files that
+/// don't exist. With `-c`, missing files are not created. With `-t STAMP` or `-d DATE`, the times are set
+/// to the given timestamp instead of the current time.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `touch` command.
+pub fn execute(args: Vec<String>) -> io::Result<Status> {
+    let mut no_create = false;
+    let mut time = None;
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let parser = match arg.as_str() {
+            "-c" => {
+                no_create = true;
+                continue;
+            }
+            "-t" => parse_stamp,
+            "-d" => parse_date,
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("touch : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => {
+                paths.push(arg);
+                continue;
+            }
+        };
+
+        match args.next().map(|value| (parser(&value), value)) {
+            Some((Some(parsed), _)) => time = Some(parsed),
+            Some((None, value)) => {
+                eprintln!("touch : invalid date format: '{}'", value);
+
+                return Ok(Status::FAILURE);
+            }
+            None => {
+                eprintln!("touch : option requires an argument - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("touch : missing file operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let time = time.unwrap_or_else(SystemTime::now);
+    let mut status = Status::SUCCESS;
+
+    for path in paths {
+        if let Err(e) = touch(&path, no_create, time) {
+            handle_error(e, &path);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Sets the access and modification times of the file at `path` to `time`, creating the file if it
+/// doesn't exist, unless `no_create` is set.
+fn touch(path: &str, no_create: bool, time: SystemTime) -> io::Result<()> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound && no_create => return Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => File::options()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(path)?,
+        Err(e) => return Err(e),
+    };
+
+    file.set_times(FileTimes::new().set_accessed(time).set_modified(time))
+}
+
+/// Parses a timestamp in the `[[CC]YY]MMDDhhmm[.ss]` format of `touch -t`, in the local time zone. The
+/// current year is used when the year is omitted, and a two-digit year lies between 1969 and 2068.
+fn parse_stamp(stamp: &str) -> Option<SystemTime> {
+    let (digits, second) = match stamp.split_once('.') {
+        Some((digits, second)) if second.len() == 2 => (digits, number(second)?),
+        Some(_) => return None,
+        None => (stamp, 0),
+    };
+
+    let (year, rest) = match digits.len() {
+        8 => (DateTime::local(SystemTime::now())?.year, digits),
+        10 => {
+            let year = number(&digits[..2])? as i32;
+
+            (
+                if year < 69 { 2000 + year } else { 1900 + year },
+                &digits[2..],
+            )
+        }
+        12 => (number(&digits[..4])? as i32, &digits[4..]),
+        _ => return None,
+    };
+
+    date_time(
+        year,
+        number(&rest[..2])?,
+        number(&rest[2..4])?,
+        number(&rest[4..6])?,
+        number(&rest[6..8])?,
+        second,
+    )
+}
+
+/// Parses a date given to `touch -d`, either as `@SECONDS` since the Unix epoch, or as `YYYY-MM-DD`
+/// optionally followed by a time `HH:MM[:SS]`, separated by a space or a `T`, in the local time zone.
+fn parse_date(date: &str) -> Option<SystemTime> {
+    if let Some(seconds) = date.strip_prefix('@') {
+        return from_unix_seconds(seconds.parse().ok()?);
+    }
+
+    let (day, time) = match date.split_once([' ', 'T']) {
+        Some((day, time)) => (day, Some(time)),
+        None => (date, None),
+    };
+
+    let mut day = day.split('-');
+    let (year, month, day) = (day.next()?, day.next()?, day.next()?);
+
+    let (hour, minute, second) = match time.map(|time| time.split(':').collect::<Vec<&str>>()) {
+        None => (0, 0, 0),
+        Some(time) => match time[..] {
+            [hour, minute] => (number(hour)?, number(minute)?, 0),
+            [hour, minute, second] => (number(hour)?, number(minute)?, number(second)?),
+            _ => return None,
+        },
+    };
+
+    date_time(
+        year.parse().ok()?,
+        number(month)?,
+        number(day)?,
+        hour,
+        minute,
+        second,
+    )
+}
+
+/// Builds a local time from its components, checking that each one is within its range.
+fn date_time(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+) -> Option<SystemTime> {
+    let valid = (1..=12).contains(&month)
+        && (1..=31).contains(&day)
+        && hour < 24
+        && minute < 60
+        && second <= 60;
+
+    if !valid {
+        return None;
+    }
+
+    DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    }
+    .to_local_time()
+}
+
+/// Parses a string made only of ASCII digits.
+fn number(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("touch : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("touch : permission denied: {}", path),
+        _ => eprintln!("touch : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path, time::Duration};
+
+    use super::*;
+    use crate::testing::{args, TempDir};
+
+    fn modified(path: &str) -> SystemTime {
+        fs::metadata(path).unwrap().modified().unwrap()
+    }
+
+    #[test]
+    fn creates_missing_files() {
+        let dir = TempDir::new();
+        let file = dir.join("new");
+
+        assert_eq!(execute(args(&[&file])).unwrap(), Status::SUCCESS);
+        assert!(Path::new(&file).is_file());
+    }
+
+    #[test]
+    fn c_does_not_create_files() {
+        let dir = TempDir::new();
+        let file = dir.join("new");
+
+        assert_eq!(execute(args(&["-c", &file])).unwrap(), Status::SUCCESS);
+        assert!(!Path::new(&file).exists());
+    }
+
+    #[test]
+    fn updates_the_times_of_existing_files() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "content");
+        let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+
+        execute(args(&[&file])).unwrap();
+
+        assert!(modified(&file) > old);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "content");
+    }
+
+    #[test]
+    fn d_sets_the_given_time() {
+        let dir = TempDir::new();
+        let file = dir.join("file");
+
+        execute(args(&["-d", "@1000000000", &file])).unwrap();
+
+        assert_eq!(
+            modified(&file),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn t_and_d_agree_on_local_times() {
+        assert_eq!(
+            parse_stamp("202403151230.45"),
+            parse_date("2024-03-15 12:30:45")
+        );
+        assert_eq!(parse_stamp("2403151230"), parse_date("2024-03-15T12:30"));
+        assert!(parse_stamp("202403151230").is_some());
+    }
+
+    #[test]
+    fn rejects_invalid_times() {
+        assert_eq!(parse_stamp("202413151230"), None);
+        assert_eq!(parse_date("2024-02-30 25:00"), None);
+        assert_eq!(parse_date("yesterday"), None);
+        assert_eq!(
+            execute(args(&["-d", "yesterday", "file"])).unwrap(),
+            Status::FAILURE
+        );
+    }
+}
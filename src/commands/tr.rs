@@ -0,0 +1,295 @@
+use std::io;
+
+use super::{Status, Streams};
+
+/// Execute the `tr` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `tr` command.
+///
+/// It copies the standard input to the standard output, byte by byte, replacing the bytes of `SET1` by the
+/// bytes at the same positions in `SET2`, whose last byte is repeated when it is the shortest. With `-d`,
+/// the bytes of `SET1` are deleted instead. With `-s`, runs of a repeated byte of the last set given are
+/// squeezed into a single one, after the translation or deletion. Sets may hold ranges like `a-z`, the
+/// classes `[:lower:]`, `[:upper:]`, `[:alpha:]`, `[:digit:]`, `[:alnum:]` and `[:space:]`, and the escapes
+/// `\n`, `\t`, `\r`, `\\` and `\NNN` for a byte in octal.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `tr` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut delete = false;
+    let mut squeeze = false;
+    let mut sets = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            sets.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'd' => delete = true,
+                's' => squeeze = true,
+                _ => {
+                    eprintln!("tr : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    // Two sets are needed to translate, and to squeeze after deleting; a single one otherwise.
+    let expected = match (delete, squeeze) {
+        (true, false) => 1..=1,
+        (false, true) => 1..=2,
+        _ => 2..=2,
+    };
+
+    if sets.len() < *expected.start() {
+        eprintln!("tr : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    if let Some(extra) = sets.get(*expected.end()) {
+        eprintln!("tr : extra operand '{}'", extra);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut parsed = vec![];
+
+    for set in &sets {
+        match parse_set(set) {
+            Ok(bytes) => parsed.push(bytes),
+            Err(message) => {
+                eprintln!("tr : {}", message);
+
+                return Ok(Status::FAILURE);
+            }
+        }
+    }
+
+    let mut translation: [u8; 256] = std::array::from_fn(|byte| byte as u8);
+    let mut deleted = [false; 256];
+    let mut squeezed = [false; 256];
+
+    if delete {
+        for &byte in &parsed[0] {
+            deleted[byte as usize] = true;
+        }
+    } else if parsed.len() == 2 {
+        let Some(&last) = parsed[1].last() else {
+            eprintln!("tr : when translating, SET2 must not be empty");
+
+            return Ok(Status::FAILURE);
+        };
+
+        for (i, &byte) in parsed[0].iter().enumerate() {
+            translation[byte as usize] = parsed[1].get(i).copied().unwrap_or(last);
+        }
+    }
+
+    if squeeze {
+        for &byte in parsed.last().into_iter().flatten() {
+            squeezed[byte as usize] = true;
+        }
+    }
+
+    // The last byte written, to squeeze the runs spanning several reads.
+    let mut previous = None;
+    let mut output = vec![];
+
+    loop {
+        let input = streams.stdin.fill_buf()?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        output.clear();
+
+        for &byte in input {
+            if deleted[byte as usize] {
+                continue;
+            }
+
+            let byte = translation[byte as usize];
+
+            if squeezed[byte as usize] && previous == Some(byte) {
+                continue;
+            }
+
+            output.push(byte);
+            previous = Some(byte);
+        }
+
+        let length = input.len();
+        streams.stdin.consume(length);
+        streams.stdout.write_all(&output)?;
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Parses a set into the list of its bytes, in order, expanding its ranges, classes and escapes.
+fn parse_set(set: &str) -> Result<Vec<u8>, String> {
+    let bytes = set.as_bytes();
+    let mut parsed = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"[:") {
+            if let Some(length) = set[i + 2..].find(":]") {
+                let name = &set[i + 2..i + 2 + length];
+
+                let class: fn(&u8) -> bool = match name {
+                    "lower" => u8::is_ascii_lowercase,
+                    "upper" => u8::is_ascii_uppercase,
+                    "alpha" => u8::is_ascii_alphabetic,
+                    "digit" => u8::is_ascii_digit,
+                    "alnum" => u8::is_ascii_alphanumeric,
+                    "space" => |byte| byte.is_ascii_whitespace() || *byte == 0x0b,
+                    _ => return Err(format!("invalid character class '{}'", name)),
+                };
+
+                parsed.extend((0..=255u8).filter(class));
+                i += length + 4;
+                continue;
+            }
+        }
+
+        let (start, next) = read_byte(bytes, i);
+
+        // A `-` is a range when it lies between two bytes, and a literal `-` otherwise.
+        if bytes.get(next) == Some(&b'-') && next + 1 < bytes.len() {
+            let (end, after) = read_byte(bytes, next + 1);
+
+            if end < start {
+                return Err(format!(
+                    "range-endpoints of '{}' are in reverse order",
+                    &set[i..after]
+                ));
+            }
+
+            parsed.extend(start..=end);
+            i = after;
+        } else {
+            parsed.push(start);
+            i = next;
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Reads the byte at `i`, which may be escaped by a backslash, returning it along with the index of the
+/// next one.
+fn read_byte(bytes: &[u8], i: usize) -> (u8, usize) {
+    if bytes[i] != b'\\' || i + 1 == bytes.len() {
+        return (bytes[i], i + 1);
+    }
+
+    let digits = bytes[i + 1..]
+        .iter()
+        .take(3)
+        .take_while(|byte| (b'0'..=b'7').contains(byte))
+        .count();
+
+    if digits > 0 {
+        let value = bytes[i + 1..i + 1 + digits]
+            .iter()
+            .fold(0u8, |value, digit| value.wrapping_mul(8) + (digit - b'0'));
+
+        return (value, i + 1 + digits);
+    }
+
+    let byte = match bytes[i + 1] {
+        b'n' => b'\n',
+        b't' => b'\t',
+        b'r' => b'\r',
+        other => other,
+    };
+
+    (byte, i + 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run};
+
+    fn tr(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn converts_case_with_ranges() {
+        assert_eq!(
+            tr(&["a-z", "A-Z"], "Hello, World!\n"),
+            (Status::SUCCESS, String::from("HELLO, WORLD!\n"))
+        );
+        assert_eq!(tr(&["[:upper:]", "[:lower:]"], "ABC def\n").1, "abc def\n");
+    }
+
+    #[test]
+    fn repeats_the_last_byte_of_a_shorter_set2() {
+        assert_eq!(tr(&["abc", "x"], "aabbcd").1, "xxxxxd");
+        assert_eq!(tr(&["ab", "xyz"], "ab").1, "xy");
+    }
+
+    #[test]
+    fn d_deletes_set1() {
+        assert_eq!(tr(&["-d", "aeiou"], "education\n").1, "dctn\n");
+        assert_eq!(tr(&["-d", "[:digit:]\\n"], "a1b2\nc3\n").1, "abc");
+    }
+
+    #[test]
+    fn s_squeezes_repeats() {
+        assert_eq!(tr(&["-s", " "], "a   b  c d\n").1, "a b c d\n");
+        assert_eq!(tr(&["-s", "a-z", "A-Z"], "aabbcc  dd\n").1, "ABC  D\n");
+        assert_eq!(tr(&["-ds", "x", "a"], "axaxb\n").1, "ab\n");
+    }
+
+    #[test]
+    fn parses_escapes_and_literal_dashes() {
+        assert_eq!(parse_set("a-c").unwrap(), b"abc");
+        assert_eq!(parse_set("-a-").unwrap(), b"-a-");
+        assert_eq!(parse_set("\\n\\t\\\\\\101").unwrap(), b"\n\t\\A");
+        assert_eq!(parse_set("[:digit:]").unwrap(), b"0123456789");
+        assert_eq!(parse_set("[:x").unwrap(), b"[:x");
+    }
+
+    #[test]
+    fn works_on_bytes() {
+        let mut stdin = io::Cursor::new(vec![0xff, b'a', 0xfe]);
+        let mut stdout = vec![];
+        let mut streams = Streams {
+            stdin: &mut stdin,
+            stdout: &mut stdout,
+            terminal: false,
+            shell_stdin: false,
+        };
+
+        execute(args(&["\\377a", "xy"]), &mut streams).unwrap();
+
+        assert_eq!(stdout, [b'x', b'y', 0xfe]);
+    }
+
+    #[test]
+    fn invalid_sets_fail() {
+        for arguments in [
+            &["z-a", "x"][..],
+            &["[:nope:]", "x"],
+            &["a", ""],
+            &["a"],
+            &["-d", "a", "b"],
+            &["-x", "a", "b"],
+        ] {
+            assert_eq!(tr(arguments, "abc"), (Status::FAILURE, String::new()));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::io;
+
+use super::Status;
+
+/// Execute the `true` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `true` command.
+///
+/// It does nothing and succeeds. Like other implementations, it ignores its arguments.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `true` command.
+pub fn execute(_args: Vec<String>) -> io::Result<Status> {
+    Ok(Status::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::args;
+
+    #[test]
+    fn ignores_its_arguments() {
+        assert_eq!(execute(args(&[])).unwrap(), Status::SUCCESS);
+        assert_eq!(execute(args(&["--help", "x"])).unwrap(), Status::SUCCESS);
+    }
+}
@@ -0,0 +1,88 @@
+use std::io;
+
+use super::{alias::Aliases, Status};
+
+/// Execute the `unalias` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `unalias` command.
+///
+/// It removes each alias given as argument. With `-a`, every alias is removed.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `unalias` command.
+/// * `aliases` - The aliases defined in the shell.
+pub fn execute(args: Vec<String>, aliases: &mut Aliases) -> io::Result<Status> {
+    if args.first().is_some_and(|arg| arg == "-a") {
+        aliases.clear();
+
+        return Ok(Status::SUCCESS);
+    }
+
+    if args.is_empty() {
+        eprintln!("unalias : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let mut status = Status::SUCCESS;
+
+    for name in args {
+        if !aliases.remove(&name) {
+            eprintln!("unalias : not found: {}", name);
+            status = Status::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        commands::alias,
+        testing::{args, run},
+    };
+
+    fn aliases(definitions: &[&str]) -> Aliases {
+        let mut aliases = Aliases::default();
+        run("", |streams| {
+            alias::execute(args(definitions), &mut aliases, streams)
+        });
+
+        aliases
+    }
+
+    #[test]
+    fn removes_the_given_aliases() {
+        let mut aliases = aliases(&["ll=ls -l", "la=ls -A"]);
+
+        assert_eq!(
+            execute(args(&["ll"]), &mut aliases).unwrap(),
+            Status::SUCCESS
+        );
+        assert_eq!(aliases.names().collect::<Vec<_>>(), ["la"]);
+        assert_eq!(aliases.expand("ll"), "ll");
+    }
+
+    #[test]
+    fn unknown_alias_fails() {
+        let mut aliases = aliases(&["ll=ls -l"]);
+
+        assert_eq!(
+            execute(args(&["la", "ll"]), &mut aliases).unwrap(),
+            Status::FAILURE
+        );
+        assert_eq!(aliases.names().count(), 0);
+    }
+
+    #[test]
+    fn a_removes_every_alias() {
+        let mut aliases = aliases(&["ll=ls -l", "la=ls -A"]);
+
+        execute(args(&["-a"]), &mut aliases).unwrap();
+
+        assert_eq!(aliases.names().count(), 0);
+    }
+}
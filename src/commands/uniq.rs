@@ -0,0 +1,218 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+};
+
+use super::{Status, Streams};
+
+/// Which groups of adjacent equal lines `uniq` prints, and how.
+struct Options {
+    count: bool,
+    /// Whether only the lines repeated at least once are printed, with `-d`.
+    repeated: bool,
+    /// Whether only the lines not repeated are printed, with `-u`.
+    unique: bool,
+    ignore_case: bool,
+}
+
+/// Execute the `uniq` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `uniq` command.
+///
+/// It prints the lines of the file given as argument, or of the standard input, printing adjacent equal
+/// lines only once. Since only adjacent lines are compared, the input is usually sorted first, as in
+/// `sort file | uniq`. With `-c`, each line is preceded by the number of times it was repeated. `-d` only
+/// prints the repeated lines, `-u` only the lines that aren't, and `-i` ignores case when comparing.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `uniq` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut options = Options {
+        count: false,
+        repeated: false,
+        unique: false,
+        ignore_case: false,
+    };
+    let mut paths = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            paths.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'c' => options.count = true,
+                'd' => options.repeated = true,
+                'u' => options.unique = true,
+                'i' => options.ignore_case = true,
+                _ => {
+                    eprintln!("uniq : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    let reader: io::Result<Box<dyn BufRead>> = match paths.as_slice() {
+        [] => Ok(Box::new(&mut *streams.stdin)),
+        [path] if path == "-" => Ok(Box::new(&mut *streams.stdin)),
+        [path] => File::open(path).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>),
+        [_, extra, ..] => {
+            eprintln!("uniq : extra operand '{}'", extra);
+
+            return Ok(Status::FAILURE);
+        }
+    };
+
+    let path = paths.first().map_or("-", String::as_str);
+    let result = reader.and_then(|mut reader| uniq(&mut reader, streams.stdout, &options));
+
+    if let Err(e) = result {
+        if e.kind() == io::ErrorKind::BrokenPipe {
+            return Err(e);
+        }
+
+        handle_error(e, path);
+
+        return Ok(Status::FAILURE);
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+/// Copies the lines of `reader` to `writer`, collapsing the runs of adjacent equal lines.
+fn uniq(reader: &mut dyn BufRead, writer: &mut dyn Write, options: &Options) -> io::Result<()> {
+    // The first line of the current run, without its newline, and the length of the run.
+    let mut current: Option<(Vec<u8>, u64)> = None;
+    let mut line = vec![];
+
+    loop {
+        line.clear();
+
+        let end = reader.read_until(b'\n', &mut line)? == 0;
+
+        if line.ends_with(b"\n") {
+            line.pop();
+        }
+
+        match &mut current {
+            Some((first, count)) if !end && equal(first, &line, options.ignore_case) => *count += 1,
+            _ => {
+                if let Some((first, count)) = current.take() {
+                    print_run(&first, count, writer, options)?;
+                }
+
+                if end {
+                    return Ok(());
+                }
+
+                current = Some((line.clone(), 1));
+            }
+        }
+    }
+}
+
+fn equal(a: &[u8], b: &[u8], ignore_case: bool) -> bool {
+    if ignore_case {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+/// Prints the first line of a run of `count` equal lines, unless `-d` or `-u` leaves it out.
+fn print_run(line: &[u8], count: u64, writer: &mut dyn Write, options: &Options) -> io::Result<()> {
+    if (options.repeated && count == 1) || (options.unique && count > 1) {
+        return Ok(());
+    }
+
+    if options.count {
+        write!(writer, "{:>7} ", count)?;
+    }
+
+    writer.write_all(line)?;
+    writer.write_all(b"\n")
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("uniq : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("uniq : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("uniq : is a directory: {}", path),
+        _ => eprintln!("uniq : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    const INPUT: &str = "a\na\nb\nc\nc\nc\na\n";
+
+    fn uniq(arguments: &[&str], input: &str) -> (Status, String) {
+        run(input, |streams| execute(args(arguments), streams))
+    }
+
+    #[test]
+    fn collapses_adjacent_equal_lines_only() {
+        assert_eq!(
+            uniq(&[], INPUT),
+            (Status::SUCCESS, String::from("a\nb\nc\na\n"))
+        );
+        assert_eq!(uniq(&[], "a\na").1, "a\n");
+        assert_eq!(uniq(&[], "").1, "");
+    }
+
+    #[test]
+    fn c_prefixes_the_counts() {
+        assert_eq!(
+            uniq(&["-c"], INPUT).1,
+            "      2 a\n      1 b\n      3 c\n      1 a\n"
+        );
+    }
+
+    #[test]
+    fn d_prints_only_repeated_lines() {
+        assert_eq!(uniq(&["-d"], INPUT).1, "a\nc\n");
+        assert_eq!(uniq(&["-dc"], INPUT).1, "      2 a\n      3 c\n");
+    }
+
+    #[test]
+    fn u_prints_only_lines_not_repeated() {
+        assert_eq!(uniq(&["-u"], INPUT).1, "b\na\n");
+        assert_eq!(uniq(&["-du"], INPUT).1, "");
+    }
+
+    #[test]
+    fn i_ignores_case_and_keeps_the_first_line() {
+        assert_eq!(
+            uniq(&["-i"], "Hello\nhello\nHELLO\nworld\n").1,
+            "Hello\nworld\n"
+        );
+        assert_eq!(uniq(&[], "Hello\nhello\n").1, "Hello\nhello\n");
+    }
+
+    #[test]
+    fn reads_a_file() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "x\nx\ny\n");
+
+        assert_eq!(uniq(&[&file], "ignored\n").1, "x\ny\n");
+        assert_eq!(uniq(&["-"], "z\nz\n").1, "z\n");
+    }
+
+    #[test]
+    fn invalid_usage_fails() {
+        let dir = TempDir::new();
+
+        for arguments in [&["-x"][..], &["a", "b"], &[&dir.join("missing")]] {
+            assert_eq!(uniq(arguments, INPUT), (Status::FAILURE, String::new()));
+        }
+    }
+}
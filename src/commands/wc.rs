@@ -0,0 +1,262 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+};
+
+use super::{Status, Streams};
+
+/// The counts of an input, in the order they are printed.
+#[derive(Default, Clone, Copy)]
+struct Counts {
+    lines: u64,
+    words: u64,
+    chars: u64,
+    bytes: u64,
+}
+
+impl Counts {
+    fn add(&mut self, other: &Counts) {
+        self.lines += other.lines;
+        self.words += other.words;
+        self.chars += other.chars;
+        self.bytes += other.bytes;
+    }
+
+    /// Returns the counts selected by the options, in the order they are printed.
+    fn selected(&self, options: &str) -> Vec<u64> {
+        [
+            ('l', self.lines),
+            ('w', self.words),
+            ('m', self.chars),
+            ('c', self.bytes),
+        ]
+        .into_iter()
+        .filter(|(option, _)| options.contains(*option))
+        .map(|(_, count)| count)
+        .collect()
+    }
+}
+
+/// Execute the `wc` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `wc` command.
+///
+/// It prints the number of lines, words and bytes of each file, followed by a total line when several
+/// files are given. The columns can be restricted with `-l` (lines), `-w` (words), `-c` (bytes) and `-m`
+/// (characters). The argument `-` stands for the standard input, which is also read when no file is given.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `wc` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let mut options = String::new();
+    let mut paths = vec![];
+
+    for arg in args {
+        match arg.strip_prefix('-') {
+            Some(letters) if !letters.is_empty() => options.push_str(letters),
+            _ => paths.push(arg),
+        }
+    }
+
+    if let Some(wrong_option) = options.chars().find(|c| !"lwcm".contains(*c)) {
+        eprintln!("wc : invalid option - '{}'", wrong_option);
+
+        return Ok(Status::FAILURE);
+    }
+
+    if options.is_empty() {
+        options = String::from("lwc");
+    }
+
+    let from_stdin = paths.is_empty();
+    let mut rows = vec![];
+    let mut total = Counts::default();
+    let mut status = Status::SUCCESS;
+
+    for path in paths.iter() {
+        let counts = if path == "-" {
+            count(&mut *streams.stdin)
+        } else {
+            File::open(path).and_then(count)
+        };
+
+        match counts {
+            Ok(counts) => {
+                total.add(&counts);
+                rows.push((counts, path.as_str()));
+            }
+            Err(e) => {
+                handle_error(e, path);
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    if from_stdin {
+        match count(&mut *streams.stdin) {
+            Ok(counts) => rows.push((counts, "")),
+            Err(e) => {
+                handle_error(e, "-");
+                status = Status::FAILURE;
+            }
+        }
+    }
+
+    if rows.len() > 1 {
+        rows.push((total, "total"));
+    }
+
+    let width = column_width(
+        &rows,
+        &options,
+        from_stdin || paths.iter().any(|p| p == "-"),
+    );
+
+    for (counts, name) in rows {
+        let columns: Vec<String> = counts
+            .selected(&options)
+            .iter()
+            .map(|count| format!("{:>width$}", count))
+            .collect();
+
+        if name.is_empty() {
+            writeln!(streams.stdout, "{}", columns.join(" "))?;
+        } else {
+            writeln!(streams.stdout, "{} {}", columns.join(" "), name)?;
+        }
+    }
+
+    Ok(status)
+}
+
+/// Returns the width of the numeric columns, wide enough for the largest count. A single count of a
+/// single input is not padded, while the standard input, whose size is not known beforehand, gets at
+/// least seven digits like coreutils.
+fn column_width(rows: &[(Counts, &str)], options: &str, from_stdin: bool) -> usize {
+    let columns: Vec<u64> = rows
+        .iter()
+        .flat_map(|(counts, _)| counts.selected(options))
+        .collect();
+
+    if columns.len() == 1 {
+        return 1;
+    }
+
+    let largest = columns.into_iter().max().unwrap_or(0);
+
+    let digits = largest.to_string().len();
+
+    if from_stdin {
+        digits.max(7)
+    } else {
+        digits
+    }
+}
+
+/// Counts the lines, words, characters and bytes of `reader`, reading it by chunks.
+///
+/// Words are sequences of non-whitespace bytes, and characters are counted as UTF-8 sequences, by
+/// skipping continuation bytes.
+fn count(mut reader: impl Read) -> io::Result<Counts> {
+    let mut counts = Counts::default();
+    let mut buffer = [0; 8192];
+    let mut in_word = false;
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        for &byte in &buffer[..read] {
+            if byte == b'\n' {
+                counts.lines += 1;
+            }
+
+            if byte.is_ascii_whitespace() {
+                in_word = false;
+            } else if !in_word {
+                in_word = true;
+                counts.words += 1;
+            }
+
+            if byte & 0xC0 != 0x80 {
+                counts.chars += 1;
+            }
+        }
+
+        counts.bytes += read as u64;
+    }
+
+    Ok(counts)
+}
+
+fn handle_error(error: io::Error, path: &str) {
+    match error.kind() {
+        io::ErrorKind::NotFound => eprintln!("wc : no such file or directory: {}", path),
+        io::ErrorKind::PermissionDenied => eprintln!("wc : permission denied: {}", path),
+        io::ErrorKind::IsADirectory => eprintln!("wc : is a directory: {}", path),
+        _ => eprintln!("wc : {}: {}", path, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    const MIXED: &str = "héllo  world\n\tone\ntwo three four\n";
+
+    #[test]
+    fn counts_lines_words_and_bytes_of_a_file() {
+        let dir = TempDir::new();
+        let path = dir.write("file", MIXED);
+
+        let (status, output) = run("", |streams| execute(args(&[&path]), streams));
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, format!(" 3  6 34 {}\n", path));
+    }
+
+    #[test]
+    fn l_prints_only_the_lines() {
+        let dir = TempDir::new();
+        let path = dir.write("file", MIXED);
+
+        let (_, output) = run("", |streams| execute(args(&["-l", &path]), streams));
+
+        assert_eq!(output, format!("3 {}\n", path));
+    }
+
+    #[test]
+    fn m_counts_characters_rather_than_bytes() {
+        let (_, output) = run(MIXED, |streams| execute(args(&["-mc"]), streams));
+
+        assert_eq!(output, "     33      34\n");
+    }
+
+    #[test]
+    fn several_files_get_a_total() {
+        let dir = TempDir::new();
+        let first = dir.write("first", "a b\n");
+        let second = dir.write("second", "c\nd\n");
+
+        let (_, output) = run("", |streams| execute(args(&[&first, &second]), streams));
+
+        assert_eq!(
+            output,
+            format!("1 2 4 {}\n2 2 4 {}\n3 4 8 total\n", first, second)
+        );
+    }
+
+    #[test]
+    fn invalid_option_fails() {
+        let (status, _) = run("", |streams| execute(args(&["-x"]), streams));
+
+        assert_eq!(status, Status::FAILURE);
+    }
+}
@@ -0,0 +1,201 @@
+use std::{io, path::Path};
+
+use crate::unix::path::{find_executables, is_executable};
+
+use super::{env::Environment, CommandRegistry, Status, Streams};
+
+/// Execute the `which` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `which` command.
+///
+/// It tells, for each name given as argument, what would run under that name: a command of the shell, or
+/// else the first executable file of that name in the directories of `$PATH`, whose path is printed. A
+/// name holding a `/` is a path, printed if it is executable. With `-a`, every match is printed, the
+/// command of the shell first. The status is 1 when a name matches nothing.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `which` command.
+/// * `registry` - The commands of the shell.
+/// * `environment` - The environment holding the `$PATH` the programs are looked up in.
+/// * `streams` - The standard streams of the command.
+pub fn execute(
+    args: Vec<String>,
+    registry: &CommandRegistry,
+    environment: &Environment,
+    streams: &mut Streams,
+) -> io::Result<Status> {
+    let mut all = false;
+    let mut names = vec![];
+
+    for arg in args {
+        let Some(letters) = arg.strip_prefix('-').filter(|letters| !letters.is_empty()) else {
+            names.push(arg);
+            continue;
+        };
+
+        for letter in letters.chars() {
+            match letter {
+                'a' => all = true,
+                _ => {
+                    eprintln!("which : invalid option - '{}'", letter);
+
+                    return Ok(Status::FAILURE);
+                }
+            }
+        }
+    }
+
+    if names.is_empty() {
+        eprintln!("which : missing operand");
+
+        return Ok(Status::FAILURE);
+    }
+
+    let path = environment.var_os("PATH").unwrap_or_default();
+    let mut status = Status::SUCCESS;
+
+    for name in names {
+        let mut matches = vec![];
+
+        if registry.get(&name).is_some() {
+            matches.push(format!("{}: shell builtin command", name));
+        }
+
+        if name.contains('/') {
+            if is_executable(Path::new(&name)) {
+                matches.push(name.clone());
+            }
+        } else if all || matches.is_empty() {
+            matches.extend(
+                find_executables(&name, &path)
+                    .into_iter()
+                    .map(|path| path.display().to_string()),
+            );
+        }
+
+        if matches.is_empty() {
+            eprintln!("which : no {} in PATH", name);
+            status = Status::FAILURE;
+        }
+
+        let printed = if all { matches.len() } else { 1 };
+
+        for line in matches.iter().take(printed) {
+            writeln!(streams.stdout, "{}", line)?;
+        }
+    }
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, os::unix::fs::PermissionsExt};
+
+    use super::*;
+    use crate::testing::{args, run, TempDir};
+
+    /// The name of the programs created for the tests, which no directory of `$PATH` should hold.
+    const PROGRAM: &str = "core-utils-test-program";
+
+    /// Creates an executable program named [`PROGRAM`] in `dir`, returning its path.
+    fn program(dir: &TempDir) -> String {
+        let path = dir.write(PROGRAM, "#!/bin/sh\n");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        path
+    }
+
+    /// Runs `which` with the given directories put in front of `$PATH`.
+    fn which(directories: &[&TempDir], arguments: &[&str]) -> (Status, String) {
+        let previous = env::var_os("PATH").unwrap_or_default();
+        let path = env::join_paths(
+            directories
+                .iter()
+                .map(|dir| dir.path().to_path_buf())
+                .chain(env::split_paths(&previous)),
+        )
+        .unwrap();
+        let mut environment = Environment::default();
+        environment.set("PATH", path.to_str().unwrap());
+
+        run("", |streams| {
+            execute(
+                args(arguments),
+                &CommandRegistry::default(),
+                &environment,
+                streams,
+            )
+        })
+    }
+
+    #[test]
+    fn builtin_is_reported_as_such() {
+        assert_eq!(
+            which(&[], &["echo"]),
+            (
+                Status::SUCCESS,
+                String::from("echo: shell builtin command\n")
+            )
+        );
+    }
+
+    #[test]
+    fn finds_the_first_program_in_path() {
+        let first = TempDir::new();
+        let second = TempDir::new();
+        let expected = program(&first);
+        program(&second);
+
+        assert_eq!(
+            which(&[&first, &second], &[PROGRAM]),
+            (Status::SUCCESS, format!("{}\n", expected))
+        );
+    }
+
+    #[test]
+    fn a_prints_every_match() {
+        let first = TempDir::new();
+        let second = TempDir::new();
+        let programs = [program(&first), program(&second)];
+
+        let (status, output) = which(&[&first, &second], &["-a", PROGRAM]);
+
+        assert_eq!(
+            (status, output),
+            (Status::SUCCESS, format!("{}\n", programs.join("\n")))
+        );
+    }
+
+    #[test]
+    fn file_without_execute_permission_is_skipped() {
+        let dir = TempDir::new();
+        dir.write(PROGRAM, "");
+
+        assert_eq!(which(&[&dir], &[PROGRAM]), (Status::FAILURE, String::new()));
+    }
+
+    #[test]
+    fn path_is_printed_if_executable() {
+        let dir = TempDir::new();
+        let path = program(&dir);
+        let other = dir.write("other", "");
+
+        let (status, output) = which(&[], &[&path, &other]);
+
+        assert_eq!((status, output), (Status::FAILURE, format!("{}\n", path)));
+    }
+
+    #[test]
+    fn missing_name_fails_but_the_others_are_printed() {
+        let (status, output) = which(&[], &["no-such-program-anywhere", "cd"]);
+
+        assert_eq!(
+            (status, output.as_str()),
+            (Status::FAILURE, "cd: shell builtin command\n")
+        );
+        assert_eq!(which(&[], &[]), (Status::FAILURE, String::new()));
+        assert_eq!(which(&[], &["-x", "ls"]), (Status::FAILURE, String::new()));
+    }
+}
@@ -0,0 +1,85 @@
+use std::{env, io};
+
+use crate::unix::users::{effective_uid, lookup_user};
+
+use super::{Status, Streams};
+
+/// Execute the `whoami` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `whoami` command.
+///
+/// It prints the name of the user the shell runs as, from its effective uid. When the uid has no name in
+/// `/etc/passwd`, the `USER` environment variable is printed instead, if it is set.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `whoami` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    if let Some(extra) = args.first() {
+        eprintln!("whoami : extra operand '{}'", extra);
+
+        return Ok(Status::FAILURE);
+    }
+
+    let uid = effective_uid();
+
+    match lookup_user(uid).or_else(|| env::var("USER").ok()) {
+        Some(name) => writeln!(streams.stdout, "{}", name)?,
+        None => {
+            eprintln!("whoami : cannot find name for user ID {}", uid);
+
+            return Ok(Status::FAILURE);
+        }
+    }
+
+    Ok(Status::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{args, lock_process, run};
+
+    #[test]
+    fn prints_the_name_of_the_effective_user() {
+        let _lock = lock_process();
+
+        let (status, output) = run("", |streams| execute(args(&[]), streams));
+
+        assert_eq!(status, Status::SUCCESS);
+        assert!(!output.trim().is_empty());
+        assert_eq!(output.lines().count(), 1);
+
+        match (lookup_user(effective_uid()), env::var("USER")) {
+            (Some(name), _) => assert_eq!(output, format!("{}\n", name)),
+            (None, Ok(user)) => assert_eq!(output, format!("{}\n", user)),
+            (None, Err(_)) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn output_matches_user_when_set() {
+        let _lock = lock_process();
+        let Some(name) = lookup_user(effective_uid()) else {
+            return;
+        };
+        let previous = env::var_os("USER");
+        env::set_var("USER", &name);
+
+        let (status, output) = run("", |streams| execute(args(&[]), streams));
+
+        match previous {
+            Some(previous) => env::set_var("USER", previous),
+            None => env::remove_var("USER"),
+        }
+        assert_eq!((status, output), (Status::SUCCESS, format!("{}\n", name)));
+    }
+
+    #[test]
+    fn operand_fails() {
+        let (status, output) = run("", |streams| execute(args(&["root"]), streams));
+
+        assert_eq!((status, output.as_str()), (Status::FAILURE, ""));
+    }
+}
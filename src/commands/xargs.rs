@@ -0,0 +1,237 @@
+use std::io;
+
+use super::{Runner, Status, Streams};
+
+/// The status of `xargs` when one of the commands it ran failed, as with GNU xargs.
+const COMMAND_FAILED: Status = Status {
+    code: 123,
+    exit: false,
+};
+
+/// Execute the `xargs` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `xargs` command.
+///
+/// It reads words separated by blanks and newlines from the standard input, and runs the command given
+/// as arguments, `echo` by default, with the words appended to its arguments. With `-n N`, the command is
+/// run once for each group of at most `N` words. With `-I REPLACE`, the command is run once for each line
+/// of the input instead, with `REPLACE` in its arguments replaced by the line. Without input, the command
+/// runs once without additional arguments, except with `-I`.
+///
+/// Like with `env`, the command is dispatched through the commands of the shell, by `run`. It gets an
+/// empty standard input, since the one of `xargs` is consumed. The status is 123 if one of the runs
+/// failed, and 0 otherwise.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `xargs` command.
+/// * `streams` - The standard streams of the command.
+/// * `run` - Runs a command of the shell with its arguments.
+pub fn execute(args: Vec<String>, streams: &mut Streams, run: &mut Runner) -> io::Result<Status> {
+    let mut batch_size = None;
+    let mut replace = None;
+    let mut args = args.into_iter();
+    let mut command = vec![];
+
+    while let Some(arg) = args.next() {
+        let option = match arg.as_str() {
+            _ if arg.starts_with("-n") || arg.starts_with("-I") => arg[1..2].to_string(),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("xargs : invalid option - '{}'", arg);
+
+                return Ok(Status::FAILURE);
+            }
+            _ => {
+                // The first operand starts the command, whose arguments may look like options.
+                command.push(arg);
+                command.extend(args.by_ref());
+                break;
+            }
+        };
+
+        let value = match &arg[2..] {
+            "" => args.next(),
+            value => Some(value.to_string()),
+        };
+
+        let Some(value) = value else {
+            eprintln!("xargs : option requires an argument - '{}'", option);
+
+            return Ok(Status::FAILURE);
+        };
+
+        if option == "I" {
+            replace = Some(value);
+            continue;
+        }
+
+        match value.parse() {
+            Ok(size) if size > 0 => batch_size = Some(size),
+            _ => {
+                eprintln!("xargs : invalid number for -n: '{}'", value);
+
+                return Ok(Status::FAILURE);
+            }
+        }
+    }
+
+    if command.is_empty() {
+        command.push(String::from("echo"));
+    }
+
+    let mut input = String::new();
+    streams.stdin.read_to_string(&mut input)?;
+
+    let runs: Vec<Vec<String>> = match &replace {
+        Some(replace) => input
+            .lines()
+            .map(str::trim_start)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                command
+                    .iter()
+                    .map(|arg| arg.replace(replace.as_str(), line))
+                    .collect()
+            })
+            .collect(),
+        None => {
+            let words: Vec<&str> = input.split_whitespace().collect();
+            let batches: Vec<&[&str]> = match batch_size {
+                _ if words.is_empty() => vec![&[]],
+                Some(size) => words.chunks(size).collect(),
+                None => vec![&words],
+            };
+
+            batches
+                .into_iter()
+                .map(|batch| {
+                    let words = batch.iter().map(|word| word.to_string());
+
+                    command.iter().cloned().chain(words).collect()
+                })
+                .collect()
+        }
+    };
+
+    let mut status = Status::SUCCESS;
+
+    for mut run_args in runs {
+        let command_name = run_args.remove(0);
+        let mut stdin = io::empty();
+        let mut command_streams = Streams {
+            stdin: &mut stdin,
+            stdout: &mut *streams.stdout,
+            terminal: streams.terminal,
+            shell_stdin: false,
+        };
+
+        if run(command_name, run_args, &mut command_streams)?.code != 0 {
+            status = COMMAND_FAILED;
+        }
+    }
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        commands::{execute_command, ShellState},
+        testing::{args, run},
+    };
+
+    /// Runs `xargs` reading `input`, recording each command it runs instead of running it, and failing the
+    /// runs of `false`.
+    fn runs(arguments: &[&str], input: &str) -> (Status, Vec<Vec<String>>) {
+        let mut runs = vec![];
+        let (status, _) = run(input, |streams| {
+            execute(args(arguments), streams, &mut |command_name, args, _| {
+                let failed = command_name == "false";
+                runs.push([vec![command_name], args].concat());
+
+                Ok(if failed {
+                    Status::FAILURE
+                } else {
+                    Status::SUCCESS
+                })
+            })
+        });
+
+        (status, runs)
+    }
+
+    #[test]
+    fn appends_the_words_of_the_input_to_the_command() {
+        assert_eq!(
+            runs(&["rm", "-f"], "a b\n  c\n"),
+            (Status::SUCCESS, vec![args(&["rm", "-f", "a", "b", "c"])])
+        );
+        assert_eq!(runs(&[], "a\n").1, [args(&["echo", "a"])]);
+    }
+
+    #[test]
+    fn n_runs_the_command_for_each_batch() {
+        assert_eq!(
+            runs(&["-n", "2", "echo"], "a b c d e").1,
+            [
+                args(&["echo", "a", "b"]),
+                args(&["echo", "c", "d"]),
+                args(&["echo", "e"])
+            ]
+        );
+        assert_eq!(runs(&["-n1"], "a b").1.len(), 2);
+    }
+
+    #[test]
+    fn i_replaces_the_placeholder_with_each_line() {
+        assert_eq!(
+            runs(&["-I", "{}", "mv", "{}", "{}.bak"], "a b\n\nc\n").1,
+            [args(&["mv", "a b", "a b.bak"]), args(&["mv", "c", "c.bak"])]
+        );
+        assert!(runs(&["-I{}", "echo"], "").1.is_empty());
+    }
+
+    #[test]
+    fn runs_once_without_input() {
+        assert_eq!(runs(&["echo", "x"], " \n").1, [args(&["echo", "x"])]);
+    }
+
+    #[test]
+    fn command_options_are_not_taken_by_xargs() {
+        assert_eq!(
+            runs(&["ls", "-n", "-x"], "a").1,
+            [args(&["ls", "-n", "-x", "a"])]
+        );
+    }
+
+    #[test]
+    fn failed_run_has_status_123() {
+        assert_eq!(runs(&["-n1", "false"], "a b").0, COMMAND_FAILED);
+    }
+
+    #[test]
+    fn pipes_file_names_into_echo() {
+        let mut state = ShellState::default();
+
+        let (status, output) = run("file1.txt\nfile2.txt  file3.txt\n", |streams| {
+            execute(
+                args(&["echo"]),
+                streams,
+                &mut |command_name, args, streams| {
+                    execute_command(command_name, args, &mut state, streams)
+                },
+            )
+        });
+
+        assert_eq!(status, Status::SUCCESS);
+        assert_eq!(output, "file1.txt file2.txt file3.txt\n");
+    }
+
+    #[test]
+    fn invalid_options_fail() {
+        for arguments in [&["-n", "0"][..], &["-n"], &["-I"], &["-x"]] {
+            assert_eq!(runs(arguments, "a"), (Status::FAILURE, vec![]));
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::io;
+
+use super::{Status, Streams};
+
+/// The size of the batches of lines written at once, rather than one line at a time.
+const BATCH_SIZE: usize = 8192;
+
+/// Execute the `yes` command with the provided arguments.
+///
+/// This function takes a vector of strings `args` representing the arguments passed to the `yes` command.
+///
+/// It prints its arguments separated by spaces, or `y` when there are none, over and over until the
+/// standard output is closed. The broken pipe then ends it like any other command, which is how it
+/// normally stops.
+///
+/// # Arguments
+///
+/// * `args` - A vector of strings representing the arguments for the `yes` command.
+/// * `streams` - The standard streams of the command.
+pub fn execute(args: Vec<String>, streams: &mut Streams) -> io::Result<Status> {
+    let line = if args.is_empty() {
+        String::from("y\n")
+    } else {
+        format!("{}\n", args.join(" "))
+    };
+
+    let batch = line.repeat((BATCH_SIZE / line.len()).max(1));
+
+    loop {
+        streams.stdout.write_all(batch.as_bytes())?;
+        streams.stdout.flush()?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, Cursor},
+        thread,
+    };
+
+    use super::*;
+    use crate::{
+        pipe::pipe,
+        testing::{args, lock_process},
+    };
+
+    #[test]
+    fn stops_with_a_broken_pipe_once_the_reader_is_dropped() {
+        let _lock = lock_process();
+        let (mut writer, mut reader) = pipe();
+
+        let yes = thread::spawn(move || {
+            let mut stdin = Cursor::new(vec![]);
+            let mut streams = Streams {
+                stdin: &mut stdin,
+                stdout: &mut writer,
+                terminal: false,
+                shell_stdin: false,
+            };
+
+            execute(args(&["a", "b"]), &mut streams)
+        });
+
+        let mut lines = vec![String::new(); 3];
+
+        for line in lines.iter_mut() {
+            reader.read_line(line).unwrap();
+        }
+
+        drop(reader);
+
+        assert_eq!(lines, ["a b\n", "a b\n", "a b\n"]);
+        assert_eq!(
+            yes.join().unwrap().unwrap_err().kind(),
+            io::ErrorKind::BrokenPipe
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use std::{
+    fs,
+    io::{self, Read, Write},
+};
+
+use crate::unix::{signals, terminal::RawMode};
+
+/// The byte sent by the terminal for Ctrl-D, ending the input on an empty line.
+const END_OF_TRANSMISSION: u8 = 0x04;
+
+/// The byte sent by the terminal for Ctrl-U, clearing the line.
+const KILL_LINE: u8 = 0x15;
+
+/// The bytes sent by the terminal for the backspace key, depending on its configuration.
+const BACKSPACE: u8 = 0x08;
+const DELETE: u8 = 0x7f;
+
+const ESCAPE: u8 = 0x1b;
+
+/// Reads a line from the terminal attached to the standard input, with basic line editing.
+///
+/// Characters are typed at the end of the line, and can be erased with backspace or Ctrl-U. Tab completes
+/// the word being typed: the first word of a command against `commands`, the other ones against the
+/// paths of the filesystem. Ctrl-C discards the line. Returns `None` when Ctrl-D is typed on an empty
+/// line.
+///
+/// # Arguments
+///
+/// * `prompt` - The prompt printed before the line.
+/// * `commands` - The names of the commands the first word of a command is completed against.
+pub fn read_line(prompt: &str, commands: &[String]) -> io::Result<Option<String>> {
+    let _raw_mode = RawMode::enable()?;
+    let mut stdin = io::stdin().lock();
+    // Like in other shells, the line is drawn on the standard error, which keeps it out of a redirected
+    // standard output.
+    let mut stderr = io::stderr().lock();
+    let mut line = String::new();
+
+    write!(stderr, "{}", prompt)?;
+    stderr.flush()?;
+
+    loop {
+        let byte = match read_byte(&mut stdin) {
+            Ok(Some(byte)) => byte,
+            Ok(None) if line.is_empty() => return Ok(None),
+            Ok(None) => break,
+            // Ctrl-C discards the line, like in other shells, and an empty one is returned instead.
+            Err(e) if e.kind() == io::ErrorKind::Interrupted && signals::clear_interrupt() => {
+                writeln!(stderr, "^C")?;
+
+                return Ok(Some(String::new()));
+            }
+            Err(e) => return Err(e),
+        };
+
+        match byte {
+            b'\r' | b'\n' => break,
+            END_OF_TRANSMISSION if line.is_empty() => {
+                writeln!(stderr)?;
+
+                return Ok(None);
+            }
+            BACKSPACE | DELETE => {
+                line.pop();
+            }
+            KILL_LINE => line.clear(),
+            b'\t' => complete(&mut line, commands, prompt, &mut stderr)?,
+            ESCAPE => skip_escape_sequence(&mut stdin)?,
+            _ if byte < 0x20 => {}
+            _ => line.push(read_char(byte, &mut stdin)?),
+        }
+
+        // The whole line is redrawn after each key, which keeps the editing logic simple.
+        write!(stderr, "\r{}{}\x1b[K", prompt, line)?;
+        stderr.flush()?;
+    }
+
+    writeln!(stderr)?;
+
+    Ok(Some(line))
+}
+
+fn read_byte(reader: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut byte = [0];
+
+    match reader.read(&mut byte)? {
+        0 => Ok(None),
+        _ => Ok(Some(byte[0])),
+    }
+}
+
+/// Reads the remaining bytes of the UTF-8 character starting with `first`, replacing an invalid sequence
+/// by the replacement character.
+fn read_char(first: u8, reader: &mut impl Read) -> io::Result<char> {
+    let length = match first {
+        0xf0.. => 4,
+        0xe0.. => 3,
+        0xc0.. => 2,
+        _ => 1,
+    };
+
+    let mut bytes = vec![first];
+
+    for _ in 1..length {
+        bytes.extend(read_byte(reader)?);
+    }
+
+    Ok(String::from_utf8_lossy(&bytes)
+        .chars()
+        .next()
+        .unwrap_or('\u{fffd}'))
+}
+
+/// Skips the escape sequence sent by keys such as the arrows, which are not supported.
+fn skip_escape_sequence(reader: &mut impl Read) -> io::Result<()> {
+    if read_byte(reader)? != Some(b'[') {
+        return Ok(());
+    }
+
+    // The parameters of a control sequence are followed by a final byte in the `@` to `~` range.
+    while let Some(byte) = read_byte(reader)? {
+        if (0x40..=0x7e).contains(&byte) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Completes the last word of `line`. A single candidate replaces the word, followed by a space unless
+/// it is a directory. Several candidates are completed up to their common prefix, or listed when the word
+/// already is that prefix. The completed text is escaped with backslashes, so that the spaces and special
+/// characters of the names it holds are kept by the shell.
+fn complete(
+    line: &mut String,
+    commands: &[String],
+    prompt: &str,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let start = word_start(line);
+    let word = unescape(&line[start..]);
+    let word = word.as_str();
+    let first_word = line[..start].trim_end().is_empty() || line[..start].trim_end().ends_with('|');
+
+    let candidates = if first_word && !word.contains('/') {
+        command_candidates(word, commands)
+    } else {
+        path_candidates(word)
+    };
+
+    match candidates.as_slice() {
+        [] => {}
+        [candidate] => {
+            let suffix = if candidate.ends_with('/') { "" } else { " " };
+
+            line.replace_range(start.., &format!("{}{}", escape(candidate), suffix));
+        }
+        _ => {
+            let prefix = common_prefix(&candidates);
+
+            if prefix.len() > word.len() {
+                line.replace_range(start.., &escape(&prefix));
+            } else {
+                let names: Vec<&str> = candidates.iter().map(|c| display_name(c)).collect();
+
+                write!(writer, "\r\n{}\r\n{}", names.join("  "), prompt)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the offset at which the last word of `line` starts, after the last whitespace or operator that
+/// is not escaped with a backslash.
+fn word_start(line: &str) -> usize {
+    let mut start = 0;
+    let mut chars = line.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            _ if c.is_whitespace() || matches!(c, '|' | '<' | '>') => start = i + c.len_utf8(),
+            _ => {}
+        }
+    }
+
+    start
+}
+
+/// Removes the backslashes escaping the characters of `word`.
+fn unescape(word: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = word.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            _ => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+/// Escapes the whitespace and the characters of `text` that are special to the shell with backslashes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+
+    for c in text.chars() {
+        if c.is_whitespace() || "*?[{|<>\"'\\".contains(c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+fn command_candidates(word: &str, commands: &[String]) -> Vec<String> {
+    let mut candidates: Vec<String> = commands
+        .iter()
+        .filter(|name| name.starts_with(word))
+        .cloned()
+        .collect();
+
+    candidates.sort();
+    candidates.dedup();
+
+    candidates
+}
+
+/// Returns the paths starting with `word`, a trailing slash being added to directories. Hidden files are
+/// only candidates when the word names them with a leading dot.
+fn path_candidates(word: &str) -> Vec<String> {
+    let (dir, prefix) = match word.rfind('/') {
+        Some(i) => word.split_at(i + 1),
+        None => ("", word),
+    };
+
+    let Ok(entries) = fs::read_dir(if dir.is_empty() { "." } else { dir }) else {
+        return vec![];
+    };
+
+    let mut candidates: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+
+            if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.')) {
+                return None;
+            }
+
+            // Symlinks to directories are completed as directories.
+            let is_dir = fs::metadata(entry.path()).is_ok_and(|m| m.is_dir());
+
+            Some(format!("{}{}{}", dir, name, if is_dir { "/" } else { "" }))
+        })
+        .collect();
+
+    candidates.sort();
+
+    candidates
+}
+
+fn common_prefix(candidates: &[String]) -> String {
+    let mut prefix = candidates[0].as_str();
+
+    for candidate in &candidates[1..] {
+        let length = prefix
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(prefix.len().min(candidate.len()), |((i, _), _)| i);
+
+        prefix = &prefix[..length];
+    }
+
+    prefix.to_string()
+}
+
+/// Returns the name listed for a candidate: the last component of a path, keeping the trailing slash of
+/// directories.
+fn display_name(candidate: &str) -> &str {
+    let trimmed = candidate.trim_end_matches('/');
+
+    match trimmed.rfind('/') {
+        Some(i) => &candidate[i + 1..],
+        None => candidate,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn strings(strings: &[&str]) -> Vec<String> {
+        strings.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn complete_line(line: &str, commands: &[&str]) -> (String, String) {
+        let mut line = line.to_string();
+        let mut output = vec![];
+
+        complete(&mut line, &strings(commands), "$ ", &mut output).unwrap();
+
+        (line, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn common_prefix_stops_at_the_first_difference() {
+        assert_eq!(common_prefix(&strings(&["foobar", "foobaz", "foo"])), "foo");
+        assert_eq!(common_prefix(&strings(&["abc", "xyz"])), "");
+        assert_eq!(common_prefix(&strings(&["same"])), "same");
+    }
+
+    #[test]
+    fn common_prefix_keeps_multibyte_characters_whole() {
+        assert_eq!(common_prefix(&strings(&["été", "étoile"])), "ét");
+        assert_eq!(common_prefix(&strings(&["éa", "èb"])), "");
+    }
+
+    #[test]
+    fn path_candidates_mark_directories_and_skip_hidden_files() {
+        let dir = TempDir::new();
+        dir.write("file", "");
+        dir.write(".hidden", "");
+        fs::create_dir(dir.path().join("folder")).unwrap();
+        let base = format!("{}/", dir.path().display());
+
+        assert_eq!(
+            path_candidates(&base),
+            [format!("{}file", base), format!("{}folder/", base)]
+        );
+        assert_eq!(
+            path_candidates(&format!("{}fo", base)),
+            [format!("{}folder/", base)]
+        );
+        assert_eq!(
+            path_candidates(&format!("{}.", base)),
+            [format!("{}.hidden", base)]
+        );
+    }
+
+    #[test]
+    fn display_name_is_the_last_component() {
+        assert_eq!(display_name("a/b/file"), "file");
+        assert_eq!(display_name("a/dir/"), "dir/");
+        assert_eq!(display_name("name"), "name");
+    }
+
+    #[test]
+    fn completes_a_single_command() {
+        assert_eq!(
+            complete_line("ec", &["echo", "exit"]),
+            (String::from("echo "), String::new())
+        );
+        assert_eq!(
+            complete_line("ls | gr", &["grep", "ls"]),
+            (String::from("ls | grep "), String::new())
+        );
+    }
+
+    #[test]
+    fn completes_up_to_the_common_prefix_then_lists_the_candidates() {
+        assert_eq!(
+            complete_line("he", &["head", "help"]),
+            (String::from("he"), String::from("\r\nhead  help\r\n$ "))
+        );
+        assert_eq!(
+            complete_line("h", &["head", "help"]),
+            (String::from("he"), String::new())
+        );
+    }
+
+    #[test]
+    fn escapes_the_special_characters_of_completed_paths() {
+        let dir = TempDir::new();
+        dir.write("my file*", "");
+        let base = format!("{}/", dir.path().display());
+
+        let (line, _) = complete_line(&format!("cat {}my", base), &[]);
+
+        assert_eq!(line, format!("cat {}my\\ file\\* ", base));
+    }
+
+    #[test]
+    fn completes_a_word_with_escaped_spaces() {
+        let dir = TempDir::new();
+        dir.write("my file", "");
+        dir.write("my folder", "");
+        let base = format!("{}/", dir.path().display());
+
+        let (line, _) = complete_line(&format!("cat {}my\\ fi", base), &[]);
+
+        assert_eq!(line, format!("cat {}my\\ file ", base));
+    }
+}
@@ -0,0 +1,220 @@
+use std::mem;
+
+/// Expands the brace expressions of `word` into the list of words they generate, in order.
+///
+/// A brace expression is either a list of comma-separated elements, as in `file{1,2,3}.txt`, or a
+/// sequence `{X..Y}` between two integers or two letters, counting down when `X` is greater than `Y`.
+/// Elements may be empty, and may themselves hold brace expressions. Integers starting with a zero are
+/// padded with zeros to the width of the widest bound. Braces holding neither a comma nor a valid
+/// sequence, like `{a}`, are kept as they are.
+///
+/// The expansion is purely lexical. A backslash makes the next character literal, and is kept in the
+/// generated words.
+///
+/// # Arguments
+///
+/// * `word` - The word to expand.
+pub fn braces(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut start = 0;
+
+    while let Some(open) = find_unescaped(&chars, start, '{') {
+        start = open + 1;
+
+        let Some(close) = closing_brace(&chars, open) else {
+            continue;
+        };
+
+        let Some(elements) = elements(&chars[open + 1..close]) else {
+            continue;
+        };
+
+        let prefix: String = chars[..open].iter().collect();
+        let suffix: String = chars[close + 1..].iter().collect();
+
+        // The prefix holds no brace expression, while the elements and the suffix may hold more of them.
+        return elements
+            .iter()
+            .flat_map(|element| braces(&format!("{}{}{}", prefix, element, suffix)))
+            .collect();
+    }
+
+    vec![word.to_string()]
+}
+
+/// Returns whether `word` holds a `{` that isn't escaped by a backslash.
+pub fn has_braces(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+
+    find_unescaped(&chars, 0, '{').is_some()
+}
+
+/// Returns the index of the first `target` of `chars` at or after `start` that isn't escaped.
+fn find_unescaped(chars: &[char], start: usize, target: char) -> Option<usize> {
+    let mut i = start;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            c if c == target => return Some(i),
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Returns the index of the `}` closing the `{` at `open`, skipping the nested pairs of braces.
+fn closing_brace(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = open;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Returns the elements generated by the content of a pair of braces, or `None` when it is not a brace
+/// expression.
+fn elements(body: &[char]) -> Option<Vec<String>> {
+    let mut elements = vec![];
+    let mut element = String::new();
+    let mut depth = 0;
+    let mut chars = body.iter();
+
+    while let Some(&c) = chars.next() {
+        match c {
+            '\\' => {
+                element.push(c);
+                element.extend(chars.next());
+                continue;
+            }
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                elements.push(mem::take(&mut element));
+                continue;
+            }
+            _ => {}
+        }
+
+        element.push(c);
+    }
+
+    if elements.is_empty() {
+        return sequence(&element);
+    }
+
+    elements.push(element);
+
+    Some(elements)
+}
+
+/// Returns the elements of a sequence `X..Y` between two integers or two letters.
+fn sequence(body: &str) -> Option<Vec<String>> {
+    let (start, end) = body.split_once("..")?;
+
+    if let (Ok(first), Ok(last)) = (start.parse::<i64>(), end.parse::<i64>()) {
+        let padded = [start, end]
+            .iter()
+            .any(|bound| bound.trim_start_matches('-').starts_with('0') && bound.len() > 1);
+        let width = if padded {
+            start.len().max(end.len())
+        } else {
+            0
+        };
+
+        return Some(
+            range(first, last)
+                .map(|n| format!("{:0width$}", n, width = width))
+                .collect(),
+        );
+    }
+
+    let (mut start, mut end) = (start.chars(), end.chars());
+
+    match (start.next(), start.next(), end.next(), end.next()) {
+        (Some(first), None, Some(last), None)
+            if first.is_ascii_alphabetic() && last.is_ascii_alphabetic() =>
+        {
+            Some(
+                range(first as i64, last as i64)
+                    .map(|c| char::from(c as u8).to_string())
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Returns the integers from `first` to `last` included, counting down when `first` is greater.
+fn range(first: i64, last: i64) -> Box<dyn Iterator<Item = i64>> {
+    if first <= last {
+        Box::new(first..=last)
+    } else {
+        Box::new((last..=first).rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comma_lists_expand_in_order() {
+        assert_eq!(
+            braces("file{1,2,3}.txt"),
+            ["file1.txt", "file2.txt", "file3.txt"]
+        );
+        assert_eq!(braces("{a,b}{x,y}"), ["ax", "ay", "bx", "by"]);
+    }
+
+    #[test]
+    fn empty_elements_are_kept() {
+        assert_eq!(braces("a{,b}"), ["a", "ab"]);
+        assert_eq!(braces("{a,,b}"), ["a", "", "b"]);
+    }
+
+    #[test]
+    fn braces_nest() {
+        assert_eq!(braces("{a,b{1,2}}c"), ["ac", "b1c", "b2c"]);
+    }
+
+    #[test]
+    fn ranges_count_up_or_down() {
+        assert_eq!(braces("{1..5}"), ["1", "2", "3", "4", "5"]);
+        assert_eq!(braces("{3..-1}"), ["3", "2", "1", "0", "-1"]);
+        assert_eq!(braces("{c..a}"), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn ranges_with_leading_zeros_are_padded() {
+        assert_eq!(braces("{08..10}"), ["08", "09", "10"]);
+    }
+
+    #[test]
+    fn invalid_expressions_are_kept() {
+        assert_eq!(braces("{a}"), ["{a}"]);
+        assert_eq!(braces("{1..b}"), ["{1..b}"]);
+        assert_eq!(braces("{a,b"), ["{a,b"]);
+        assert_eq!(braces(r"\{a,b}"), [r"\{a,b}"]);
+        assert!(!has_braces(r"\{a,b}"));
+    }
+}
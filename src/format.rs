@@ -0,0 +1,41 @@
+/// Units of the human-readable sizes, each one being 1024 times the previous one.
+const UNITS: [char; 8] = ['K', 'M', 'G', 'T', 'P', 'E', 'Z', 'Y'];
+
+/// Formats a size in bytes as a human-readable string, like `ls -h` does.
+///
+/// Sizes under 1024 bytes are printed as is. Larger sizes are scaled by powers of 1024 and suffixed by
+/// their unit, with one decimal place below 10 (`1.5K`) and none above (`23M`). Values are rounded up,
+/// so that a size is never under-reported.
+///
+/// # Arguments
+///
+/// * `size` - The size in bytes to format.
+pub fn human_size(size: u64) -> String {
+    if size < 1024 {
+        return size.to_string();
+    }
+
+    let mut value = size as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if value < 10.0 {
+        let rounded = (value * 10.0).ceil() / 10.0;
+
+        if rounded < 10.0 {
+            return format!("{:.1}{}", rounded, UNITS[unit]);
+        }
+    }
+
+    let rounded = value.ceil();
+
+    if rounded >= 1024.0 && unit < UNITS.len() - 1 {
+        return format!("1.0{}", UNITS[unit + 1]);
+    }
+
+    format!("{:.0}{}", rounded, UNITS[unit])
+}
@@ -0,0 +1,273 @@
+use std::fs;
+
+/// Returns whether `name` matches the glob `pattern`.
+///
+/// In the pattern, `*` matches any sequence of characters, `?` matches any single character, and `[...]`
+/// matches any character of the set, which may hold ranges like `a-z` and be negated by a leading `!` or
+/// `^`. A backslash makes the next character match literally, and a `[` without a closing `]` matches
+/// itself.
+///
+/// # Arguments
+///
+/// * `pattern` - The glob pattern to match against.
+/// * `name` - The name to match.
+pub fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` met, and of the name character it was last extended to.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if pattern.get(p) == Some(&'*') {
+            backtrack = Some((p, n));
+            p += 1;
+            continue;
+        }
+
+        if let Some(consumed) = match_single(&pattern[p..], name[n]) {
+            p += consumed;
+            n += 1;
+            continue;
+        }
+
+        // On a mismatch, the last `*` is made to swallow one more character of the name.
+        match backtrack {
+            Some((star, start)) => {
+                p = star + 1;
+                n = start + 1;
+                backtrack = Some((star, start + 1));
+            }
+            None => return false,
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Matches the first element of `pattern`, which isn't a `*`, against `c`. Returns the number of pattern
+/// characters making the element if it matches, and `None` otherwise.
+fn match_single(pattern: &[char], c: char) -> Option<usize> {
+    match pattern.first()? {
+        '?' => Some(1),
+        '\\' if pattern.len() > 1 => (pattern[1] == c).then_some(2),
+        '[' => match match_class(pattern, c) {
+            Some((true, consumed)) => Some(consumed),
+            Some((false, _)) => None,
+            None => (c == '[').then_some(1),
+        },
+        literal => (*literal == c).then_some(1),
+    }
+}
+
+/// Matches the bracket expression starting `pattern` against `c`. Returns whether it matches along with
+/// its length, or `None` if the expression has no closing bracket.
+fn match_class(pattern: &[char], c: char) -> Option<(bool, usize)> {
+    let mut i = 1;
+    let negated = matches!(pattern.get(i), Some('!' | '^'));
+
+    if negated {
+        i += 1;
+    }
+
+    let mut matched = false;
+    let mut first = true;
+
+    loop {
+        let start = *pattern.get(i)?;
+
+        // A `]` right after the opening bracket is part of the set rather than closing it.
+        if start == ']' && !first {
+            return Some((matched != negated, i + 1));
+        }
+
+        first = false;
+
+        match (pattern.get(i + 1), pattern.get(i + 2)) {
+            (Some('-'), Some(&end)) if end != ']' => {
+                matched |= start <= c && c <= end;
+                i += 3;
+            }
+            _ => {
+                matched |= start == c;
+                i += 1;
+            }
+        }
+    }
+}
+
+/// Expands the glob `pattern` into the sorted list of the paths it matches.
+///
+/// The pattern is matched one path component at a time, so that `*` and `?` never match a `/`. Like in
+/// other shells, a name starting with a dot is only matched by a component starting with a dot too.
+/// Returns an empty list when nothing matches.
+///
+/// # Arguments
+///
+/// * `pattern` - The glob pattern to expand, in which a backslash makes the next character literal.
+pub fn expand(pattern: &str) -> Vec<String> {
+    let (mut paths, components) = match pattern.strip_prefix('/') {
+        Some(rest) => (vec![String::from("/")], rest),
+        None => (vec![String::new()], pattern),
+    };
+
+    for component in components.split('/') {
+        paths = paths
+            .iter()
+            .flat_map(|path| expand_component(path, component))
+            .collect();
+    }
+
+    paths.sort();
+
+    paths
+}
+
+/// Returns the paths made of `path` followed by a name matching `component`.
+fn expand_component(path: &str, component: &str) -> Vec<String> {
+    let join = |name: &str| match path {
+        "" => name.to_string(),
+        _ if path.ends_with('/') => format!("{}{}", path, name),
+        _ => format!("{}/{}", path, name),
+    };
+
+    // An empty component comes from a trailing slash, which only directories match.
+    if component.is_empty() {
+        return match fs::metadata(path) {
+            Ok(metadata) if metadata.is_dir() => vec![join("")],
+            _ => vec![],
+        };
+    }
+
+    if !is_pattern(component) {
+        let joined = join(&unescape(component));
+
+        return match fs::symlink_metadata(&joined) {
+            Ok(_) => vec![joined],
+            Err(_) => vec![],
+        };
+    }
+
+    let Ok(entries) = fs::read_dir(if path.is_empty() { "." } else { path }) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| !name.starts_with('.') || component.starts_with('.'))
+        .filter(|name| matches(component, name))
+        .map(|name| join(&name))
+        .collect()
+}
+
+/// Returns whether `pattern` holds a `*`, a `?` or a `[` that isn't escaped by a backslash.
+pub fn is_pattern(pattern: &str) -> bool {
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '*' | '?' | '[' => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Removes the backslashes escaping the characters of `pattern`, giving the name it matches literally.
+pub fn unescape(pattern: &str) -> String {
+    let mut name = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        name.push(match c {
+            '\\' => chars.next().unwrap_or('\\'),
+            _ => c,
+        });
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn star_matches_any_sequence() {
+        assert!(matches("*.txt", "notes.txt"));
+        assert!(matches("*.txt", ".txt"));
+        assert!(matches("a*b*c", "aXbYbc"));
+        assert!(!matches("*.txt", "notes.txt.bak"));
+    }
+
+    #[test]
+    fn question_mark_matches_a_single_character() {
+        assert!(matches("file?.rs", "file1.rs"));
+        assert!(!matches("file?.rs", "file.rs"));
+        assert!(!matches("file?.rs", "file12.rs"));
+    }
+
+    #[test]
+    fn brackets_match_a_set_of_characters() {
+        assert!(matches("[abc]1", "b1"));
+        assert!(matches("[a-c]1", "c1"));
+        assert!(!matches("[a-c]1", "d1"));
+        assert!(matches("[!a-c]1", "d1"));
+        assert!(matches("[^a-c]1", "d1"));
+        assert!(matches("[]]", "]"));
+        assert!(matches("[a", "[a"));
+    }
+
+    #[test]
+    fn backslash_escapes_the_next_character() {
+        assert!(matches(r"\*", "*"));
+        assert!(!matches(r"\*", "a"));
+        assert!(is_pattern("a*"));
+        assert!(!is_pattern(r"a\*"));
+        assert_eq!(unescape(r"a\*\\b"), r"a*\b");
+    }
+
+    #[test]
+    fn expand_lists_the_matching_paths_sorted() {
+        let dir = TempDir::new();
+        for name in ["b.txt", "a.txt", "c.rs", ".hidden.txt"] {
+            dir.write(name, "");
+        }
+        let prefix = dir.join("");
+
+        assert_eq!(
+            expand(&format!("{}*.txt", prefix)),
+            [dir.join("a.txt"), dir.join("b.txt")]
+        );
+        assert_eq!(expand(&format!("{}?.rs", prefix)), [dir.join("c.rs")]);
+        assert_eq!(
+            expand(&format!("{}.*.txt", prefix)),
+            [dir.join(".hidden.txt")]
+        );
+    }
+
+    #[test]
+    fn expand_matches_one_component_at_a_time() {
+        let dir = TempDir::new();
+        std::fs::create_dir_all(dir.join("sub/deep")).unwrap();
+        dir.write("sub/file", "");
+        dir.write("sub/deep/file", "");
+        let prefix = dir.join("");
+
+        assert_eq!(expand(&format!("{}*/file", prefix)), [dir.join("sub/file")]);
+        assert_eq!(expand(&format!("{}*/*/", prefix)), [dir.join("sub/deep/")]);
+    }
+
+    #[test]
+    fn expand_without_match_is_empty() {
+        let dir = TempDir::new();
+
+        assert!(expand(&format!("{}*.none", dir.join(""))).is_empty());
+    }
+}
@@ -1,81 +1,557 @@
-use std::io::{self};
+use std::{
+    env,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write},
+    panic,
+    path::PathBuf,
+    process::ExitCode,
+    thread,
+};
 
 mod commands;
+mod editor;
+mod expand;
+mod format;
+mod glob;
+mod parser;
+mod pipe;
+mod prompt;
+#[cfg(test)]
+mod testing;
 mod unix;
 
 use commands::{
-    echo::execute as execute_echo, exit::execute as execute_exit, ls::execute as execute_ls,
+    execute_command, help,
+    history::{history_file, History},
+    ShellState, Status, Streams,
 };
+use parser::{parse, scan, word_token, Command, Redirection, Token};
+use pipe::{PipeReader, PipeWriter};
+use unix::signals::{self, Interruptible};
 
-fn main() -> io::Result<()> {
-    let stdin = io::stdin();
-    for line in stdin.lines() {
-        let input = String::from(line?.trim());
-        let tokens = scan(input);
+fn main() -> io::Result<ExitCode> {
+    let mut state = ShellState::default();
 
-        if let Some((command_name, args)) = parse(tokens) {
-            let continue_execution = execute_command(command_name, args)?;
+    match env::args().nth(1).as_deref() {
+        Some("--version" | "-v") => {
+            println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
 
-            if !continue_execution {
-                return Ok(());
+            return Ok(ExitCode::SUCCESS);
+        }
+        Some("--help") => {
+            match print_usage(&state) {
+                Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e),
+                _ => {}
             }
+
+            return Ok(ExitCode::SUCCESS);
         }
+        Some(path) => return run_script(path, &mut state),
+        None => {}
     }
 
-    Ok(())
+    state.interactive = io::stdin().is_terminal();
+
+    let history_file = start_session(&mut state);
+
+    while let Some(line) = read_input(&state)? {
+        let input = line.trim();
+
+        if !input.is_empty() {
+            state.history.push(input.to_string());
+        }
+
+        if execute_line(input, &mut state)?.exit {
+            break;
+        }
+    }
+
+    if let Some(path) = &history_file {
+        if let Err(e) = state.history.append(path) {
+            eprintln!("history : cannot save {}: {}", path.display(), e);
+        }
+    }
+
+    Ok(exit_code(&state))
 }
 
-/// Execute a command with the provided arguments.
+/// Prepares the shell to read its commands, depending on whether it is interactive. Returns the file the
+/// history of the session is to be saved to, if any.
 ///
-/// This function takes a command string `command` and a vector of strings `args` representing the arguments
-/// for the command. It performs the logic for executing the specified command and returns an `io::Result<bool>`.
+/// Ctrl-C only interrupts the running command of an interactive shell, whereas a script is ended by it,
+/// like with other shells. Only the lines typed at a terminal are worth recalling, so the history of a
+/// shell reading its commands from a pipe is neither loaded nor saved.
+fn start_session(state: &mut ShellState) -> Option<PathBuf> {
+    if !state.interactive {
+        return None;
+    }
+
+    if let Err(e) = signals::catch_interrupts() {
+        eprintln!("core-utils : cannot catch interrupts: {}", e);
+    }
+
+    let path = history_file()?;
+
+    match History::load(&path) {
+        Ok(history) => state.history = history,
+        Err(e) => eprintln!("history : cannot load {}: {}", path.display(), e),
+    }
+
+    Some(path)
+}
+
+/// Prints how to start the shell, followed by the commands it provides.
+fn print_usage(state: &ShellState) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "Usage: {} [SCRIPT]", env!("CARGO_PKG_NAME"))?;
+    writeln!(
+        stdout,
+        "       {} --version | --help",
+        env!("CARGO_PKG_NAME")
+    )?;
+    writeln!(stdout)?;
+    writeln!(
+        stdout,
+        "Reads commands from the standard input, or from the SCRIPT file when given."
+    )?;
+    writeln!(stdout)?;
+    writeln!(stdout, "Commands:")?;
+
+    help::list_commands(&state.registry, &mut stdout)
+}
+
+/// Runs the commands of the script file at `path`, one line at a time, until its end or an `exit`.
 ///
-/// # Arguments
+/// Empty lines and lines starting with `#` are skipped. Unlike the interactive shell, the commands of a
+/// script are not recorded in the history.
+fn run_script(path: &str, state: &mut ShellState) -> io::Result<ExitCode> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("core-utils : {}: {}", path, e);
+
+            return Ok(ExitCode::from(127));
+        }
+    };
+
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        let input = line.trim();
+
+        if input.is_empty() || input.starts_with('#') {
+            continue;
+        }
+
+        if execute_line(input, state)?.exit {
+            break;
+        }
+    }
+
+    Ok(exit_code(state))
+}
+
+/// Expands, parses and runs a line of input, recording its status as the last one.
 ///
-/// * `command` - A string representing the name of the command to execute.
-/// * `args` - A vector of strings representing the arguments for the command.
-fn execute_command(command_name: String, args: Vec<String>) -> io::Result<bool> {
-    if command_name.is_empty() {
-        print!("");
-    }
-
-    match command_name.as_str() {
-        "echo" => execute_echo(args),
-        "exit" => execute_exit(),
-        "ls" => execute_ls(args),
-        _ => {
-            eprintln!("command not found : {}", command_name);
-            Ok(true)
+/// A syntax error is reported and gives a status of 2, like in other shells.
+fn execute_line(input: &str, state: &mut ShellState) -> io::Result<Status> {
+    let input = state.aliases.expand(input);
+    let tokens = scan(input, state.last_status)
+        .map(expand_braces)
+        .map(expand_patterns);
+    let pipeline = match tokens.and_then(parse) {
+        Ok(pipeline) => pipeline,
+        Err(e) => {
+            eprintln!("{}", e);
+            state.last_status = 2;
+
+            return Ok(Status {
+                code: 2,
+                exit: false,
+            });
+        }
+    };
+
+    // An interrupt received before the line ran, while nothing could be interrupted, is forgotten.
+    signals::clear_interrupt();
+
+    let status = run(pipeline, state)?;
+
+    state.last_status = status.code;
+
+    Ok(status)
+}
+
+/// Replaces each pattern of `tokens` holding brace expressions by the words they generate, which may
+/// still be glob patterns.
+fn expand_braces(tokens: Vec<Token>) -> Vec<Token> {
+    let mut expanded = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        match token {
+            Token::Pattern(pattern) => {
+                expanded.extend(expand::braces(&pattern).into_iter().map(word_token))
+            }
+            _ => expanded.push(token),
         }
     }
+
+    expanded
 }
 
-/// Scan an input string and split it into a vector of tokens.
+/// Replaces each glob pattern of `tokens` by the paths it matches, sorted.
 ///
-/// This function takes an input string `input` and splits it into individual tokens based on spaces.
+/// A pattern matching nothing is kept as a literal word, like in other shells, unless the `NULLGLOB`
+/// environment variable is set, in which case it is removed.
+fn expand_patterns(tokens: Vec<Token>) -> Vec<Token> {
+    let null_glob = env::var_os("NULLGLOB").is_some();
+    let mut expanded = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        // Patterns left by the brace expansion may hold no glob characters at all.
+        let pattern = match token {
+            Token::Pattern(pattern) if glob::is_pattern(&pattern) => pattern,
+            Token::Pattern(word) => {
+                expanded.push(Token::Word(glob::unescape(&word)));
+                continue;
+            }
+            _ => {
+                expanded.push(token);
+                continue;
+            }
+        };
+
+        let paths = glob::expand(&pattern);
+
+        if paths.is_empty() && !null_glob {
+            expanded.push(Token::Word(glob::unescape(&pattern)));
+        } else {
+            expanded.extend(paths.into_iter().map(Token::Word));
+        }
+    }
+
+    expanded
+}
+
+/// Returns the exit code of the shell, which is the status of the last command.
+fn exit_code(state: &ShellState) -> ExitCode {
+    // Like other shells, only the low byte of the status is reported to the parent process.
+    ExitCode::from(state.last_status as u8)
+}
+
+/// Reads the next line of input, returning `None` at the end of the input.
+///
+/// When the shell is interactive, the line is read through the line editor after printing the
+/// prompt, completing the names of the commands and aliases of the shell. Otherwise, it is read as is,
+/// without a prompt, so that the output of a piped script only holds the output of its commands.
+fn read_input(state: &ShellState) -> io::Result<Option<String>> {
+    if state.interactive {
+        let commands: Vec<String> = state
+            .registry
+            .names()
+            .map(String::from)
+            .chain(state.aliases.names().map(String::from))
+            .collect();
+
+        return editor::read_line(&prompt::prompt(), &commands);
+    }
+
+    // Lines are read one at a time rather than through `stdin.lines()`, which would hold the stdin
+    // lock for the whole session and prevent commands such as `cat -` from reading it.
+    let mut line = String::new();
+
+    if io::stdin().read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(line))
+}
+
+/// Run a parsed pipeline, feeding the standard output of each command to the standard input of the next.
+///
+/// The commands run at the same time, each one but the last in its own thread, connected by pipes holding
+/// a bounded amount of data: a command writing faster than the next one reads waits for it, and stops with
+/// a broken pipe once the next one is done, as in `yes | head`. Like the subshells of other shells, the
+/// commands but the last run with a copy of the state of the shell, so that only the last one may change it
+/// or ask the shell to exit. The current directory and the environment belong to the whole process rather
+/// than to that state, so these commands run as a subshell, in which `cd` and `export` leave them as they
+/// are. The status of the pipeline is the status of its last command.
 ///
 /// # Arguments
 ///
-/// * `input` - A string representing the input to be scanned and split into tokens.
-fn scan(input: String) -> Vec<String> {
-    input.split(" ").map(String::from).collect()
+/// * `pipeline` - The commands to run, along with their arguments and redirections.
+/// * `state` - The state of the shell, kept across command executions.
+fn run(mut pipeline: Vec<Command>, state: &mut ShellState) -> io::Result<Status> {
+    let Some(last) = pipeline.pop() else {
+        return Ok(Status::SUCCESS);
+    };
+
+    let mut stages = vec![];
+    let mut piped = None;
+
+    for command in pipeline {
+        let (writer, reader) = pipe::pipe();
+        let input = piped.replace(reader);
+        let mut stage_state = ShellState {
+            subshell: true,
+            ..state.clone()
+        };
+
+        stages.push(thread::spawn(move || {
+            run_command(command, input, Some(writer), &mut stage_state)
+        }));
+    }
+
+    let status = run_command(last, piped, None, state);
+
+    // Ctrl-C interrupts the whole pipeline, rather than only the command it was delivered to.
+    if signals::interrupted() {
+        stages.iter().for_each(signals::interrupt_thread);
+    }
+
+    for stage in stages {
+        stage.join().unwrap_or_else(|e| panic::resume_unwind(e))?;
+    }
+
+    if signals::interrupted() {
+        eprintln!();
+    }
+
+    status
 }
 
-/// Parse a vector of tokens into a command and its arguments.
+/// Run a command, with its standard streams connected to the pipes or files they are redirected to.
 ///
-/// This function takes a vector of strings `tokens` representing command tokens. It extracts the first token
-/// as the command name and the rest as its arguments.
+/// Redirections to files take precedence over pipes. A file that cannot be opened for a redirection is
+/// reported, and the command is not run.
 ///
 /// # Arguments
 ///
-/// * `tokens` - A vector of strings representing the command tokens.
-fn parse(tokens: Vec<String>) -> Option<(String, Vec<String>)> {
-    if let Some(x) = tokens.get(0) {
-        let command_name = x.to_string();
-        let args: Vec<String> = tokens.into_iter().skip(1).collect();
+/// * `command` - The command to run, along with its arguments and redirections.
+/// * `piped_input` - The pipe from the previous command of the pipeline, if any.
+/// * `piped_output` - The pipe to the next command of the pipeline, if any.
+/// * `state` - The state of the shell, kept across command executions.
+fn run_command(
+    command: Command,
+    piped_input: Option<PipeReader>,
+    piped_output: Option<PipeWriter>,
+    state: &mut ShellState,
+) -> io::Result<Status> {
+    let (stdin, shell_stdin): (Box<dyn BufRead>, bool) = match (&command.input, piped_input) {
+        (Some(path), _) => match File::open(path) {
+            Ok(file) => (Box::new(BufReader::new(file)), false),
+            Err(e) => {
+                eprintln!("redirection : {}: {}", path, e);
 
-        Some((command_name, args))
+                return Ok(Status::FAILURE);
+            }
+        },
+        (None, Some(reader)) => (Box::new(reader), false),
+        (None, None) => (Box::new(io::stdin().lock()), true),
+    };
+
+    let (stdout, terminal): (Box<dyn Write>, bool) = match (&command.output, piped_output) {
+        (Some(redirection), _) => match open_output(redirection) {
+            Ok(file) => (Box::new(BufWriter::new(file)), false),
+            Err(e) => {
+                eprintln!("redirection : {}: {}", redirection.path, e);
+
+                return Ok(Status::FAILURE);
+            }
+        },
+        (None, Some(writer)) => (Box::new(BufWriter::new(writer)), false),
+        (None, None) => (Box::new(io::stdout().lock()), io::stdout().is_terminal()),
+    };
+
+    let mut stdin = Interruptible(stdin);
+    let mut stdout = Interruptible(stdout);
+    let mut streams = Streams {
+        stdin: &mut stdin,
+        stdout: &mut stdout,
+        terminal,
+        shell_stdin,
+    };
+    let status = execute_command(command.name, command.args, state, &mut streams)
+        .and_then(|status| stdout.flush().map(|_| status));
+
+    // Like in other shells, an interrupted command has the status of a command killed by `SIGINT`.
+    if signals::interrupted() {
+        return Ok(Status {
+            code: 130,
+            exit: false,
+        });
+    }
+
+    match status {
+        // The output was closed by its reader, as in `ls | head` once `head` is done. Like in other
+        // shells, this ends the command quietly, with the status of a command killed by `SIGPIPE`.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(Status {
+            code: 141,
+            exit: false,
+        }),
+        status => status,
+    }
+}
+
+/// Opens the target file of an output redirection, truncating it or appending to it.
+fn open_output(redirection: &Redirection) -> io::Result<File> {
+    if redirection.append {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&redirection.path)
     } else {
-        None
+        File::create(&redirection.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::testing::{lock_process, run as run_streams, TempDir};
+
+    fn command(name: &str, args: &[&str]) -> Command {
+        Command {
+            name: name.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            input: None,
+            output: None,
+        }
+    }
+
+    #[test]
+    fn command_writing_to_a_closed_pipe_has_status_141() {
+        let _lock = lock_process();
+        let (writer, reader) = pipe::pipe();
+        drop(reader);
+
+        let status = run_command(
+            command("yes", &[]),
+            None,
+            Some(writer),
+            &mut ShellState::default(),
+        );
+
+        assert_eq!(status.unwrap().code, 141);
+    }
+
+    #[test]
+    fn pipeline_status_is_the_status_of_its_last_command() {
+        let _lock = lock_process();
+        let mut state = ShellState::default();
+
+        let status = run(
+            vec![
+                command("yes", &[]),
+                command("head", &["-n", "0"]),
+                command("false", &[]),
+            ],
+            &mut state,
+        );
+
+        assert_eq!(status.unwrap(), Status::FAILURE);
+    }
+
+    #[test]
+    fn only_the_last_command_of_a_pipeline_can_exit() {
+        let _lock = lock_process();
+        let mut state = ShellState::default();
+
+        let first = run(
+            vec![command("exit", &["3"]), command("true", &[])],
+            &mut state,
+        );
+        let last = run(
+            vec![command("true", &[]), command("exit", &["4"])],
+            &mut state,
+        );
+
+        assert_eq!(first.unwrap(), Status::SUCCESS);
+        assert_eq!(last.unwrap(), Status::exit(4));
+    }
+
+    #[test]
+    fn commands_but_the_last_leave_the_directory_and_environment_unchanged() {
+        let _lock = lock_process();
+        let dir = TempDir::new();
+        let current_dir = env::current_dir().unwrap();
+        env::remove_var("CORE_UTILS_TEST_PIPED");
+        let mut state = ShellState::default();
+
+        let status = run(
+            vec![
+                command("cd", &[&dir.path().to_string_lossy()]),
+                command("export", &["CORE_UTILS_TEST_PIPED=1"]),
+                command("true", &[]),
+            ],
+            &mut state,
+        );
+
+        assert_eq!(status.unwrap(), Status::SUCCESS);
+        assert_eq!(env::current_dir().unwrap(), current_dir);
+        assert!(env::var_os("CORE_UTILS_TEST_PIPED").is_none());
+    }
+
+    #[test]
+    fn cd_in_a_pipeline_still_reports_a_missing_directory() {
+        let _lock = lock_process();
+        let dir = TempDir::new();
+        let current_dir = env::current_dir().unwrap();
+
+        let status = run_command(
+            command("cd", &[&dir.join("missing")]),
+            None,
+            Some(pipe::pipe().0),
+            &mut ShellState {
+                subshell: true,
+                ..ShellState::default()
+            },
+        );
+
+        assert_eq!(status.unwrap(), Status::FAILURE);
+        assert_eq!(env::current_dir().unwrap(), current_dir);
+    }
+
+    /// Starts a session of a shell, interactive or not, whose home directory holds a history. Returns
+    /// whether the history is to be saved, and what `history` prints.
+    fn start_session_at_home(interactive: bool) -> (bool, String) {
+        let _lock = lock_process();
+        let home = env::var_os("HOME");
+        let dir = TempDir::new();
+        env::set_var("HOME", dir.path());
+        fs::write(history_file().unwrap(), "earlier\n").unwrap();
+
+        let mut state = ShellState {
+            interactive,
+            ..ShellState::default()
+        };
+        let history_file = start_session(&mut state);
+
+        match home {
+            Some(home) => env::set_var("HOME", home),
+            None => env::remove_var("HOME"),
+        }
+
+        let (_, history) = run_streams("", |streams| {
+            execute_command(String::from("history"), vec![], &mut state, streams)
+        });
+
+        (history_file.is_some(), history)
+    }
+
+    #[test]
+    fn interactive_session_loads_the_history() {
+        let (saved, history) = start_session_at_home(true);
+
+        assert!(saved);
+        assert!(history.ends_with(" earlier\n"), "{}", history);
+    }
+
+    #[test]
+    fn session_reading_a_pipe_has_no_history() {
+        let (saved, history) = start_session_at_home(false);
+
+        assert_eq!((saved, history.as_str()), (false, ""));
     }
 }
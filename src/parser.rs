@@ -0,0 +1,577 @@
+use std::{env, fmt, iter::Peekable, mem, process, str::Chars};
+
+use crate::{expand, glob};
+
+/// A token of an input line.
+#[derive(Debug, PartialEq)]
+pub enum Token {
+    Word(String),
+    /// A word holding unquoted glob characters or braces, to be expanded into the words and the paths it
+    /// generates. Its other glob characters, braces, commas and backslashes are escaped with a backslash.
+    Pattern(String),
+    /// `>`, redirecting the standard output to a file, truncating it.
+    RedirectOut,
+    /// `>>`, redirecting the standard output to a file, appending to it.
+    AppendOut,
+    /// `<`, redirecting the standard input from a file.
+    RedirectIn,
+    /// `|`, feeding the standard output of a command to the standard input of the next one.
+    Pipe,
+}
+
+/// A redirection of the standard output of a command to a file.
+pub struct Redirection {
+    pub path: String,
+    pub append: bool,
+}
+
+/// A command parsed from an input line, ready to be executed.
+pub struct Command {
+    pub name: String,
+    pub args: Vec<String>,
+    pub input: Option<String>,
+    pub output: Option<Redirection>,
+}
+
+/// An error raised while scanning or parsing an input line.
+#[derive(Debug)]
+pub enum SyntaxError {
+    UnmatchedQuote(char),
+    MissingRedirectionTarget,
+    UnexpectedPipe,
+    BadSubstitution,
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyntaxError::UnmatchedQuote(quote) => write!(f, "syntax error : unmatched {}", quote),
+            SyntaxError::MissingRedirectionTarget => {
+                write!(f, "syntax error : expected a file after the redirection")
+            }
+            SyntaxError::UnexpectedPipe => write!(f, "syntax error : expected a command around |"),
+            SyntaxError::BadSubstitution => write!(f, "syntax error : bad substitution"),
+        }
+    }
+}
+
+/// Scan an input string and split it into a vector of tokens.
+///
+/// This function takes an input string `input` and splits it into individual tokens, treating any run
+/// of whitespace as a single delimiter. Spans enclosed in single or double quotes are kept in a single
+/// token, with the quotes stripped, and a backslash outside of quotes preserves the literal value of the
+/// character that follows it. The pipe `|` and the redirection operators `<`, `>` and `>>` are tokens of
+/// their own, even when not surrounded by whitespace, unless they are quoted or escaped.
+///
+/// Variables written as `$NAME` or `${NAME}` are replaced by their value in the environment, or by
+/// nothing when they are not set, `$$` is replaced by the process id of the shell, and `$?` by the exit
+/// status of the last command. Expansion happens
+/// outside of quotes and inside double quotes, but not inside single quotes. The expanded value is not
+/// split into several tokens, and an unquoted variable expanding to nothing yields no token at all.
+///
+/// A word holding an unquoted `*`, `?`, `[` or `{` is scanned as a pattern rather than as a plain word.
+///
+/// # Arguments
+///
+/// * `input` - A string representing the input to be scanned and split into tokens.
+/// * `last_status` - The exit status of the last command, which `$?` expands to.
+pub fn scan(input: String, last_status: i32) -> Result<Vec<Token>, SyntaxError> {
+    let mut tokens = vec![];
+    let mut token: Option<String> = None;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            tokens.extend(token.take().map(word_token));
+            continue;
+        }
+
+        if c == '|' || c == '<' || c == '>' {
+            tokens.extend(token.take().map(word_token));
+
+            if c == '|' {
+                tokens.push(Token::Pipe);
+            } else if c == '<' {
+                tokens.push(Token::RedirectIn);
+            } else if chars.next_if_eq(&'>').is_some() {
+                tokens.push(Token::AppendOut);
+            } else {
+                tokens.push(Token::RedirectOut);
+            }
+
+            continue;
+        }
+
+        if c == '$' {
+            let value = scan_variable(&mut chars, last_status)?;
+
+            if !value.is_empty() {
+                push_literal_str(token.get_or_insert_with(String::new), &value);
+            }
+
+            continue;
+        }
+
+        let token = token.get_or_insert_with(String::new);
+
+        match c {
+            '\\' => push_literal(token, chars.next().unwrap_or('\\')),
+            '\'' | '"' => scan_quoted(&mut chars, c, token, last_status)?,
+            _ => token.push(c),
+        }
+    }
+
+    tokens.extend(token.map(word_token));
+
+    Ok(tokens)
+}
+
+/// Scan a quoted span up to its closing quote, appending its content to `token`.
+///
+/// Inside double quotes, variables are expanded and a backslash escapes a `"`, a `$` or another
+/// backslash. Single quotes preserve every character literally.
+///
+/// # Arguments
+///
+/// * `chars` - The remaining characters of the input, positioned right after the opening quote.
+/// * `quote` - The opening quote character, either `'` or `"`.
+/// * `token` - The token being built, to which the quoted content is appended.
+/// * `last_status` - The exit status of the last command, which `$?` expands to.
+fn scan_quoted(
+    chars: &mut Peekable<Chars>,
+    quote: char,
+    token: &mut String,
+    last_status: i32,
+) -> Result<(), SyntaxError> {
+    while let Some(c) = chars.next() {
+        match c {
+            _ if c == quote => return Ok(()),
+            '\\' if quote == '"' => match chars.next() {
+                Some(escaped @ ('"' | '$' | '\\')) => push_literal(token, escaped),
+                Some(other) => {
+                    push_literal(token, '\\');
+                    push_literal(token, other);
+                }
+                None => break,
+            },
+            '$' if quote == '"' => push_literal_str(token, &scan_variable(chars, last_status)?),
+            _ => push_literal(token, c),
+        }
+    }
+
+    Err(SyntaxError::UnmatchedQuote(quote))
+}
+
+/// Scan a variable reference following a `$`, returning its value.
+///
+/// A `$` followed by neither a variable name, a `{`, a `?` nor another `$` is kept literally.
+///
+/// # Arguments
+///
+/// * `chars` - The remaining characters of the input, positioned right after the `$`.
+/// * `last_status` - The exit status of the last command, which `$?` expands to.
+fn scan_variable(chars: &mut Peekable<Chars>, last_status: i32) -> Result<String, SyntaxError> {
+    let name = match chars.peek() {
+        Some('$') => {
+            chars.next();
+
+            return Ok(process::id().to_string());
+        }
+        Some('?') => {
+            chars.next();
+
+            return Ok(last_status.to_string());
+        }
+        Some('{') => {
+            chars.next();
+
+            let mut name = String::new();
+
+            loop {
+                match chars.next() {
+                    Some('}') if is_variable_name(&name) => break name,
+                    Some(c) if c != '}' => name.push(c),
+                    _ => return Err(SyntaxError::BadSubstitution),
+                }
+            }
+        }
+        Some(c) if *c == '_' || c.is_ascii_alphabetic() => {
+            let mut name = String::new();
+
+            while let Some(c) = chars.next_if(|c| *c == '_' || c.is_ascii_alphanumeric()) {
+                name.push(c);
+            }
+
+            name
+        }
+        _ => return Ok(String::from("$")),
+    };
+
+    Ok(env::var_os(name)
+        .map(|value| value.to_string_lossy().into_owned())
+        .unwrap_or_default())
+}
+
+/// Appends a quoted or escaped character to `token`, escaping it with a backslash when it has a meaning
+/// in glob patterns or brace expressions, so that it is taken literally.
+fn push_literal(token: &mut String, c: char) {
+    if matches!(c, '*' | '?' | '[' | '{' | '}' | ',' | '\\') {
+        token.push('\\');
+    }
+
+    token.push(c);
+}
+
+fn push_literal_str(token: &mut String, s: &str) {
+    for c in s.chars() {
+        push_literal(token, c);
+    }
+}
+
+/// Returns the token of a scanned word: a pattern when it holds unescaped glob characters or braces, and
+/// a plain word, with its escaping backslashes removed, otherwise.
+pub fn word_token(word: String) -> Token {
+    if glob::is_pattern(&word) || expand::has_braces(&word) {
+        Token::Pattern(word)
+    } else {
+        Token::Word(glob::unescape(&word))
+    }
+}
+
+/// Returns whether `name` is a valid variable name: letters, digits and underscores, not starting
+/// with a digit.
+pub fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
+/// Parse a vector of tokens into a pipeline of commands, with their arguments and redirections.
+///
+/// This function takes a vector of tokens `tokens` representing an input line. The line is split on `|`
+/// into the commands of a pipeline. For each of them, it extracts the file following each redirection
+/// operator as the redirection target, then the first remaining word as the command name and the rest
+/// as its arguments. When several redirections of the same stream are given, the last one wins.
+///
+/// A glob pattern left among the tokens is taken literally. An empty line yields an empty pipeline.
+///
+/// # Arguments
+///
+/// * `tokens` - A vector of tokens representing the input line.
+pub fn parse(tokens: Vec<Token>) -> Result<Vec<Command>, SyntaxError> {
+    let mut pipeline = vec![];
+    let mut words = vec![];
+    let mut input = None;
+    let mut output = None;
+    let mut tokens = tokens.into_iter();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Word(word) => words.push(word),
+            Token::Pattern(pattern) => words.push(glob::unescape(&pattern)),
+            Token::RedirectIn => input = Some(target(&mut tokens)?),
+            Token::RedirectOut | Token::AppendOut => {
+                output = Some(Redirection {
+                    path: target(&mut tokens)?,
+                    append: token == Token::AppendOut,
+                })
+            }
+            Token::Pipe => {
+                let command = build_command(mem::take(&mut words), input.take(), output.take());
+
+                pipeline.push(command.ok_or(SyntaxError::UnexpectedPipe)?);
+            }
+        }
+    }
+
+    match build_command(words, input, output) {
+        Some(command) => pipeline.push(command),
+        None if !pipeline.is_empty() => return Err(SyntaxError::UnexpectedPipe),
+        None => {}
+    }
+
+    Ok(pipeline)
+}
+
+/// Builds a command from its words, the first of which is its name, and its redirections. Returns
+/// `None` when there are no words.
+fn build_command(
+    words: Vec<String>,
+    input: Option<String>,
+    output: Option<Redirection>,
+) -> Option<Command> {
+    let mut words = words.into_iter();
+
+    words.next().map(|name| Command {
+        name,
+        args: words.collect(),
+        input,
+        output,
+    })
+}
+
+/// Returns the word following a redirection operator, naming the file to redirect from or to.
+fn target(tokens: &mut impl Iterator<Item = Token>) -> Result<String, SyntaxError> {
+    match tokens.next() {
+        Some(Token::Word(path)) => Ok(path),
+        Some(Token::Pattern(pattern)) => Ok(glob::unescape(&pattern)),
+        _ => Err(SyntaxError::MissingRedirectionTarget),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::lock_process;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        scan(input.to_string(), 0).unwrap()
+    }
+
+    fn words(words: &[&str]) -> Vec<Token> {
+        words
+            .iter()
+            .map(|word| Token::Word(word.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn escaped_space_keeps_a_single_word() {
+        assert_eq!(tokens(r"echo foo\ bar"), words(&["echo", "foo bar"]));
+    }
+
+    #[test]
+    fn escaped_backslash_is_a_single_backslash() {
+        assert_eq!(tokens(r"echo \\"), words(&["echo", r"\"]));
+    }
+
+    #[test]
+    fn escaped_letters_are_taken_literally() {
+        assert_eq!(tokens(r"echo \t\n"), words(&["echo", "tn"]));
+    }
+
+    #[test]
+    fn trailing_backslash_is_taken_literally() {
+        assert_eq!(tokens(r"echo a\"), words(&["echo", r"a\"]));
+    }
+
+    #[test]
+    fn escaped_operators_are_words() {
+        assert_eq!(
+            tokens(r"echo a\|b \> \*"),
+            words(&["echo", "a|b", ">", "*"])
+        );
+    }
+
+    #[test]
+    fn runs_of_spaces_and_tabs_are_single_delimiters() {
+        assert_eq!(tokens("echo  a \t\t b\t"), words(&["echo", "a", "b"]));
+    }
+
+    #[test]
+    fn leading_and_trailing_whitespace_is_ignored() {
+        assert_eq!(tokens(" \t echo a  "), words(&["echo", "a"]));
+    }
+
+    #[test]
+    fn blank_line_parses_to_no_command() {
+        assert!(tokens(" \t  ").is_empty());
+        assert!(parse(tokens("   ")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn input_redirection_names_a_file() {
+        let pipeline = parse(tokens("cat < in.txt -n")).unwrap();
+
+        assert_eq!(pipeline.len(), 1);
+        assert_eq!(pipeline[0].name, "cat");
+        assert_eq!(pipeline[0].args, ["-n"]);
+        assert_eq!(pipeline[0].input.as_deref(), Some("in.txt"));
+    }
+
+    #[test]
+    fn input_redirection_needs_no_spaces_and_the_last_one_wins() {
+        let pipeline = parse(tokens("cat <a <b")).unwrap();
+
+        assert_eq!(pipeline[0].input.as_deref(), Some("b"));
+        assert!(pipeline[0].args.is_empty());
+    }
+
+    #[test]
+    fn input_redirection_without_file_is_an_error() {
+        assert!(matches!(
+            parse(tokens("cat <")),
+            Err(SyntaxError::MissingRedirectionTarget)
+        ));
+        assert!(matches!(
+            parse(tokens("cat < | wc")),
+            Err(SyntaxError::MissingRedirectionTarget)
+        ));
+    }
+
+    #[test]
+    fn output_redirection_truncates_or_appends() {
+        let truncate = parse(tokens("echo hi > out.txt")).unwrap();
+        let append = parse(tokens("echo hi >>out.txt")).unwrap();
+
+        let truncate = truncate[0].output.as_ref().unwrap();
+        let append = append[0].output.as_ref().unwrap();
+
+        assert_eq!(
+            (truncate.path.as_str(), truncate.append),
+            ("out.txt", false)
+        );
+        assert_eq!((append.path.as_str(), append.append), ("out.txt", true));
+    }
+
+    #[test]
+    fn output_redirection_is_removed_from_the_arguments() {
+        let pipeline = parse(tokens("echo a > out.txt b")).unwrap();
+
+        assert_eq!(pipeline[0].args, ["a", "b"]);
+    }
+
+    #[test]
+    fn output_redirection_without_file_is_an_error() {
+        assert!(matches!(
+            parse(tokens("echo >")),
+            Err(SyntaxError::MissingRedirectionTarget)
+        ));
+    }
+
+    #[test]
+    fn pipes_split_the_line_into_a_pipeline() {
+        let pipeline = parse(tokens("ls -l | grep foo|wc -l")).unwrap();
+        let commands: Vec<(&str, &[String])> = pipeline
+            .iter()
+            .map(|command| (command.name.as_str(), command.args.as_slice()))
+            .collect();
+
+        assert_eq!(
+            commands,
+            [
+                ("ls", &["-l".to_string()][..]),
+                ("grep", &["foo".to_string()][..]),
+                ("wc", &["-l".to_string()][..])
+            ]
+        );
+    }
+
+    #[test]
+    fn pipe_without_command_is_an_error() {
+        for line in ["| wc", "ls |", "ls | | wc"] {
+            assert!(
+                matches!(parse(tokens(line)), Err(SyntaxError::UnexpectedPipe)),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn variables_expand_to_their_value() {
+        let _lock = lock_process();
+        env::set_var("CORE_UTILS_TEST_GREETING", "hello world");
+
+        assert_eq!(
+            tokens("echo $CORE_UTILS_TEST_GREETING ${CORE_UTILS_TEST_GREETING}!"),
+            words(&["echo", "hello world", "hello world!"])
+        );
+
+        env::remove_var("CORE_UTILS_TEST_GREETING");
+    }
+
+    #[test]
+    fn undefined_variables_expand_to_nothing() {
+        let _lock = lock_process();
+        env::remove_var("CORE_UTILS_TEST_UNSET");
+
+        assert_eq!(
+            tokens("echo $CORE_UTILS_TEST_UNSET a\"$CORE_UTILS_TEST_UNSET\"b"),
+            words(&["echo", "ab"])
+        );
+    }
+
+    #[test]
+    fn single_quotes_suppress_expansion_but_double_quotes_allow_it() {
+        let _lock = lock_process();
+        env::set_var("CORE_UTILS_TEST_QUOTED", "value");
+
+        assert_eq!(
+            tokens(r#"echo '$CORE_UTILS_TEST_QUOTED' "$CORE_UTILS_TEST_QUOTED" "\$x""#),
+            words(&["echo", "$CORE_UTILS_TEST_QUOTED", "value", "$x"])
+        );
+
+        env::remove_var("CORE_UTILS_TEST_QUOTED");
+    }
+
+    #[test]
+    fn empty_quotes_are_a_single_empty_argument() {
+        assert_eq!(tokens(r#"echo """#), words(&["echo", ""]));
+        assert_eq!(tokens("echo '' b"), words(&["echo", "", "b"]));
+    }
+
+    #[test]
+    fn escaped_quote_inside_double_quotes_is_literal() {
+        assert_eq!(
+            tokens(r#"echo "a \"b\" c""#),
+            words(&["echo", r#"a "b" c"#])
+        );
+    }
+
+    #[test]
+    fn single_quotes_are_literal() {
+        assert_eq!(
+            tokens(r#"echo 'a  \" | > *'"#),
+            words(&["echo", r#"a  \" | > *"#])
+        );
+    }
+
+    #[test]
+    fn unmatched_quote_is_an_error() {
+        assert!(matches!(
+            scan(String::from("echo 'a"), 0),
+            Err(SyntaxError::UnmatchedQuote('\''))
+        ));
+        assert!(matches!(
+            scan(String::from(r#"echo "a \""#), 0),
+            Err(SyntaxError::UnmatchedQuote('"'))
+        ));
+    }
+
+    #[test]
+    fn double_dollar_is_the_process_id() {
+        assert_eq!(
+            tokens("echo $$"),
+            words(&["echo", &process::id().to_string()])
+        );
+    }
+
+    #[test]
+    fn lone_dollar_is_taken_literally() {
+        assert_eq!(tokens("echo $ a$ $1"), words(&["echo", "$", "a$", "$1"]));
+    }
+
+    #[test]
+    fn unclosed_brace_is_a_bad_substitution() {
+        assert!(matches!(
+            scan(String::from("echo ${HOME"), 0),
+            Err(SyntaxError::BadSubstitution)
+        ));
+        assert!(matches!(
+            scan(String::from("echo ${}"), 0),
+            Err(SyntaxError::BadSubstitution)
+        ));
+    }
+
+    #[test]
+    fn question_mark_is_the_last_status() {
+        assert_eq!(
+            scan(String::from("echo $? \"$?\" '$?'"), 127).unwrap(),
+            words(&["echo", "127", "127", "$?"])
+        );
+    }
+}
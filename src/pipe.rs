@@ -0,0 +1,160 @@
+use std::{
+    io::{self, BufRead, Read, Write},
+    sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender},
+    time::Duration,
+};
+
+use crate::unix::signals;
+
+/// The number of writes a pipe holds before its writer waits for its reader, which bounds the memory used
+/// by a pipeline whatever the pace of its commands.
+const CAPACITY: usize = 16;
+
+/// The delay between two checks for Ctrl-C while waiting for data.
+const INTERRUPT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Creates a pipe, connecting the standard output of a command of a pipeline to the standard input of the
+/// next one, running in another thread.
+pub fn pipe() -> (PipeWriter, PipeReader) {
+    let (sender, receiver) = mpsc::sync_channel(CAPACITY);
+
+    let reader = PipeReader {
+        receiver,
+        chunk: vec![],
+        position: 0,
+    };
+
+    (PipeWriter(sender), reader)
+}
+
+/// The writing end of a pipe. Like with the pipes of the system, writing waits while the pipe is full,
+/// and fails with a broken pipe once the reading end is dropped.
+pub struct PipeWriter(SyncSender<Vec<u8>>);
+
+impl Write for PipeWriter {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        if buffer.is_empty() {
+            return Ok(0);
+        }
+
+        self.0
+            .send(buffer.to_vec())
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The reading end of a pipe, which reaches its end once the writing end is dropped.
+pub struct PipeReader {
+    receiver: Receiver<Vec<u8>>,
+    /// The last data received, read up to `position`.
+    chunk: Vec<u8>,
+    position: usize,
+}
+
+impl Read for PipeReader {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let read = available.len().min(buffer.len());
+
+        buffer[..read].copy_from_slice(&available[..read]);
+        self.consume(read);
+
+        Ok(read)
+    }
+}
+
+impl BufRead for PipeReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.position == self.chunk.len() {
+            match self.receiver.recv_timeout(INTERRUPT_INTERVAL) {
+                Ok(chunk) => {
+                    self.chunk = chunk;
+                    self.position = 0;
+                }
+                // Like a blocking read of the system, waiting for data is interrupted by Ctrl-C.
+                Err(RecvTimeoutError::Timeout) if signals::interrupted() => {
+                    return Err(io::Error::from(io::ErrorKind::Interrupted));
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        Ok(&self.chunk[self.position..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position += amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::testing::lock_process;
+
+    #[test]
+    fn data_written_is_read_until_the_writer_is_dropped() {
+        let _lock = lock_process();
+        let (mut writer, mut reader) = pipe();
+
+        let written = thread::spawn(move || {
+            for i in 0..1000 {
+                writeln!(writer, "line {}", i)?;
+            }
+
+            io::Result::Ok(())
+        });
+
+        let mut lines = 0;
+        let mut line = String::new();
+
+        while reader.read_line(&mut line).unwrap() > 0 {
+            assert_eq!(line, format!("line {}\n", lines));
+            lines += 1;
+            line.clear();
+        }
+
+        written.join().unwrap().unwrap();
+        assert_eq!(lines, 1000);
+    }
+
+    #[test]
+    fn writer_fails_with_a_broken_pipe_once_the_reader_closes_early() {
+        let _lock = lock_process();
+        let (mut writer, mut reader) = pipe();
+
+        // Without a reader, an unbounded pipe would fill the memory rather than failing.
+        let written = thread::spawn(move || loop {
+            writer.write_all(b"y\n")?;
+        });
+
+        let mut first = [0; 4];
+        reader.read_exact(&mut first).unwrap();
+        drop(reader);
+
+        let error: io::Result<()> = written.join().unwrap();
+
+        assert_eq!(first, *b"y\ny\n");
+        assert_eq!(error.unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn writer_waits_while_the_pipe_is_full() {
+        let (mut writer, _reader) = pipe();
+
+        for _ in 0..CAPACITY {
+            writer.write_all(b"full").unwrap();
+        }
+
+        assert!(writer.0.try_send(b"more".to_vec()).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    commands::pwd::logical_current_dir,
+    unix::users::{effective_uid, lookup_user},
+};
+
+/// Asks a yes/no question on the standard error and reads the answer from `input`, the standard input of
+/// the command asking it.
+///
+/// Only an answer starting with `y` or `Y` is taken as a yes, so that a mistyped answer never confirms a
+/// destructive action. The end of the input is taken as a no.
+///
+/// # Arguments
+///
+/// * `question` - The question to ask, printed as is before reading the answer.
+/// * `input` - The input the answer is read from.
+pub fn confirm(question: &str, input: &mut dyn BufRead) -> io::Result<bool> {
+    eprint!("{} ", question);
+    io::stderr().flush()?;
+
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+
+    Ok(answer.trim_start().starts_with(['y', 'Y']))
+}
+
+/// The prompt printed when `$PS1` isn't set.
+const DEFAULT_PROMPT: &str = "$ ";
+
+/// The file holding the name of the host, on Linux.
+const HOSTNAME_FILE: &str = "/proc/sys/kernel/hostname";
+
+/// Returns the prompt of the interactive shell, made of `$PS1` with its escapes expanded, or `$ ` when
+/// it isn't set.
+pub fn prompt() -> String {
+    match env::var("PS1") {
+        Ok(ps1) => expand_prompt(&ps1),
+        Err(_) => DEFAULT_PROMPT.to_string(),
+    }
+}
+
+/// Expands the escapes of a prompt string.
+///
+/// Supported escapes are `\u` (the name of the user), `\h` (the name of the host, up to its first dot),
+/// `\w` (the current directory, with the home directory shortened to `~`), `\$` (`#` for root, `$`
+/// otherwise) and `\\`. Any other escape, as well as a lone trailing backslash, is kept literally.
+///
+/// # Arguments
+///
+/// * `ps1` - The prompt string to expand.
+pub fn expand_prompt(ps1: &str) -> String {
+    let mut prompt = String::new();
+    let mut chars = ps1.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            prompt.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('u') => prompt.push_str(&user_name()),
+            Some('h') => prompt.push_str(&host_name()),
+            Some('w') => prompt.push_str(&working_directory()),
+            Some('$') if effective_uid() == 0 => prompt.push('#'),
+            Some('$') => prompt.push('$'),
+            Some('\\') => prompt.push('\\'),
+            Some(other) => {
+                prompt.push('\\');
+                prompt.push(other);
+            }
+            None => prompt.push('\\'),
+        }
+    }
+
+    prompt
+}
+
+fn user_name() -> String {
+    lookup_user(effective_uid())
+        .or_else(|| env::var("USER").ok())
+        .unwrap_or_default()
+}
+
+fn host_name() -> String {
+    let name = fs::read_to_string(HOSTNAME_FILE).unwrap_or_default();
+
+    name.trim()
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Returns the current directory, as `pwd` prints it, with the home directory shortened to `~`.
+fn working_directory() -> String {
+    let Ok(current_dir) = logical_current_dir() else {
+        return String::new();
+    };
+
+    match env::var_os("HOME").map(PathBuf::from) {
+        Some(home) if home.as_os_str() != "/" && current_dir.starts_with(&home) => {
+            match current_dir.strip_prefix(&home) {
+                Ok(rest) if !rest.as_os_str().is_empty() => {
+                    Path::new("~").join(rest).display().to_string()
+                }
+                _ => "~".to_string(),
+            }
+        }
+        _ => current_dir.display().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{lock_process, CurrentDirGuard, TempDir};
+
+    #[test]
+    fn text_without_escapes_is_kept() {
+        assert_eq!(expand_prompt("> "), "> ");
+        assert_eq!(expand_prompt(""), "");
+    }
+
+    #[test]
+    fn unknown_escapes_and_trailing_backslash_are_kept() {
+        assert_eq!(expand_prompt("\\\\ \\x \\"), "\\ \\x \\");
+    }
+
+    #[test]
+    fn expands_the_user_and_host() {
+        let host = expand_prompt("\\h");
+
+        assert_eq!(expand_prompt("\\u"), user_name());
+        assert!(!host.contains('.'), "{}", host);
+        assert_eq!(
+            expand_prompt("[\\u@\\h]"),
+            format!("[{}@{}]", user_name(), host)
+        );
+    }
+
+    #[test]
+    fn dollar_is_a_hash_for_root() {
+        let expected = if effective_uid() == 0 { "# " } else { "$ " };
+
+        assert_eq!(expand_prompt("\\$ "), expected);
+    }
+
+    #[test]
+    fn working_directory_shortens_the_home_directory() {
+        let _lock = lock_process();
+        let _guard = CurrentDirGuard::new();
+        let home = env::var_os("HOME");
+        let dir = TempDir::new();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        let sub = root.join("sub");
+        fs::create_dir(&sub).unwrap();
+        env::set_current_dir(&sub).unwrap();
+        env::set_var("PWD", &sub);
+
+        env::set_var("HOME", &root);
+        let in_home = expand_prompt("\\w");
+        env::set_var("HOME", &sub);
+        let at_home = expand_prompt("\\w");
+        env::set_var("HOME", root.join("su"));
+        let elsewhere = expand_prompt("\\w");
+
+        match home {
+            Some(home) => env::set_var("HOME", home),
+            None => env::remove_var("HOME"),
+        }
+
+        assert_eq!(in_home, "~/sub");
+        assert_eq!(at_home, "~");
+        assert_eq!(elsewhere, sub.display().to_string());
+    }
+
+    #[test]
+    fn ps1_replaces_the_default_prompt() {
+        let _lock = lock_process();
+        let ps1 = env::var_os("PS1");
+
+        env::remove_var("PS1");
+        let default = prompt();
+        env::set_var("PS1", "custom \\\\> ");
+        let custom = prompt();
+
+        match ps1 {
+            Some(ps1) => env::set_var("PS1", ps1),
+            None => env::remove_var("PS1"),
+        }
+
+        assert_eq!(default, DEFAULT_PROMPT);
+        assert_eq!(custom, "custom \\> ");
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    env, fs,
+    io::{self, Cursor},
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard,
+    },
+};
+
+use crate::commands::{Status, Streams};
+
+/// Serializes the tests changing what the whole process shares: its current directory, its environment
+/// and the interrupt flag, along with the tests relying on the flag staying cleared, like those of pipes.
+static PROCESS_LOCK: Mutex<()> = Mutex::new(());
+
+/// Takes the lock of the tests changing the state of the whole process, for as long as it is held.
+pub fn lock_process() -> MutexGuard<'static, ()> {
+    // A test failing while holding the lock doesn't make the state any less usable by the next one.
+    PROCESS_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Returns the arguments of a command as owned strings.
+pub fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+/// Runs a command reading `input` on its standard input, returning its status and what it printed on its
+/// standard output.
+pub fn run(
+    input: &str,
+    command: impl FnOnce(&mut Streams) -> io::Result<Status>,
+) -> (Status, String) {
+    let mut stdin = Cursor::new(input.as_bytes().to_vec());
+    let mut stdout = vec![];
+    let mut streams = Streams {
+        stdin: &mut stdin,
+        stdout: &mut stdout,
+        terminal: false,
+        shell_stdin: false,
+    };
+
+    let status = command(&mut streams).unwrap();
+
+    (status, String::from_utf8_lossy(&stdout).into_owned())
+}
+
+/// A directory created for a test, removed along with its content when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new() -> TempDir {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let name = format!(
+            "core-utils-test-{}-{}",
+            process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = env::temp_dir().join(name);
+
+        fs::create_dir_all(&path).unwrap();
+
+        TempDir(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Returns the path of `name` in the directory, as an argument of a command.
+    pub fn join(&self, name: &str) -> String {
+        self.0.join(name).to_string_lossy().into_owned()
+    }
+
+    /// Creates the file `name` in the directory, holding `content`, and returns its path.
+    pub fn write(&self, name: &str, content: &str) -> String {
+        let path = self.join(name);
+
+        fs::write(&path, content).unwrap();
+
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Restores the current directory and `$PWD` of the process when dropped, for the tests changing them.
+pub struct CurrentDirGuard {
+    dir: PathBuf,
+    pwd: Option<std::ffi::OsString>,
+}
+
+impl CurrentDirGuard {
+    pub fn new() -> CurrentDirGuard {
+        CurrentDirGuard {
+            dir: env::current_dir().unwrap(),
+            pwd: env::var_os("PWD"),
+        }
+    }
+}
+
+impl Drop for CurrentDirGuard {
+    fn drop(&mut self) {
+        let _ = env::set_current_dir(&self.dir);
+
+        match &self.pwd {
+            Some(pwd) => env::set_var("PWD", pwd),
+            None => env::remove_var("PWD"),
+        }
+    }
+}
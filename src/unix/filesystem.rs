@@ -0,0 +1,59 @@
+use std::{
+    ffi::CString,
+    io,
+    os::{
+        raw::{c_char, c_int, c_ulong},
+        unix::ffi::OsStrExt,
+    },
+    path::Path,
+};
+
+/// The statistics of a filesystem, as filled by `statvfs` on Linux.
+#[repr(C)]
+#[derive(Default)]
+struct StatVfs {
+    f_bsize: c_ulong,
+    f_frsize: c_ulong,
+    f_blocks: u64,
+    f_bfree: u64,
+    f_bavail: u64,
+    f_files: u64,
+    f_ffree: u64,
+    f_favail: u64,
+    f_fsid: c_ulong,
+    f_flag: c_ulong,
+    f_namemax: c_ulong,
+    f_spare: [c_int; 6],
+}
+
+extern "C" {
+    fn statvfs(path: *const c_char, buf: *mut StatVfs) -> c_int;
+}
+
+/// The space of a filesystem, in bytes.
+pub struct Usage {
+    pub total: u64,
+    pub free: u64,
+    /// The free space available to unprivileged users, which excludes the space reserved for root.
+    pub available: u64,
+}
+
+/// Returns the space of the filesystem holding `path`.
+pub fn usage(path: &Path) -> io::Result<Usage> {
+    let path = CString::new(path.as_os_str().as_bytes())?;
+    let mut stats = StatVfs::default();
+
+    // SAFETY: `path` is a valid C string, and `statvfs` only writes a `statvfs` struct, which `StatVfs`
+    // mirrors.
+    if unsafe { statvfs(path.as_ptr(), &mut stats) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let block_size = stats.f_frsize;
+
+    Ok(Usage {
+        total: stats.f_blocks * block_size,
+        free: stats.f_bfree * block_size,
+        available: stats.f_bavail * block_size,
+    })
+}
@@ -1 +1,7 @@
+pub mod filesystem;
+pub mod path;
 pub mod permissions;
+pub mod signals;
+pub mod terminal;
+pub mod time;
+pub mod users;
@@ -0,0 +1,28 @@
+use std::{
+    env,
+    ffi::OsStr,
+    fs,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+};
+
+/// Returns the executable files named `name` in the directories of `path`, a list of directories in the
+/// format of `$PATH`, in the order of the directories. An empty directory stands for the current one.
+pub fn find_executables(name: &str, path: &OsStr) -> Vec<PathBuf> {
+    env::split_paths(path)
+        .map(|directory| {
+            if directory.as_os_str().is_empty() {
+                PathBuf::from(".").join(name)
+            } else {
+                directory.join(name)
+            }
+        })
+        .filter(|candidate| is_executable(candidate))
+        .collect()
+}
+
+/// Returns whether `path` is a regular file, or a symlink to one, with an execute permission bit set.
+pub fn is_executable(path: &Path) -> bool {
+    fs::metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
@@ -1,9 +1,16 @@
-use std::{ops::BitAnd, os::unix::fs::PermissionsExt};
+use std::{
+    fs,
+    ops::BitAnd,
+    os::unix::fs::{FileTypeExt, PermissionsExt},
+};
 
 enum PermissionFlag {
-    READ = 0o4,
-    WRITE = 0o2,
-    EXECUTE = 0o1,
+    Read = 0o4,
+    Write = 0o2,
+    Execute = 0o1,
+    SetUid = 0o4000,
+    SetGid = 0o2000,
+    Sticky = 0o1000,
 }
 
 impl BitAnd<PermissionFlag> for u32 {
@@ -14,19 +21,32 @@ impl BitAnd<PermissionFlag> for u32 {
     }
 }
 
-pub struct Permission(u32);
+/// The `rwx` bits of one class of users, along with the character shown in the execute slot when the
+/// special bit of that class (setuid, setgid or sticky) is set.
+pub struct Permission(u32, Option<char>);
 
 impl Permission {
     pub fn readable(&self) -> bool {
-        self.0 & PermissionFlag::READ > 0
+        self.0 & PermissionFlag::Read > 0
     }
 
     pub fn writable(&self) -> bool {
-        self.0 & PermissionFlag::WRITE > 0
+        self.0 & PermissionFlag::Write > 0
     }
 
     pub fn executable(&self) -> bool {
-        self.0 & PermissionFlag::EXECUTE > 0
+        self.0 & PermissionFlag::Execute > 0
+    }
+
+    /// Returns the character shown in the execute slot: `x` or `-`, unless the special bit is set, in
+    /// which case its character is shown in lowercase if executable and in uppercase otherwise.
+    fn execute_char(&self) -> char {
+        match (self.1, self.executable()) {
+            (Some(special), true) => special,
+            (Some(special), false) => special.to_ascii_uppercase(),
+            (None, true) => 'x',
+            (None, false) => '-',
+        }
     }
 }
 
@@ -37,11 +57,37 @@ impl std::fmt::Display for Permission {
             "{}{}{}",
             if self.readable() { 'r' } else { '-' },
             if self.writable() { 'w' } else { '-' },
-            if self.executable() { 'x' } else { '-' }
+            self.execute_char()
         )
     }
 }
 
+/// A file type, displayed as the character starting the mode string of `ls -l`.
+pub struct FileType(pub fs::FileType);
+
+impl FileType {
+    /// Returns the character standing for the file type in the mode string of `ls -l`.
+    pub fn symbol(&self) -> char {
+        let file_type = self.0;
+
+        match file_type {
+            _ if file_type.is_dir() => 'd',
+            _ if file_type.is_symlink() => 'l',
+            _ if file_type.is_block_device() => 'b',
+            _ if file_type.is_char_device() => 'c',
+            _ if file_type.is_socket() => 's',
+            _ if file_type.is_fifo() => 'p',
+            _ => '-',
+        }
+    }
+}
+
+impl std::fmt::Display for FileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
 pub trait UnixPermissions {
     fn owner(&self) -> Permission;
     fn group(&self) -> Permission;
@@ -50,14 +96,296 @@ pub trait UnixPermissions {
 
 impl UnixPermissions for std::fs::Permissions {
     fn owner(&self) -> Permission {
-        Permission((self.mode() & 0o700) >> 6)
+        let setuid = (self.mode() & PermissionFlag::SetUid > 0).then_some('s');
+
+        Permission((self.mode() & 0o700) >> 6, setuid)
     }
 
     fn group(&self) -> Permission {
-        Permission((self.mode() & 0o70) >> 3)
+        let setgid = (self.mode() & PermissionFlag::SetGid > 0).then_some('s');
+
+        Permission((self.mode() & 0o70) >> 3, setgid)
     }
 
     fn other(&self) -> Permission {
-        Permission(self.mode() & 0o7)
+        let sticky = (self.mode() & PermissionFlag::Sticky > 0).then_some('t');
+
+        Permission(self.mode() & 0o7, sticky)
+    }
+}
+
+/// The file type bits of a mode denoting a directory.
+const DIRECTORY_TYPE: u32 = 0o040000;
+
+/// The mask of the file type bits of a mode.
+const TYPE_MASK: u32 = 0o170000;
+
+/// An error raised while parsing an invalid mode string.
+#[derive(Debug)]
+pub enum ParseModeError {
+    Empty,
+    OctalOutOfRange(String),
+    InvalidClause(String),
+}
+
+impl std::fmt::Display for ParseModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseModeError::Empty => write!(f, "invalid mode: empty mode"),
+            ParseModeError::OctalOutOfRange(mode) => {
+                write!(f, "invalid mode: '{}' is out of range", mode)
+            }
+            ParseModeError::InvalidClause(clause) => {
+                write!(f, "invalid mode: '{}' is not a valid clause", clause)
+            }
+        }
+    }
+}
+
+/// A parser for the mode strings accepted by `chmod`, in octal or symbolic form.
+pub struct Mode;
+
+impl Mode {
+    /// Parses a mode string into mode bits, starting from no permission at all.
+    ///
+    /// The string is either an octal number like `755`, or a comma separated list of symbolic clauses
+    /// like `u+rwx,go-w`. Each clause is made of the classes `u`, `g`, `o` or `a` (all of them when
+    /// omitted), followed by one or more operations: an operator `+`, `-` or `=` and the permissions
+    /// `r`, `w`, `x`, `X`, `s` and `t`, or the class `u`, `g` or `o` to copy the permissions from.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode string to parse.
+    pub fn parse(mode: &str) -> Result<u32, ParseModeError> {
+        Mode::parse_relative(mode, 0)
+    }
+
+    /// Parses a mode string into the mode bits obtained by applying it to `current`.
+    ///
+    /// An octal mode replaces the permission bits of `current`, while symbolic clauses change them
+    /// relatively. The file type bits of `current` are kept, and tell whether `X` applies.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode string to parse.
+    /// * `current` - The mode to which the mode string applies.
+    pub fn parse_relative(mode: &str, current: u32) -> Result<u32, ParseModeError> {
+        if mode.is_empty() {
+            return Err(ParseModeError::Empty);
+        }
+
+        if mode.chars().all(|c| c.is_digit(8)) {
+            return match u32::from_str_radix(mode, 8) {
+                Ok(bits) if bits <= 0o7777 => Ok((current & !0o7777) | bits),
+                _ => Err(ParseModeError::OctalOutOfRange(mode.to_string())),
+            };
+        }
+
+        mode.split(',').try_fold(current, |current, clause| {
+            apply_clause(clause, current)
+                .ok_or_else(|| ParseModeError::InvalidClause(clause.to_string()))
+        })
+    }
+}
+
+/// Applies a single symbolic clause to `current`, returning `None` if the clause is invalid.
+fn apply_clause(clause: &str, current: u32) -> Option<u32> {
+    let operations_start = clause.find(['+', '-', '='])?;
+    let (classes, operations) = clause.split_at(operations_start);
+
+    let mut who = 0;
+
+    for class in classes.chars() {
+        who |= match class {
+            'u' => 0o700,
+            'g' => 0o070,
+            'o' => 0o007,
+            'a' => 0o777,
+            _ => return None,
+        };
+    }
+
+    if who == 0 {
+        who = 0o777;
+    }
+
+    let mut mode = current;
+    let mut chars = operations.chars().peekable();
+
+    while let Some(operator) = chars.next() {
+        let mut bits = 0;
+
+        while let Some(&permission) = chars.peek() {
+            bits |= match permission {
+                'r' => 0o444 & who,
+                'w' => 0o222 & who,
+                'x' => 0o111 & who,
+                'X' if mode & TYPE_MASK == DIRECTORY_TYPE || mode & 0o111 > 0 => 0o111 & who,
+                'X' => 0,
+                's' => {
+                    (if who & 0o700 > 0 { 0o4000 } else { 0 })
+                        | (if who & 0o070 > 0 { 0o2000 } else { 0 })
+                }
+                't' => {
+                    if who & 0o007 > 0 {
+                        0o1000
+                    } else {
+                        0
+                    }
+                }
+                'u' => copy_class((mode & 0o700) >> 6, who),
+                'g' => copy_class((mode & 0o070) >> 3, who),
+                'o' => copy_class(mode & 0o007, who),
+                '+' | '-' | '=' => break,
+                _ => return None,
+            };
+
+            chars.next();
+        }
+
+        mode = match operator {
+            '+' => mode | bits,
+            '-' => mode & !bits,
+            '=' => (mode & !class_bits(who)) | bits,
+            _ => return None,
+        };
+    }
+
+    Some(mode)
+}
+
+/// Spreads the `rwx` bits of one class over every class selected by `who`.
+fn copy_class(bits: u32, who: u32) -> u32 {
+    (bits << 6 | bits << 3 | bits) & who
+}
+
+/// Returns every bit cleared by the `=` operator for the classes selected by `who`, which includes their
+/// special bit.
+fn class_bits(who: u32) -> u32 {
+    let mut bits = who;
+
+    if who & 0o700 > 0 {
+        bits |= 0o4000;
+    }
+
+    if who & 0o070 > 0 {
+        bits |= 0o2000;
+    }
+
+    if who & 0o007 > 0 {
+        bits |= 0o1000;
+    }
+
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::{fs::symlink, net::UnixListener};
+
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn symbol(path: &str) -> char {
+        FileType(fs::symlink_metadata(path).unwrap().file_type()).symbol()
+    }
+
+    #[test]
+    fn file_types_have_their_ls_character() {
+        let dir = TempDir::new();
+        let file = dir.write("file", "");
+        symlink(&file, dir.join("link")).unwrap();
+        let _socket = UnixListener::bind(dir.join("socket")).unwrap();
+
+        assert_eq!(symbol(&file), '-');
+        assert_eq!(symbol(&dir.join("")), 'd');
+        assert_eq!(symbol(&dir.join("link")), 'l');
+        assert_eq!(symbol(&dir.join("socket")), 's');
+        assert_eq!(symbol("/dev/null"), 'c');
+    }
+
+    #[test]
+    fn symlinks_and_sockets_are_told_apart() {
+        let dir = TempDir::new();
+        symlink(dir.join("socket"), dir.join("link")).unwrap();
+        let _socket = UnixListener::bind(dir.join("socket")).unwrap();
+
+        assert_ne!(symbol(&dir.join("link")), symbol(&dir.join("socket")));
+        assert_eq!(
+            FileType(fs::symlink_metadata(dir.join("link")).unwrap().file_type()).to_string(),
+            "l"
+        );
+    }
+
+    fn mode_string(mode: u32) -> String {
+        let permissions = fs::Permissions::from_mode(mode);
+
+        format!(
+            "{}{}{}",
+            permissions.owner(),
+            permissions.group(),
+            permissions.other()
+        )
+    }
+
+    #[test]
+    fn special_bits_replace_the_execute_character() {
+        assert_eq!(mode_string(0o755), "rwxr-xr-x");
+        assert_eq!(mode_string(0o4755), "rwsr-xr-x");
+        assert_eq!(mode_string(0o2755), "rwxr-sr-x");
+        assert_eq!(mode_string(0o1755), "rwxr-xr-t");
+    }
+
+    #[test]
+    fn special_bits_without_execute_are_uppercase() {
+        assert_eq!(mode_string(0o4644), "rwSr--r--");
+        assert_eq!(mode_string(0o2644), "rw-r-Sr--");
+        assert_eq!(mode_string(0o1644), "rw-r--r-T");
+    }
+
+    #[test]
+    fn parses_octal_modes() {
+        assert_eq!(Mode::parse("755").unwrap(), 0o755);
+        assert_eq!(Mode::parse("0644").unwrap(), 0o644);
+        assert_eq!(Mode::parse("4755").unwrap(), 0o4755);
+        assert_eq!(Mode::parse_relative("600", 0o100755).unwrap(), 0o100600);
+    }
+
+    #[test]
+    fn parses_symbolic_modes() {
+        assert_eq!(Mode::parse("u+rwx,go+rx").unwrap(), 0o755);
+        assert_eq!(Mode::parse("a=r").unwrap(), 0o444);
+        assert_eq!(Mode::parse("+x").unwrap(), 0o111);
+        assert_eq!(Mode::parse_relative("go-w", 0o666).unwrap(), 0o644);
+        assert_eq!(Mode::parse_relative("u=g", 0o654).unwrap(), 0o554);
+        assert_eq!(Mode::parse_relative("u+s,+t", 0o755).unwrap(), 0o5755);
+    }
+
+    #[test]
+    fn capital_x_only_applies_to_directories_and_executables() {
+        assert_eq!(Mode::parse_relative("a+X", 0o100644).unwrap(), 0o100644);
+        assert_eq!(Mode::parse_relative("a+X", 0o100744).unwrap(), 0o100755);
+        assert_eq!(Mode::parse_relative("a+X", 0o040644).unwrap(), 0o040755);
+    }
+
+    #[test]
+    fn rejects_invalid_modes() {
+        assert!(matches!(Mode::parse(""), Err(ParseModeError::Empty)));
+        assert!(matches!(
+            Mode::parse("17777"),
+            Err(ParseModeError::OctalOutOfRange(_))
+        ));
+        assert!(matches!(
+            Mode::parse("u+rwz"),
+            Err(ParseModeError::InvalidClause(clause)) if clause == "u+rwz"
+        ));
+        assert!(matches!(
+            Mode::parse("q+r"),
+            Err(ParseModeError::InvalidClause(_))
+        ));
+        assert!(matches!(
+            Mode::parse("u+r,,"),
+            Err(ParseModeError::InvalidClause(_))
+        ));
     }
 }
@@ -0,0 +1,131 @@
+use std::{
+    io::{self, BufRead, Read, Write},
+    os::{
+        raw::c_int,
+        unix::thread::{JoinHandleExt, RawPthread},
+    },
+    sync::atomic::{AtomicBool, Ordering},
+    thread::JoinHandle,
+};
+
+/// The number of the signal sent by the terminal for Ctrl-C.
+const SIGINT: c_int = 2;
+
+extern "C" {
+    fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+    fn siginterrupt(signum: c_int, flag: c_int) -> c_int;
+    fn pthread_kill(thread: RawPthread, signum: c_int) -> c_int;
+}
+
+/// Whether Ctrl-C was pressed since the flag was last cleared.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn handle_interrupt(_: c_int) {
+    // Only async-signal-safe operations are allowed here, which atomic stores are.
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Catches the signal sent by Ctrl-C, so that it interrupts the running command rather than the shell.
+///
+/// External programs still get the signal from the terminal, since caught signals are reset to their
+/// default action when a program is executed. Blocking reads are interrupted by the signal rather than
+/// resumed, so that waiting for input can be interrupted too.
+pub fn catch_interrupts() -> io::Result<()> {
+    // SAFETY: the handler only stores to an atomic, and both calls only change the disposition of SIGINT.
+    let failed =
+        unsafe { signal(SIGINT, handle_interrupt) == usize::MAX || siginterrupt(SIGINT, 1) == -1 };
+
+    if failed {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// Sends the signal of Ctrl-C to `thread`, so that a blocking read or write it is waiting on is
+/// interrupted too. The signal sent by the terminal is only delivered to one of the threads of the shell,
+/// which may not be the one waiting.
+pub fn interrupt_thread<T>(thread: &JoinHandle<T>) {
+    // SAFETY: the thread wasn't joined yet, so its id is still valid, and the signal is only sent once it
+    // was caught, by a handler storing to an atomic.
+    unsafe {
+        pthread_kill(thread.as_pthread_t(), SIGINT);
+    }
+}
+
+/// Returns whether Ctrl-C was pressed since the last call to [`clear_interrupt`].
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Sets the interrupt flag as Ctrl-C does, without sending any signal.
+#[cfg(test)]
+pub fn interrupt() {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Clears the interrupt flag, returning whether it was set.
+pub fn clear_interrupt() -> bool {
+    INTERRUPTED.swap(false, Ordering::SeqCst)
+}
+
+/// The error returned by the streams of a command once Ctrl-C is pressed.
+fn interrupted_error() -> io::Error {
+    io::Error::other("interrupted")
+}
+
+/// A stream that fails once Ctrl-C is pressed, which stops the commands reading or writing it.
+pub struct Interruptible<T>(pub T);
+
+impl<T> Interruptible<T> {
+    /// Fails if Ctrl-C was pressed, otherwise runs `operation`, whose reads or writes interrupted by the
+    /// signal fail rather than being retried.
+    fn check<R>(&mut self, operation: impl FnOnce(&mut T) -> io::Result<R>) -> io::Result<R> {
+        if interrupted() {
+            return Err(interrupted_error());
+        }
+
+        match operation(&mut self.0) {
+            Err(e) if e.kind() == io::ErrorKind::Interrupted && interrupted() => {
+                Err(interrupted_error())
+            }
+            result => result,
+        }
+    }
+}
+
+impl<T: Read> Read for Interruptible<T> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        self.check(|reader| reader.read(buffer))
+    }
+}
+
+impl<T: BufRead> BufRead for Interruptible<T> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if interrupted() {
+            return Err(interrupted_error());
+        }
+
+        // Written without `check`, whose closure cannot return a borrow of the stream.
+        match self.0.fill_buf() {
+            Err(e) if e.kind() == io::ErrorKind::Interrupted && interrupted() => {
+                Err(interrupted_error())
+            }
+            result => result,
+        }
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.0.consume(amount);
+    }
+}
+
+impl<T: Write> Write for Interruptible<T> {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        self.check(|writer| writer.write(buffer))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.check(|writer| writer.flush())
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    env, io,
+    os::raw::{c_int, c_uint, c_ulong, c_ushort},
+};
+
+/// The `ioctl` request querying the window size of a terminal on Linux.
+const TIOCGWINSZ: c_ulong = 0x5413;
+
+/// The window size of a terminal, as filled by the `TIOCGWINSZ` request.
+#[repr(C)]
+#[derive(Default)]
+struct WinSize {
+    ws_row: c_ushort,
+    ws_col: c_ushort,
+    ws_xpixel: c_ushort,
+    ws_ypixel: c_ushort,
+}
+
+/// The number of control characters of a `termios` struct on Linux.
+const NCCS: usize = 32;
+
+/// The local mode flag enabling the canonical, line by line, input mode.
+const ICANON: c_uint = 0o2;
+
+/// The local mode flag echoing the input characters.
+const ECHO: c_uint = 0o10;
+
+/// The indexes of the control characters setting how many bytes a read waits for, and for how long.
+const VTIME: usize = 5;
+const VMIN: usize = 6;
+
+/// The `tcsetattr` action applying the new attributes immediately.
+const TCSANOW: c_int = 0;
+
+/// The attributes of a terminal, as filled by `tcgetattr` on Linux.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Termios {
+    c_iflag: c_uint,
+    c_oflag: c_uint,
+    c_cflag: c_uint,
+    c_lflag: c_uint,
+    c_line: u8,
+    c_cc: [u8; NCCS],
+    c_ispeed: c_uint,
+    c_ospeed: c_uint,
+}
+
+extern "C" {
+    fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+    fn tcgetattr(fd: c_int, termios: *mut Termios) -> c_int;
+    fn tcsetattr(fd: c_int, action: c_int, termios: *const Termios) -> c_int;
+}
+
+/// The terminal attached to the standard input switched to raw mode, where input is read byte by byte
+/// without being echoed. The original mode is restored when dropped.
+pub struct RawMode {
+    original: Termios,
+}
+
+impl RawMode {
+    /// Switches the terminal attached to the standard input to raw mode. Signals such as the one sent by
+    /// Ctrl-C are still generated by the terminal.
+    pub fn enable() -> io::Result<RawMode> {
+        let mut original = Termios {
+            c_iflag: 0,
+            c_oflag: 0,
+            c_cflag: 0,
+            c_lflag: 0,
+            c_line: 0,
+            c_cc: [0; NCCS],
+            c_ispeed: 0,
+            c_ospeed: 0,
+        };
+
+        // SAFETY: `tcgetattr` only writes a `termios` struct, which `Termios` mirrors.
+        if unsafe { tcgetattr(0, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut raw = original;
+        raw.c_lflag &= !(ICANON | ECHO);
+        raw.c_cc[VMIN] = 1;
+        raw.c_cc[VTIME] = 0;
+
+        // SAFETY: `raw` is a valid `termios` struct, obtained from `tcgetattr`.
+        if unsafe { tcsetattr(0, TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(RawMode { original })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        // SAFETY: `original` is a valid `termios` struct, obtained from `tcgetattr`.
+        unsafe { tcsetattr(0, TCSANOW, &self.original) };
+    }
+}
+
+/// Returns the width of the terminal in columns.
+///
+/// The `COLUMNS` environment variable takes precedence, then the size reported by the terminal attached
+/// to the standard output. Returns `None` if neither is available.
+pub fn width() -> Option<usize> {
+    if let Some(columns) = env::var("COLUMNS").ok().and_then(|c| c.parse().ok()) {
+        return Some(columns);
+    }
+
+    let mut size = WinSize::default();
+
+    // SAFETY: `TIOCGWINSZ` only writes a `winsize` struct, which `WinSize` mirrors.
+    let result = unsafe { ioctl(1, TIOCGWINSZ, &mut size as *mut WinSize) };
+
+    if result == 0 && size.ws_col > 0 {
+        Some(size.ws_col as usize)
+    } else {
+        None
+    }
+}
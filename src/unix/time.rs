@@ -0,0 +1,170 @@
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_int, c_long},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The abbreviated names of the months, as printed by `ls -l`.
+pub const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The abbreviated names of the days of the week, starting on Sunday.
+pub const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// The broken-down time filled by `localtime_r` and `gmtime_r`, mirroring the glibc `struct tm`.
+#[repr(C)]
+struct Tm {
+    tm_sec: c_int,
+    tm_min: c_int,
+    tm_hour: c_int,
+    tm_mday: c_int,
+    tm_mon: c_int,
+    tm_year: c_int,
+    tm_wday: c_int,
+    tm_yday: c_int,
+    tm_isdst: c_int,
+    tm_gmtoff: c_long,
+    tm_zone: *const c_char,
+}
+
+extern "C" {
+    fn localtime_r(timep: *const i64, result: *mut Tm) -> *mut Tm;
+    fn gmtime_r(timep: *const i64, result: *mut Tm) -> *mut Tm;
+    fn mktime(tm: *mut Tm) -> i64;
+}
+
+/// A calendar date and time of day.
+pub struct DateTime {
+    pub year: i32,
+    /// The month, from 1 to 12.
+    pub month: u32,
+    /// The day of the month, from 1 to 31.
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl DateTime {
+    /// Converts a time into the local time zone, as configured by `TZ` or `/etc/localtime`.
+    ///
+    /// Returns `None` if the time cannot be represented.
+    pub fn local(time: SystemTime) -> Option<DateTime> {
+        broken_down(time, localtime_r).map(DateTime::from_tm)
+    }
+
+    /// Converts a time into Coordinated Universal Time.
+    ///
+    /// Returns `None` if the time cannot be represented.
+    pub fn utc(time: SystemTime) -> Option<DateTime> {
+        broken_down(time, gmtime_r).map(DateTime::from_tm)
+    }
+
+    fn from_tm(tm: Tm) -> DateTime {
+        DateTime {
+            year: tm.tm_year + 1900,
+            month: tm.tm_mon as u32 + 1,
+            day: tm.tm_mday as u32,
+            hour: tm.tm_hour as u32,
+            minute: tm.tm_min as u32,
+            second: tm.tm_sec as u32,
+        }
+    }
+
+    /// Returns the day of the week, from 0 for Sunday to 6 for Saturday.
+    pub fn weekday(&self) -> u32 {
+        // Sakamoto's method, which counts January and February as the last months of the previous year.
+        const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+        let year = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let days = year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + OFFSETS[self.month as usize - 1]
+            + self.day as i32;
+
+        days.rem_euclid(7) as u32
+    }
+
+    /// Converts a date and time of the local time zone into a time.
+    ///
+    /// Returns `None` if the date doesn't exist in the local time zone or cannot be represented.
+    pub fn to_local_time(&self) -> Option<SystemTime> {
+        // SAFETY: `Tm` mirrors the glibc `struct tm`, and a null `tm_zone` is never read by `mktime`.
+        let seconds = unsafe {
+            let mut tm: Tm = std::mem::zeroed();
+
+            tm.tm_year = self.year - 1900;
+            tm.tm_mon = self.month as c_int - 1;
+            tm.tm_mday = self.day as c_int;
+            tm.tm_hour = self.hour as c_int;
+            tm.tm_min = self.minute as c_int;
+            tm.tm_sec = self.second as c_int;
+            // Lets `mktime` find out whether daylight saving time applies.
+            tm.tm_isdst = -1;
+
+            mktime(&mut tm)
+        };
+
+        if seconds == -1 {
+            return None;
+        }
+
+        from_unix_seconds(seconds)
+    }
+}
+
+/// Returns the abbreviation of the local time zone at `time`, like `CET` or `CEST`.
+pub fn local_zone(time: SystemTime) -> Option<String> {
+    let tm = broken_down(time, localtime_r)?;
+
+    if tm.tm_zone.is_null() {
+        return None;
+    }
+
+    // SAFETY: a non-null `tm_zone` set by `localtime_r` points to a C string owned by the C library,
+    // which stays valid until the time zone is changed.
+    let zone = unsafe { CStr::from_ptr(tm.tm_zone) };
+
+    Some(zone.to_string_lossy().into_owned())
+}
+
+/// Breaks `time` down into its calendar fields with `localtime_r` or `gmtime_r`.
+fn broken_down(
+    time: SystemTime,
+    convert: unsafe extern "C" fn(*const i64, *mut Tm) -> *mut Tm,
+) -> Option<Tm> {
+    let seconds = unix_seconds(time);
+
+    // SAFETY: `Tm` mirrors the glibc `struct tm`, which both conversions only write.
+    unsafe {
+        let mut tm: Tm = std::mem::zeroed();
+
+        if convert(&seconds, &mut tm).is_null() {
+            return None;
+        }
+
+        Some(tm)
+    }
+}
+
+/// Returns the time lying `seconds` after the Unix epoch, or before it if negative.
+pub fn from_unix_seconds(seconds: i64) -> Option<SystemTime> {
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+    }
+}
+
+/// Returns the number of seconds between the Unix epoch and `time`, negative for earlier times.
+pub fn unix_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs_f64().ceil() as i64),
+    }
+}
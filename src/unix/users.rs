@@ -0,0 +1,120 @@
+use std::{collections::HashMap, fs, sync::OnceLock};
+
+extern "C" {
+    fn geteuid() -> u32;
+    fn getegid() -> u32;
+    fn getgroups(size: i32, list: *mut u32) -> i32;
+}
+
+/// The names of the users, by uid, parsed once from `/etc/passwd`.
+static USERS: OnceLock<HashMap<u32, String>> = OnceLock::new();
+
+/// The names of the groups, by gid, parsed once from `/etc/group`.
+static GROUPS: OnceLock<HashMap<u32, String>> = OnceLock::new();
+
+/// Returns the effective uid of the process, which determines its privileges.
+pub fn effective_uid() -> u32 {
+    // SAFETY: `geteuid` has no preconditions and cannot fail.
+    unsafe { geteuid() }
+}
+
+/// Returns the effective gid of the process.
+pub fn effective_gid() -> u32 {
+    // SAFETY: `getegid` has no preconditions and cannot fail.
+    unsafe { getegid() }
+}
+
+/// Returns the supplementary gids of the process, or an empty list if they cannot be read.
+pub fn supplementary_gids() -> Vec<u32> {
+    // SAFETY: with a size of zero, `getgroups` only returns the number of groups without writing any.
+    let count = unsafe { getgroups(0, std::ptr::null_mut()) };
+    let mut gids = vec![0; count.max(0) as usize];
+
+    // SAFETY: `gids` has room for `count` gids, the size given to `getgroups`.
+    let count = unsafe { getgroups(gids.len() as i32, gids.as_mut_ptr()) };
+    gids.truncate(count.max(0) as usize);
+
+    gids
+}
+
+/// Returns the name of the user with the given uid, if `/etc/passwd` has one.
+pub fn lookup_user(uid: u32) -> Option<String> {
+    USERS
+        .get_or_init(|| parse_database("/etc/passwd"))
+        .get(&uid)
+        .cloned()
+}
+
+/// Returns the name of the group with the given gid, if `/etc/group` has one.
+pub fn lookup_group(gid: u32) -> Option<String> {
+    GROUPS
+        .get_or_init(|| parse_database("/etc/group"))
+        .get(&gid)
+        .cloned()
+}
+
+/// Returns the uid of the user named `name`, if `/etc/passwd` has one.
+pub fn lookup_uid(name: &str) -> Option<u32> {
+    find_id(USERS.get_or_init(|| parse_database("/etc/passwd")), name)
+}
+
+/// Returns the gid of the group named `name`, if `/etc/group` has one.
+pub fn lookup_gid(name: &str) -> Option<u32> {
+    find_id(GROUPS.get_or_init(|| parse_database("/etc/group")), name)
+}
+
+/// Returns the primary gid of the user named `name`, from the fourth field of its `/etc/passwd` entry.
+pub fn primary_gid(name: &str) -> Option<u32> {
+    let content = fs::read_to_string("/etc/passwd").ok()?;
+
+    content
+        .lines()
+        .map(|line| line.split(':').collect::<Vec<_>>())
+        .find(|fields| fields[0] == name)
+        .and_then(|fields| fields.get(3)?.parse().ok())
+}
+
+/// Returns the gids of the groups listing the user named `name` among their members, in the fourth field
+/// of their `/etc/group` entries.
+pub fn member_gids(name: &str) -> Vec<u32> {
+    let content = fs::read_to_string("/etc/group").unwrap_or_default();
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<_> = line.split(':').collect();
+            let is_member = fields.get(3)?.split(',').any(|member| member == name);
+
+            is_member.then(|| fields[2].parse().ok()).flatten()
+        })
+        .collect()
+}
+
+/// Returns the smallest id with the given name, so that the result doesn't depend on the map order.
+fn find_id(names: &HashMap<u32, String>, name: &str) -> Option<u32> {
+    names
+        .iter()
+        .filter(|(_, candidate)| *candidate == name)
+        .map(|(id, _)| *id)
+        .min()
+}
+
+/// Parses a database made of `name:password:id:...` lines, like `/etc/passwd` and `/etc/group`, into a
+/// map of names by id. A missing or unreadable file yields an empty map, and malformed lines are skipped.
+fn parse_database(path: &str) -> HashMap<u32, String> {
+    let content = fs::read_to_string(path).unwrap_or_default();
+    let mut names = HashMap::new();
+
+    for line in content.lines() {
+        let mut fields = line.split(':');
+
+        if let (Some(name), Some(id)) = (fields.next(), fields.nth(1)) {
+            if let Ok(id) = id.parse() {
+                // The first entry wins when an id is listed several times, as with `getpwuid`.
+                names.entry(id).or_insert_with(|| name.to_string());
+            }
+        }
+    }
+
+    names
+}
@@ -0,0 +1,339 @@
+use std::{
+    env, fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How long the shell may run before a test fails, so that a hang is reported rather than waited on.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A directory created for a test, removed along with its content when dropped. The shell runs in it,
+/// with it as its home directory.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new() -> TempDir {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let name = format!(
+            "core-utils-shell-test-{}-{}",
+            process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = env::temp_dir().join(name);
+
+        fs::create_dir_all(&path).unwrap();
+
+        TempDir(path)
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// What the shell printed, and the status it exited with.
+struct Output {
+    stdout: String,
+    stderr: String,
+    code: Option<i32>,
+}
+
+/// Runs the shell with `args` in `dir`, feeding it `input`, and waits for it to exit.
+fn run_in(dir: &TempDir, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_core-utils"))
+        .args(args)
+        .current_dir(dir.path())
+        .env("HOME", dir.path())
+        .env_remove("PS1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_string();
+    let feeder = thread::spawn(move || {
+        let _ = stdin.write_all(input.as_bytes());
+    });
+
+    let mut stdout = child.stdout.take().unwrap();
+    let mut stderr = child.stderr.take().unwrap();
+    let stdout = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stdout.read_to_string(&mut output);
+        output
+    });
+    let stderr = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stderr.read_to_string(&mut output);
+        output
+    });
+
+    let start = Instant::now();
+
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+
+        if start.elapsed() > TIMEOUT {
+            child.kill().unwrap();
+            panic!("the shell did not exit within {:?}", TIMEOUT);
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    feeder.join().unwrap();
+
+    Output {
+        stdout: stdout.join().unwrap(),
+        stderr: stderr.join().unwrap(),
+        code: status.code(),
+    }
+}
+
+/// Runs the shell in a new directory, feeding it `input`.
+fn run(input: &str) -> Output {
+    run_in(&TempDir::new(), &[], input)
+}
+
+#[test]
+fn input_redirection_reads_a_file() {
+    let dir = TempDir::new();
+    fs::write(dir.path().join("input"), "from a file\n").unwrap();
+
+    let output = run_in(&dir, &[], "cat < input\nwc -l <input\n");
+
+    assert_eq!(output.stdout, "from a file\n1\n");
+}
+
+#[test]
+fn missing_input_file_is_reported() {
+    let output = run("cat < missing\n");
+
+    assert_eq!(output.code, Some(1));
+    assert!(output.stderr.contains("missing"), "{}", output.stderr);
+}
+
+#[test]
+fn confirmations_are_read_from_the_input_of_the_command() {
+    let dir = TempDir::new();
+    fs::write(dir.path().join("kept"), "").unwrap();
+    fs::write(dir.path().join("removed"), "").unwrap();
+    fs::write(dir.path().join("answer"), "n\n").unwrap();
+
+    run_in(&dir, &[], "rm -i removed\ny\nrm -i kept < answer\n");
+
+    assert!(!dir.path().join("removed").exists());
+    assert!(dir.path().join("kept").exists());
+}
+
+#[test]
+fn pipe_feeds_the_output_of_a_command_to_the_next() {
+    let output = run("echo hello | wc -c\necho b a | tr ' ' '\\n' | sort\n");
+
+    assert_eq!(output.stdout, "6\na\nb\n");
+}
+
+#[test]
+fn xargs_runs_a_command_with_the_piped_names() {
+    let dir = TempDir::new();
+    fs::write(dir.path().join("a"), "").unwrap();
+    fs::write(dir.path().join("b"), "").unwrap();
+
+    let output = run_in(
+        &dir,
+        &[],
+        "ls | xargs echo found\nls | xargs -n 1 rm\nls | wc -l\n",
+    );
+
+    assert_eq!(output.stdout, "found a b\n0\n");
+}
+
+#[test]
+fn prompt_is_not_printed_when_the_input_is_not_a_terminal() {
+    let output = run("echo hello\n");
+
+    assert_eq!(
+        (output.stdout.as_str(), output.stderr.as_str()),
+        ("hello\n", "")
+    );
+}
+
+#[test]
+fn exit_status_is_the_status_of_the_shell() {
+    assert_eq!(run("exit 0\n").code, Some(0));
+    assert_eq!(run("exit 42\necho not reached\n").code, Some(42));
+    assert_eq!(run("false\nexit\n").code, Some(1));
+    assert_eq!(run("false\n").code, Some(1));
+    assert_eq!(run("exit 257\n").code, Some(1));
+}
+
+#[test]
+fn last_status_expands_as_question_mark() {
+    let output = run("ls /nonexistent\necho $?\ntrue\necho \"$?\" '$?'\n");
+    let lines: Vec<&str> = output.stdout.lines().collect();
+
+    assert_eq!(lines.len(), 2, "{}", output.stdout);
+    assert_ne!(lines[0], "0");
+    assert!(lines[0].parse::<i32>().is_ok(), "{}", lines[0]);
+    assert_eq!(lines[1], "0 $?");
+}
+
+#[test]
+fn syntax_error_has_status_2() {
+    let output = run("echo 'unclosed\necho $?\n");
+
+    assert_eq!(output.stdout, "2\n");
+}
+
+#[test]
+fn alias_changes_what_a_line_runs() {
+    let output = run("alias greet='echo hello'\ngreet world\nunalias greet\ngreet world\n");
+
+    assert_eq!(output.stdout, "hello world\n");
+    assert!(
+        output.stderr.contains("command not found : greet"),
+        "{}",
+        output.stderr
+    );
+}
+
+#[test]
+fn script_file_runs_until_exit() {
+    let dir = TempDir::new();
+    fs::write(
+        dir.path().join("script"),
+        "# a comment\necho one\n\n   \necho two | wc -c\nexit 3\necho not reached\n",
+    )
+    .unwrap();
+
+    let output = run_in(&dir, &["script"], "echo from stdin\n");
+
+    assert_eq!(output.stdout, "one\n4\nGoodbye!\n");
+    assert_eq!(output.code, Some(3));
+}
+
+#[test]
+fn missing_script_file_has_status_127() {
+    let output = run_in(&TempDir::new(), &["missing"], "");
+
+    assert_eq!(output.code, Some(127));
+    assert!(output.stderr.contains("missing"), "{}", output.stderr);
+}
+
+#[test]
+fn version_flag_prints_the_version() {
+    let expected = format!("{} {}\n", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+
+    for flag in ["--version", "-v"] {
+        let output = run_in(&TempDir::new(), &[flag], "echo not run\n");
+
+        assert_eq!(output.stdout, expected);
+        assert_eq!(output.code, Some(0));
+    }
+}
+
+#[test]
+fn help_flag_lists_the_commands() {
+    let output = run_in(&TempDir::new(), &["--help"], "echo not run\n");
+
+    assert!(output.stdout.starts_with("Usage: "), "{}", output.stdout);
+    assert!(output.stdout.contains("\nCommands:\n"));
+    assert!(output.stdout.contains("echo"));
+    assert!(!output.stdout.contains("not run"));
+    assert_eq!(output.code, Some(0));
+}
+
+#[test]
+fn patterns_expand_to_the_matching_paths() {
+    let dir = TempDir::new();
+    for name in ["b.txt", "a.txt", "c.rs"] {
+        fs::write(dir.path().join(name), "").unwrap();
+    }
+
+    let output = run_in(&dir, &[], "echo *.txt\necho '*.txt' \\*.txt\necho *.none\n");
+
+    assert_eq!(output.stdout, "a.txt b.txt\n*.txt *.txt\n*.none\n");
+}
+
+#[test]
+fn false_sets_a_failing_status_and_true_resets_it() {
+    let output = run("false\necho $?\ntrue\necho $?\n");
+
+    assert_eq!(output.stdout, "1\n0\n");
+}
+
+#[test]
+fn endless_command_stops_once_the_next_one_is_done() {
+    let output = run("yes | head -n 2\necho $?\nyes | cat | head -n 1\n");
+
+    assert_eq!(output.stdout, "y\ny\n0\ny\n");
+}
+
+#[test]
+fn external_programs_stream_their_pipes() {
+    let Some(cat) = ["/bin/cat", "/usr/bin/cat"]
+        .into_iter()
+        .find(|path| Path::new(path).exists())
+    else {
+        return;
+    };
+
+    let output = run(&format!(
+        "yes | {cat} | head -n 1\nseq 100000 | {cat} | wc -l\n"
+    ));
+
+    assert_eq!(output.stdout, "y\n100000\n");
+}
+
+#[test]
+fn env_sets_variables_for_its_command_only() {
+    let Some(printenv) = ["/usr/bin/printenv", "/bin/printenv"]
+        .into_iter()
+        .find(|path| Path::new(path).exists())
+    else {
+        return;
+    };
+
+    let output = run(&format!(
+        "env CORE_UTILS_TEST_ENV=1 {printenv} CORE_UTILS_TEST_ENV\n\
+         env CORE_UTILS_TEST_ENV=2 env | grep CORE_UTILS_TEST_ENV\n\
+         env CORE_UTILS_TEST_ENV=3 true | {printenv} CORE_UTILS_TEST_ENV\n\
+         echo $?\n\
+         {printenv} CORE_UTILS_TEST_ENV\n\
+         echo $?\n"
+    ));
+
+    assert_eq!(output.stdout, "1\nCORE_UTILS_TEST_ENV=2\n1\n1\n");
+}
+
+#[test]
+fn large_listing_piped_into_a_command_stopping_early() {
+    let dir = TempDir::new();
+    for i in 0..5000 {
+        fs::write(dir.path().join(format!("file{:05}", i)), "").unwrap();
+    }
+
+    let output = run_in(
+        &dir,
+        &[],
+        "ls -1 | head -n 1\necho $?\nls -l | head -n 0\necho $?\n",
+    );
+
+    assert_eq!(output.stdout, "file00000\n0\n0\n");
+    assert_eq!(output.stderr, "");
+}